@@ -0,0 +1,138 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Member, Result, Type};
+
+/// A struct field and whether it is a nested bundle
+struct BundleField {
+    member: Member,
+    ty: Type,
+    nested: bool,
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let bundle: syn::Path = parse_quote!(::archetype_ecs::component::Bundle);
+    let component: syn::Path = parse_quote!(::archetype_ecs::component::Component);
+
+    let data = match &input.data {
+        Data::Struct(data) => data,
+        Data::Enum(data) => {
+            return Err(syn::Error::new(
+                data.enum_token.span,
+                "Bundle can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "Bundle can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut fields = Vec::with_capacity(data.fields.len());
+    for (index, field) in data.fields.iter().enumerate() {
+        let mut nested = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("bundle")) {
+            attr.meta.require_path_only()?;
+            nested = true;
+        }
+        // Best effort: types are compared as written, so `f32` and
+        // `core::primitive::f32` or a type alias slip through. Those, and
+        // repeats through nested bundles, are rejected when spawning
+        if !nested {
+            let ty = &field.ty;
+            let name = quote!(#ty).to_string();
+            let repeated = fields.iter().any(|other: &BundleField| {
+                let other_ty = &other.ty;
+                !other.nested && quote!(#other_ty).to_string() == name
+            });
+            if repeated {
+                return Err(syn::Error::new_spanned(
+                    ty,
+                    format!("component type `{name}` appears more than once in this bundle"),
+                ));
+            }
+        }
+        let member = match &field.ident {
+            Some(ident) => Member::Named(ident.clone()),
+            None => Member::Unnamed(index.into()),
+        };
+        fields.push(BundleField {
+            member,
+            ty: field.ty.clone(),
+            nested,
+        });
+    }
+
+    let mut generics = input.generics.clone();
+    {
+        let where_clause = generics.make_where_clause();
+        for field in &fields {
+            let ty = &field.ty;
+            if field.nested {
+                where_clause.predicates.push(parse_quote!(#ty: #bundle));
+            } else {
+                where_clause.predicates.push(parse_quote!(#ty: #component));
+            }
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    let mut type_ids = Vec::with_capacity(fields.len());
+    let mut registers = Vec::with_capacity(fields.len());
+    let mut counts = Vec::with_capacity(fields.len());
+    let mut writes = Vec::with_capacity(fields.len());
+    let mut members = Vec::with_capacity(fields.len());
+    let mut bindings = Vec::with_capacity(fields.len());
+    for (index, field) in fields.iter().enumerate() {
+        let ty = &field.ty;
+        let binding = format_ident!("__field{}", index);
+        if field.nested {
+            type_ids.push(quote!(<#ty as #bundle>::type_ids()));
+            registers.push(quote!(<#ty as #bundle>::register_components(archetype);));
+            counts.push(quote!(<#ty as #bundle>::component_count()));
+            writes.push(quote! {
+                let __count = <#ty as #bundle>::component_count();
+                #bundle::write_components(#binding, &ptrs[__offset..__offset + __count]);
+                __offset += __count;
+            });
+        } else {
+            type_ids.push(quote!(::std::iter::once(::std::any::TypeId::of::<#ty>())));
+            registers.push(quote!(archetype.register_component::<#ty>();));
+            counts.push(quote!(1usize));
+            writes.push(quote! {
+                ::std::ptr::write(ptrs[__offset] as *mut #ty, #binding);
+                __offset += 1;
+            });
+        }
+        members.push(&field.member);
+        bindings.push(binding);
+    }
+
+    Ok(quote! {
+        impl #impl_generics #bundle for #ident #ty_generics #where_clause {
+            fn type_ids() -> ::archetype_ecs::component::BundleTypeIds {
+                ::std::iter::empty()
+                    #(.chain(#type_ids))*
+                    .collect()
+            }
+
+            fn register_components(archetype: &mut ::archetype_ecs::archetype::Archetype) {
+                let _ = &archetype;
+                #(#registers)*
+            }
+
+            fn component_count() -> usize {
+                0usize #(+ #counts)*
+            }
+
+            #[allow(unused_mut, unused_variables, unused_assignments)]
+            unsafe fn write_components(self, ptrs: &[*mut u8]) {
+                let Self { #(#members: #bindings,)* } = self;
+                let mut __offset = 0usize;
+                #(#writes)*
+            }
+        }
+    })
+}
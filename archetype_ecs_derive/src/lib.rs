@@ -0,0 +1,54 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Derive macros for Archetype ECS
+//!
+//! These macros are re-exported by `archetype_ecs`; depend on that crate
+//! rather than using this one directly.
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+mod bundle;
+mod reflect;
+
+/// Derive `archetype_ecs::reflection::Reflect`
+///
+/// Generates field access for structs (named and tuple) and for the active
+/// variant of enums. Field types must implement `Reflect` unless marked
+/// with `#[reflect(ignore)]`. The type must implement `Clone`.
+#[proc_macro_derive(Reflect, attributes(reflect))]
+pub fn derive_reflect(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    reflect::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Derive `archetype_ecs::component::Bundle` for a struct
+///
+/// Every field is stored as a component, except fields marked `#[bundle]`,
+/// which must themselves implement `Bundle` and are flattened into the
+/// parent's component list.
+///
+/// Fields whose types are spelled the same are rejected at compile time.
+/// This check is best effort; other repeated types are reported when
+/// spawning.
+#[proc_macro_derive(Bundle, attributes(bundle))]
+pub fn derive_bundle(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    bundle::derive(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
@@ -0,0 +1,235 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_quote, Data, DeriveInput, Fields, Ident, Member, Result};
+
+/// A field that takes part in reflection
+struct ReflectField {
+    member: Member,
+    name: String,
+}
+
+pub fn derive(input: DeriveInput) -> Result<TokenStream> {
+    let reflect: syn::Path = parse_quote!(::archetype_ecs::reflection::Reflect);
+
+    let body = match &input.data {
+        Data::Struct(data) => struct_body(&reflected_fields(&data.fields)?, &reflect),
+        Data::Enum(data) => {
+            let mut variants = Vec::with_capacity(data.variants.len());
+            for variant in &data.variants {
+                variants.push((variant.ident.clone(), reflected_fields(&variant.fields)?));
+            }
+            enum_body(&variants, &reflect)
+        }
+        Data::Union(data) => {
+            return Err(syn::Error::new(
+                data.union_token.span,
+                "Reflect cannot be derived for unions",
+            ))
+        }
+    };
+
+    let mut generics = input.generics.clone();
+    for param in generics.type_params_mut() {
+        param.bounds.push(parse_quote!(#reflect));
+        param.bounds.push(parse_quote!(::std::clone::Clone));
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let ident = &input.ident;
+
+    Ok(quote! {
+        impl #impl_generics #reflect for #ident #ty_generics #where_clause {
+            fn as_any(&self) -> &dyn ::std::any::Any {
+                self
+            }
+
+            fn as_any_mut(&mut self) -> &mut dyn ::std::any::Any {
+                self
+            }
+
+            fn apply(&mut self, value: &dyn #reflect) {
+                if let Some(v) = value.as_any().downcast_ref::<Self>() {
+                    *self = ::std::clone::Clone::clone(v);
+                }
+            }
+
+            fn reflect_clone(&self) -> ::std::boxed::Box<dyn #reflect> {
+                ::std::boxed::Box::new(::std::clone::Clone::clone(self))
+            }
+
+            #body
+        }
+    })
+}
+
+/// Collect the fields not marked `#[reflect(ignore)]`
+fn reflected_fields(fields: &Fields) -> Result<Vec<ReflectField>> {
+    let mut reflected = Vec::new();
+    for (index, field) in fields.iter().enumerate() {
+        let mut ignore = false;
+        for attr in field.attrs.iter().filter(|a| a.path().is_ident("reflect")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("ignore") {
+                    ignore = true;
+                    Ok(())
+                } else {
+                    Err(meta.error("unsupported reflect attribute, expected `ignore`"))
+                }
+            })?;
+        }
+        if ignore {
+            continue;
+        }
+
+        let (member, name) = match &field.ident {
+            Some(ident) => (Member::Named(ident.clone()), ident.to_string()),
+            None => (Member::Unnamed(index.into()), index.to_string()),
+        };
+        reflected.push(ReflectField { member, name });
+    }
+    Ok(reflected)
+}
+
+fn struct_body(fields: &[ReflectField], reflect: &syn::Path) -> TokenStream {
+    if fields.is_empty() {
+        return TokenStream::new();
+    }
+
+    let count = fields.len();
+    let indices: Vec<_> = (0..count).collect();
+    let members: Vec<_> = fields.iter().map(|f| &f.member).collect();
+    let names: Vec<_> = fields.iter().map(|f| &f.name).collect();
+
+    quote! {
+        fn field_count(&self) -> usize {
+            #count
+        }
+
+        fn field_at(&self, index: usize) -> ::std::option::Option<&dyn #reflect> {
+            match index {
+                #(#indices => ::std::option::Option::Some(&self.#members),)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn field_at_mut(&mut self, index: usize) -> ::std::option::Option<&mut dyn #reflect> {
+            match index {
+                #(#indices => ::std::option::Option::Some(&mut self.#members),)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn static_field_name(&self, index: usize) -> ::std::option::Option<&'static str> {
+            match index {
+                #(#indices => ::std::option::Option::Some(#names),)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn field_by_name(&self, name: &str) -> ::std::option::Option<&dyn #reflect> {
+            match name {
+                #(#names => ::std::option::Option::Some(&self.#members),)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn field_by_name_mut(&mut self, name: &str) -> ::std::option::Option<&mut dyn #reflect> {
+            match name {
+                #(#names => ::std::option::Option::Some(&mut self.#members),)*
+                _ => ::std::option::Option::None,
+            }
+        }
+    }
+}
+
+fn enum_body(variants: &[(Ident, Vec<ReflectField>)], reflect: &syn::Path) -> TokenStream {
+    let variant_idents: Vec<_> = variants.iter().map(|(ident, _)| ident).collect();
+    let variant_names: Vec<_> = variant_idents.iter().map(|i| i.to_string()).collect();
+    let variant_indices = 0..variants.len();
+
+    let mut tokens = quote! {
+        fn variant_name(&self) -> ::std::option::Option<&'static str> {
+            match *self {
+                #(Self::#variant_idents { .. } => ::std::option::Option::Some(#variant_names),)*
+            }
+        }
+
+        fn variant_index(&self) -> ::std::option::Option<usize> {
+            match *self {
+                #(Self::#variant_idents { .. } => ::std::option::Option::Some(#variant_indices),)*
+            }
+        }
+    };
+
+    if variants.iter().all(|(_, fields)| fields.is_empty()) {
+        return tokens;
+    }
+
+    let counts = variants.iter().map(|(_, fields)| fields.len());
+    let mut by_index = Vec::new();
+    let mut names_by_index = Vec::new();
+    let mut by_name = Vec::new();
+    for (variant, fields) in variants {
+        for (index, field) in fields.iter().enumerate() {
+            let member = &field.member;
+            let name = &field.name;
+            let binding = format_ident!("__field{}", index);
+            by_index.push(quote! {
+                (Self::#variant { #member: #binding, .. }, #index) => {
+                    ::std::option::Option::Some(#binding)
+                }
+            });
+            names_by_index.push(quote! {
+                (Self::#variant { .. }, #index) => ::std::option::Option::Some(#name)
+            });
+            by_name.push(quote! {
+                (Self::#variant { #member: #binding, .. }, #name) => {
+                    ::std::option::Option::Some(#binding)
+                }
+            });
+        }
+    }
+
+    tokens.extend(quote! {
+        fn field_count(&self) -> usize {
+            match *self {
+                #(Self::#variant_idents { .. } => #counts,)*
+            }
+        }
+
+        fn field_at(&self, index: usize) -> ::std::option::Option<&dyn #reflect> {
+            match (self, index) {
+                #(#by_index)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn field_at_mut(&mut self, index: usize) -> ::std::option::Option<&mut dyn #reflect> {
+            match (self, index) {
+                #(#by_index)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn static_field_name(&self, index: usize) -> ::std::option::Option<&'static str> {
+            match (self, index) {
+                #(#names_by_index,)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn field_by_name(&self, name: &str) -> ::std::option::Option<&dyn #reflect> {
+            match (self, name) {
+                #(#by_name)*
+                _ => ::std::option::Option::None,
+            }
+        }
+
+        fn field_by_name_mut(&mut self, name: &str) -> ::std::option::Option<&mut dyn #reflect> {
+            match (self, name) {
+                #(#by_name)*
+                _ => ::std::option::Option::None,
+            }
+        }
+    });
+    tokens
+}
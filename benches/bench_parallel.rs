@@ -17,6 +17,7 @@ impl System for HeavySystem {
         SystemAccess {
             reads: self.reads.clone(),
             writes: self.writes.clone(),
+            ..SystemAccess::new()
         }
     }
 
@@ -1,3 +1,4 @@
+use archetype_ecs::serialization::{load_world, save_world, SerializationRegistry};
 use archetype_ecs::World;
 use criterion::{criterion_group, criterion_main, Criterion};
 use serde::{Deserialize, Serialize};
@@ -16,17 +17,64 @@ struct Health {
     hp: f32,
 }
 
+archetype_ecs::impl_reflect!(Position);
+archetype_ecs::impl_reflect!(Health);
+
+const ENTITY_COUNT: usize = 10_000;
+
+fn setup() -> (World, SerializationRegistry) {
+    let mut world = World::new();
+    world
+        .spawn_batch((0..ENTITY_COUNT).map(|i| {
+            (
+                Position {
+                    x: i as f32,
+                    y: 0.0,
+                    z: i as f32 * 0.5,
+                },
+                Health { hp: 100.0 },
+            )
+        }))
+        .unwrap();
+
+    let mut registry = SerializationRegistry::new();
+    registry.register_binary::<Position>();
+    registry.register_binary::<Health>();
+    (world, registry)
+}
+
 fn bench_serialization(c: &mut Criterion) {
-    let mut _world = World::new();
-    // Benchmark setup omitted because serialization methods are not yet implemented on World.
-    // Use src/serialization.rs directly when fully implemented.
+    let (world, registry) = setup();
+    let scene = save_world(&world, &registry).unwrap();
+    let bytes = world.save_binary(&registry).unwrap();
 
-    /*
-    // ... (previous code) ...
-     */
     let mut group = c.benchmark_group("serialization");
-    // Empty benchmark to satisfy criterion
-    group.bench_function("placeholder", |b| b.iter(|| black_box(1)));
+
+    group.bench_function("save_world_json", |b| {
+        b.iter(|| {
+            let scene = save_world(black_box(&world), &registry).unwrap();
+            black_box(serde_json::to_string(&scene).unwrap())
+        })
+    });
+
+    group.bench_function("load_world_json", |b| {
+        b.iter(|| {
+            let mut loaded = World::new();
+            black_box(load_world(&mut loaded, black_box(&scene), &registry).unwrap())
+        })
+    });
+
+    group.bench_function("save_world_binary", |b| {
+        b.iter(|| black_box(world.save_binary(&registry).unwrap()))
+    });
+
+    group.bench_function("load_world_binary", |b| {
+        b.iter(|| {
+            let mut loaded = World::new();
+            black_box(loaded.load_binary(black_box(&bytes), &registry).unwrap())
+        })
+    });
+
     group.finish();
 }
 
@@ -22,7 +22,7 @@ fn spawn_simple_benchmark(c: &mut Criterion) {
 
     group.bench_function("spawn_entity_2_components", |b| {
         b.iter_batched(
-            || World::new(),
+            World::new,
             |mut world| {
                 for _ in 0..10_000 {
                     world.spawn_entity((Position(1.0, 2.0, 3.0), Velocity(1.0, 0.0, 0.0)));
@@ -41,7 +41,7 @@ fn spawn_heavy_benchmark(c: &mut Criterion) {
 
     group.bench_function("spawn_entity_4_components", |b| {
         b.iter_batched(
-            || World::new(),
+            World::new,
             |mut world| {
                 for _ in 0..10_000 {
                     world.spawn_entity((
@@ -65,7 +65,7 @@ fn spawn_mixed_benchmark(c: &mut Criterion) {
 
     group.bench_function("spawn_entity_mixed", |b| {
         b.iter_batched(
-            || World::new(),
+            World::new,
             |mut world| {
                 for i in 0..10_000 {
                     if i % 2 == 0 {
@@ -4,9 +4,10 @@
 //! - Component registration with SerializationRegistry
 //! - Scene creation and JSON export
 //! - Entity and component serialization
+//! - Loading a scene back into a fresh world
 
 use archetype_ecs::prelude::*;
-use archetype_ecs::serialization::{save_world, Scene, SerializationRegistry};
+use archetype_ecs::serialization::{load_world, save_world, Scene, SerializationRegistry};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -95,7 +96,7 @@ fn main() {
 
     // Save world to scene
     println!("=== Saving World to Scene ===");
-    match save_world(&world, &registry) {
+    let json = match save_world(&world, &registry) {
         Ok(scene) => {
             println!("✅ Successfully created scene");
             println!("Scene contains {} entities", scene.entity_count());
@@ -112,19 +113,47 @@ fn main() {
                     } else {
                         println!("\n✅ Saved scene to scene.json");
                     }
+                    json
                 }
                 Err(e) => {
                     println!("❌ Failed to serialize scene to JSON: {}", e);
+                    return;
                 }
             }
         }
         Err(e) => {
             println!("❌ Failed to save world: {:?}", e);
+            return;
+        }
+    };
+
+    // Load scene into a fresh world
+    println!("\n=== Loading Scene into New World ===");
+    let scene: Scene = match serde_json::from_str(&json) {
+        Ok(scene) => scene,
+        Err(e) => {
+            println!("❌ Failed to parse scene JSON: {}", e);
+            return;
+        }
+    };
+
+    let mut loaded_world = World::new();
+    match load_world(&mut loaded_world, &scene, &registry) {
+        Ok(id_map) => {
+            println!("✅ Loaded {} entities", loaded_world.entity_count());
+            for new_id in id_map.values() {
+                if let (Some(pos), Some(health)) = (
+                    loaded_world.get_component::<Position>(*new_id),
+                    loaded_world.get_component::<Health>(*new_id),
+                ) {
+                    println!("  {new_id}: {:?}, {:?}", pos, health);
+                }
+            }
+        }
+        Err(e) => {
+            println!("❌ Failed to load scene: {:?}", e);
         }
     }
 
     println!("\n=== Serialization Example Complete ===");
-    println!("\nNote: Full entity deserialization requires additional infrastructure");
-    println!("for spawning entities from Box<dyn Reflect> components.");
-    println!("This is planned for future iterations.");
 }
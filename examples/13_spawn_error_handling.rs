@@ -14,12 +14,12 @@ fn main() {
     let mut world = World::new();
 
     println!("1. Normal spawn (using spawn() - panics on error):");
-    let entity1 = world.spawn_entity((1.0f32, 2.0f32)); // Position tuple
+    let entity1 = world.spawn_entity((1.0f32, 2.0f64)); // Position tuple
     println!("   ✅ Spawned entity: {:?}", entity1);
 
     println!("\n2. Safe spawn (using try_spawn() - returns Result):");
     match world.try_spawn_entity((3.0f32, 100.0f32)) {
-        // Position + Health tuple
+        // Position + Health tuple (both f32, so this is rejected)
         Ok(entity2) => println!("   ✅ Spawned entity: {:?}", entity2),
         Err(e) => println!("   ❌ Spawn failed: {}", e),
     }
@@ -57,6 +57,7 @@ fn demonstrate_error_context() {
     println!("   - EntityCapacityExhausted: 'Entity capacity exhausted: attempted to spawn 1000001, max is 1000000'");
     println!("   - ComponentRegistrationFailed: 'Failed to register component: Type registration failed'");
     println!("   - ArchetypeCreationFailed: 'Failed to create archetype for 5 components: Memory allocation failed'");
+    println!("   - DuplicateComponent: 'Bundle (f32, f32) contains the same component type twice'");
     println!("   Each error provides specific context for debugging and monitoring.");
 }
 
@@ -55,11 +55,9 @@ impl System for MovementSystem {
         #[cfg(feature = "profiling")]
         let _guard = span.enter();
 
-        let mut _moved_count = 0;
         for (pos, vel) in world.query_mut::<(&mut Position, &Velocity)>().iter() {
             pos.x += vel.x;
             pos.y += vel.y;
-            _moved_count += 1;
         }
 
         #[cfg(feature = "profiling")]
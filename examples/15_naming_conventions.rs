@@ -15,13 +15,13 @@ fn main() {
     println!("1. Creation/Modification Methods:");
 
     // ✅ New standardized naming
-    let entity1 = world.spawn_entity((1.0f32, 2.0f32)); // Position tuple
+    let entity1 = world.spawn_entity((1.0f32, 2.0f64)); // Position tuple
     println!("   ✅ spawn_entity(): {:?}", entity1);
 
     // ✅ Batch operations
     let bundles = vec![
-        (3.0f32, 100.0f32), // Position + Health
-        (5.0f32, 0.2f32),   // Position + Velocity
+        (3.0f32, 100.0f64), // Position + Health
+        (5.0f32, 0.2f64),   // Position + Velocity
     ];
     let entities = world.spawn_batch(bundles).unwrap();
     println!("   ✅ spawn_batch(): {:?}", entities);
@@ -59,8 +59,8 @@ fn main() {
 
     // ✅ Query operations
     let mut count = 0;
-    for (pos, vel) in world.query_mut::<(&mut f32, &f32)>().iter() {
-        *pos += *vel;
+    for (pos, vel) in world.query_mut::<(&mut f32, &f64)>().iter() {
+        *pos += *vel as f32;
         count += 1;
     }
     println!("   ✅ query_mut().iter(): Processed {} entities", count);
@@ -72,7 +72,7 @@ fn main() {
     println!("\n4. Backward Compatibility:");
 
     // ⚠️ Old methods still work but show deprecation warnings
-    let entity3 = world.spawn_entity((9.0f32, 10.0f32));
+    let entity3 = world.spawn_entity((9.0f32, 10.0f64));
     println!("   ⚠️ spawn() (deprecated): {:?}", entity3);
 
     println!("\n=== Naming Convention Benefits ===");
@@ -1,56 +1,331 @@
-use crate::error::Result;
+use crate::error::{EcsError, Result};
+use crate::event_channel::{self, Events};
 use crate::executor::Executor;
 use crate::hot_reload::{HotReloadManager, HotReloadApp, ReloadableSystem};
-use crate::plugin::Plugin;
+use crate::plugin::{Plugin, PluginGroup};
+use crate::runner::{self, AppExit, Runner};
 use crate::schedule::Schedule;
+use crate::state::{NextState, State, StateScheduleLabel, StateSchedules, StateTransitions, States};
 use crate::system::BoxedSystem;
+use crate::time::{FixedTime, Time};
 use crate::world::World;
+use std::time::Duration;
 
 /// Main application entry point
+///
+/// The world starts with `Time` and `FixedTime` (60 Hz) resources.
 pub struct App {
     pub world: World,
     pub schedule: Schedule,
+    /// Systems stepped at the `FixedTime` rate, before `schedule`
+    pub fixed_schedule: Schedule,
     hot_reload_manager: HotReloadManager,
+    state_transitions: Vec<Box<dyn StateTransitions>>,
+    /// `Events::update` of each channel added with `add_event`
+    event_updaters: Vec<fn(&mut World)>,
+    runner: Option<Runner>,
+    plugins: Vec<Box<dyn Plugin>>,
+    /// Names of `plugins`, kept apart so they stay visible to lifecycle hooks
+    plugin_names: Vec<&'static str>,
+    plugins_state: PluginsState,
+}
+
+/// Lifecycle stage of an app's plugins
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PluginsState {
+    /// Plugins may still be added
+    Adding,
+    /// `finish` hooks have run
+    Finished,
+    /// `cleanup` hooks have run
+    Cleaned,
 }
 
 impl App {
     /// Create new application
     pub fn new() -> Self {
+        let mut world = World::new();
+        world.insert_resource(Time::new());
+        world.insert_resource(FixedTime::default());
         Self {
-            world: World::new(),
+            world,
             schedule: Schedule::new(),
+            fixed_schedule: Schedule::new(),
             hot_reload_manager: HotReloadManager::new(),
+            state_transitions: Vec::new(),
+            event_updaters: Vec::new(),
+            runner: None,
+            plugins: Vec::new(),
+            plugin_names: Vec::new(),
+            plugins_state: PluginsState::Adding,
         }
     }
 
     /// Add a plugin
-    pub fn add_plugin<P: Plugin>(&mut self, plugin: P) -> &mut Self {
-        println!("📦 Registering plugin: {}", plugin.plugin_name());
-        plugin.build(self);
+    ///
+    /// # Panics
+    /// Panics if [`App::try_add_plugin`] would fail.
+    pub fn add_plugin<P: Plugin + 'static>(&mut self, plugin: P) -> &mut Self {
+        if let Err(err) = self.add_boxed_plugin(Box::new(plugin)) {
+            panic!("{err}");
+        }
         self
     }
 
+    /// Add a plugin, failing if it is a duplicate of a unique plugin, its
+    /// dependencies haven't been added yet, or plugins are already finished
+    pub fn try_add_plugin<P: Plugin + 'static>(&mut self, plugin: P) -> Result<&mut Self> {
+        self.add_boxed_plugin(Box::new(plugin))?;
+        Ok(self)
+    }
+
+    /// Add every plugin of a group, in order
+    ///
+    /// # Panics
+    /// Panics if adding any of the plugins fails.
+    pub fn add_plugins<G: PluginGroup>(&mut self, group: G) -> &mut Self {
+        for plugin in group.build().into_plugins() {
+            if let Err(err) = self.add_boxed_plugin(plugin) {
+                panic!("{err}");
+            }
+        }
+        self
+    }
+
+    fn add_boxed_plugin(&mut self, plugin: Box<dyn Plugin>) -> Result<()> {
+        let name = plugin.plugin_name();
+        if self.plugins_state != PluginsState::Adding {
+            return Err(EcsError::PluginError(format!(
+                "cannot add `{name}` after plugins are finished"
+            )));
+        }
+        if plugin.is_unique() && self.is_plugin_added(name) {
+            return Err(EcsError::PluginError(format!(
+                "`{name}` was already added"
+            )));
+        }
+        let missing: Vec<&str> = plugin
+            .dependencies()
+            .into_iter()
+            .filter(|dep| !self.is_plugin_added(dep))
+            .collect();
+        if !missing.is_empty() {
+            return Err(EcsError::PluginError(format!(
+                "`{name}` depends on {} which must be added first",
+                missing.join(", ")
+            )));
+        }
+
+        #[cfg(feature = "profiling")]
+        tracing::debug!(plugin = name, "registering plugin");
+        self.plugin_names.push(name);
+        plugin.build(self);
+        self.plugins.push(plugin);
+        Ok(())
+    }
+
+    /// Whether a plugin with this name has been added
+    pub fn is_plugin_added(&self, name: &str) -> bool {
+        self.plugin_names.contains(&name)
+    }
+
+    /// Current plugin lifecycle stage
+    pub fn plugins_state(&self) -> PluginsState {
+        self.plugins_state
+    }
+
+    /// Run every plugin's `finish` hook
+    ///
+    /// Called by [`App::run`]; call it yourself when driving
+    /// [`App::update`] directly. Runs at most once.
+    pub fn finish_plugins(&mut self) {
+        if self.plugins_state != PluginsState::Adding {
+            return;
+        }
+        self.plugins_state = PluginsState::Finished;
+        let plugins = std::mem::take(&mut self.plugins);
+        for plugin in &plugins {
+            plugin.finish(self);
+        }
+        self.plugins = plugins;
+    }
+
+    /// Run every plugin's `cleanup` hook, finishing first if needed
+    pub fn cleanup_plugins(&mut self) {
+        self.finish_plugins();
+        if self.plugins_state != PluginsState::Finished {
+            return;
+        }
+        self.plugins_state = PluginsState::Cleaned;
+        let plugins = std::mem::take(&mut self.plugins);
+        for plugin in &plugins {
+            plugin.cleanup(self);
+        }
+        self.plugins = plugins;
+    }
+
     /// Add a system
     pub fn add_system(&mut self, system: BoxedSystem) -> &mut Self {
         self.schedule.add_system(system);
         self
     }
 
+    /// Add a system to the fixed-timestep schedule
+    pub fn add_fixed_system(&mut self, system: BoxedSystem) -> &mut Self {
+        self.fixed_schedule.add_system(system);
+        self
+    }
+
+    /// Set the fixed-timestep interval
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
+    pub fn set_fixed_timestep(&mut self, timestep: Duration) -> &mut Self {
+        let mut fixed = FixedTime::from_duration(timestep);
+        if let Some(previous) = self.world.resource::<FixedTime>() {
+            fixed.set_max_steps(previous.max_steps());
+        }
+        self.world.insert_resource(fixed);
+        self
+    }
+
+    /// Cap the fixed steps run in one frame, see [`FixedTime::set_max_steps`]
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is zero.
+    pub fn set_max_fixed_steps(&mut self, max_steps: usize) -> &mut Self {
+        self.world.get_or_insert_with(FixedTime::default).set_max_steps(max_steps);
+        self
+    }
+
+    /// Add the `Events<T>` channel, swapped at the start of every update
+    pub fn add_event<T: Send + Sync + 'static>(&mut self) -> &mut Self {
+        if !self.world.has_resource::<Events<T>>() {
+            self.world.insert_resource(Events::<T>::new());
+            self.event_updaters.push(event_channel::update_events::<T>);
+        }
+        self
+    }
+
+    /// Add state `S`, starting in `initial`
+    ///
+    /// Inserts the `State<S>` and `NextState<S>` resources.
+    pub fn insert_state<S: States>(&mut self, initial: S) -> &mut Self {
+        self.world.insert_resource(State::new(initial));
+        self.world.insert_resource(NextState::<S>::default());
+        self.state_schedules::<S>();
+        self
+    }
+
+    /// Add a system to an `OnEnter`, `OnExit` or `OnTransition` schedule
+    pub fn add_state_system<L: StateScheduleLabel>(
+        &mut self,
+        label: L,
+        system: BoxedSystem,
+    ) -> &mut Self {
+        self.state_schedules::<L::State>()
+            .add_system(label.key(), system);
+        self
+    }
+
+    fn state_schedules<S: States>(&mut self) -> &mut StateSchedules<S> {
+        let index = match self
+            .state_transitions
+            .iter_mut()
+            .position(|t| t.as_any_mut().is::<StateSchedules<S>>())
+        {
+            Some(index) => index,
+            None => {
+                self.state_transitions
+                    .push(Box::new(StateSchedules::<S>::default()));
+                self.state_transitions.len() - 1
+            }
+        };
+        // Internal invariant - the entry at `index` has this type
+        self.state_transitions[index]
+            .as_any_mut()
+            .downcast_mut()
+            .expect("state schedules type mismatch")
+    }
+
     /// Run the application (one frame)
+    ///
+    /// Updates `Time`, applies pending state transitions, steps the fixed
+    /// schedule as often as `FixedTime` allows, then runs the main schedule.
     pub fn update(&mut self) -> Result<()> {
+        self.world.get_or_insert_with(Time::new).update();
+        self.run_frame()
+    }
+
+    /// Run one frame with an explicit delta instead of the wall clock
+    ///
+    /// Useful for tests, replays and lockstep simulation.
+    pub fn update_with_delta(&mut self, delta: Duration) -> Result<()> {
+        self.world.get_or_insert_with(Time::new).advance_by(delta);
+        self.run_frame()
+    }
+
+    fn run_frame(&mut self) -> Result<()> {
+        for update in &self.event_updaters {
+            update(&mut self.world);
+        }
+        for transitions in &mut self.state_transitions {
+            transitions.apply(&mut self.world)?;
+        }
+
+        // Scaled, so pausing `Time` also pauses fixed updates
+        let delta = self
+            .world
+            .resource::<Time>()
+            .map_or(Duration::ZERO, |time| time.delta().mul_f32(time.time_scale()));
+        let steps = self
+            .world
+            .resource_mut::<FixedTime>()
+            .map_or(0, |fixed| fixed.tick(delta));
+        if steps > 0 {
+            // Fixed systems read the timestep as `Time::delta`
+            let timestep = self.world.resource::<FixedTime>().unwrap().timestep();
+            let frame = self
+                .world
+                .resource_mut::<Time>()
+                .map(|time| time.begin_fixed_steps(timestep));
+            let mut executor = Executor::new(&mut self.fixed_schedule);
+            let result = (0..steps).try_for_each(|_| executor.execute_frame(&mut self.world));
+            if let (Some(frame), Some(time)) = (frame, self.world.resource_mut::<Time>()) {
+                time.end_fixed_steps(frame);
+            }
+            result?;
+        }
+
         // Create executor with schedule reference for this frame
         let mut executor = Executor::new(&mut self.schedule);
         executor.execute_frame(&mut self.world)?;
         Ok(())
     }
 
-    /// Run the application loop (simplified)
-    pub fn run(&mut self) -> Result<()> {
-        loop {
-            self.update()?;
-            // Break condition?
-            std::thread::sleep(std::time::Duration::from_millis(16));
-        }
+    /// Set the runner used by [`App::run`]
+    pub fn set_runner(&mut self, runner: Runner) -> &mut Self {
+        self.runner = Some(runner);
+        self
+    }
+
+    /// Run the application until a system requests [`AppExit`]
+    ///
+    /// Finishes and cleans up plugins first, then hands over to the runner
+    /// from [`App::set_runner`], or a 60 Hz loop.
+    pub fn run(&mut self) -> Result<AppExit> {
+        self.cleanup_plugins();
+        let mut runner = self.runner.take().unwrap_or_else(|| runner::run_loop(60));
+        let exit = runner(self);
+        self.runner = Some(runner);
+        exit
+    }
+
+    /// Take a pending exit request, if any
+    pub fn take_exit(&mut self) -> Option<AppExit> {
+        self.world.remove_resource::<AppExit>()
     }
 }
 
@@ -15,12 +15,15 @@
 //! Archetype storage with row allocation and removal
 
 use std::any::TypeId;
+use std::ptr::NonNull;
 
 use rustc_hash::FxHashMap;
 use smallvec::SmallVec;
 
 use crate::component::Component;
 use crate::entity::EntityId;
+use crate::storage::{SparseSet, SparseStorage};
+use crate::world::rebase_tick;
 
 /// Chunk size in bytes (16KB - fits in L1 cache, Unity DOTS standard)
 pub const CHUNK_SIZE_BYTES: usize = 16384;
@@ -91,9 +94,23 @@ pub struct Archetype {
     component_indices: FxHashMap<TypeId, usize>,
     columns_initialized: bool,
     edges: ArchetypeEdges,
+    /// Owning world's sparse storage, for components stored outside columns
+    sparse_storage: Option<NonNull<SparseStorage>>,
 }
 
+// SAFETY: `sparse_storage` points into the owning World, which is Send + Sync
+// and only hands out access to it under the same borrow rules as the archetype.
+unsafe impl Send for Archetype {}
+unsafe impl Sync for Archetype {}
+
 impl Archetype {
+    /// Shift every column's ticks down for a world tick rebase
+    pub(crate) fn rebase_ticks(&mut self) {
+        for column in &mut self.components {
+            column.rebase_ticks();
+        }
+    }
+
     pub(crate) fn add_column_raw(&mut self, type_id: TypeId, column: ComponentColumn) {
         // Prevent duplicates
         if !self.component_indices.contains_key(&type_id) {
@@ -112,6 +129,7 @@ impl Archetype {
             component_indices: FxHashMap::default(),
             columns_initialized: false,
             edges: ArchetypeEdges::default(),
+            sparse_storage: None,
         };
         archetype.reserve_rows(128);
         archetype
@@ -241,6 +259,17 @@ impl Archetype {
         }
     }
 
+    /// Drop every component in `row` without removing the row
+    ///
+    /// # Safety
+    /// The row's components must be initialized and must not be read or
+    /// dropped again; the row is expected to be removed right after.
+    pub(crate) unsafe fn drop_row(&mut self, row: usize) {
+        for column in &mut self.components {
+            column.drop_at(row);
+        }
+    }
+
     /// Get column immutably
     pub fn get_column(&self, type_id: TypeId) -> Option<&ComponentColumn> {
         let idx = *self.component_indices.get(&type_id)?;
@@ -377,7 +406,7 @@ impl Archetype {
     ///
     /// # Arguments
     /// * `chunk_size` - Number of entities per chunk (default: 64)
-    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = ArchetypeChunk> + '_ {
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = ArchetypeChunk<'_>> + '_ {
         let total_entities = self.len();
         let chunk_size = chunk_size.max(1); // Ensure at least 1 entity per chunk
 
@@ -391,7 +420,7 @@ impl Archetype {
     }
 
     /// Iterate over mutable chunks of entities
-    pub fn chunks_mut(&mut self, chunk_size: usize) -> Vec<ArchetypeChunkMut> {
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> Vec<ArchetypeChunkMut<'_>> {
         let total_entities = self.len();
         let chunk_size = chunk_size.max(1);
 
@@ -434,6 +463,31 @@ impl Archetype {
         chunks // Collect for parallelization, not streaming
     }
 
+    /// Attach the owning world's sparse storage
+    pub(crate) fn set_sparse_storage(&mut self, storage: NonNull<SparseStorage>) {
+        self.sparse_storage = Some(storage);
+    }
+
+    /// Check if a component type uses sparse-set storage in the owning world
+    ///
+    /// Sparse components have no column; any entity in the archetype may or
+    /// may not have one.
+    pub fn is_sparse(&self, type_id: TypeId) -> bool {
+        self.sparse_storage()
+            .is_some_and(|storage| storage.is_sparse(type_id))
+    }
+
+    /// Owning world's sparse storage
+    pub fn sparse_storage(&self) -> Option<&SparseStorage> {
+        // SAFETY: The world outlives its archetypes and keeps the storage pinned
+        self.sparse_storage.map(|ptr| unsafe { &*ptr.as_ptr() })
+    }
+
+    /// Sparse set for `T`, if `T` uses sparse-set storage
+    pub fn sparse_set<T: Component>(&self) -> Option<&SparseSet<T>> {
+        self.sparse_storage()?.get::<T>()
+    }
+
     /// Register component column
     pub fn register_component<T: Component>(&mut self) {
         let type_id = TypeId::of::<T>();
@@ -444,6 +498,23 @@ impl Archetype {
         }
     }
 
+    /// Drop the columns of sparse-set components, registered by a bundle
+    ///
+    /// Only valid while the archetype has no rows.
+    pub(crate) fn drop_sparse_columns(&mut self) {
+        debug_assert!(self.entities.is_empty());
+        let mut indices: Vec<(TypeId, usize)> = self.component_indices.drain().collect();
+        indices.sort_unstable_by_key(|&(_, index)| index);
+        let columns = std::mem::take(&mut self.components);
+        for ((type_id, _), column) in indices.into_iter().zip(columns) {
+            if !self.is_sparse(type_id) {
+                self.component_indices
+                    .insert(type_id, self.components.len());
+                self.components.push(column);
+            }
+        }
+    }
+
     /// Check if all component columns have been initialized for this signature
     pub fn columns_initialized(&self) -> bool {
         self.columns_initialized
@@ -485,31 +556,41 @@ impl ComponentColumn {
     /// Initializes a type-erased component column that can store components of type T.
     /// The column stores components as raw bytes and maintains a drop function for cleanup.
     pub fn new<T: Component>() -> Self {
+        // Store a drop function only if T needs drop
+        // This is critical for proper cleanup of components with destructors
+        let drop_fn: Option<unsafe fn(*mut u8)> = if std::mem::needs_drop::<T>() {
+            Some(|ptr| {
+                // SAFETY: This closure is only called from ComponentColumn::drop
+                // with a valid pointer to an initialized T at the correct offset.
+                // The pointer:
+                // 1. Points to properly aligned memory (allocated for T)
+                // 2. Points to an initialized T (written via get_ptr_mut)
+                // 3. Will not be aliased (exclusive access during drop)
+                unsafe {
+                    std::ptr::drop_in_place(ptr as *mut T);
+                }
+            })
+        } else {
+            None
+        };
+        Self::from_layout(std::alloc::Layout::new::<T>(), drop_fn)
+    }
+
+    /// Create new column from a runtime layout and drop hook
+    ///
+    /// Used for components whose concrete type is only known through a
+    /// `TypeRegistration`.
+    pub(crate) fn from_layout(
+        layout: std::alloc::Layout,
+        drop_fn: Option<unsafe fn(*mut u8)>,
+    ) -> Self {
         Self {
             ptr: std::ptr::null_mut(),
             len: 0,
             cap: 0,
-            item_size: std::mem::size_of::<T>(),
-            align: std::mem::align_of::<T>(),
-            // Store a drop function only if T needs drop
-            // This is critical for proper cleanup of components with destructors
-            drop_fn: if std::mem::needs_drop::<T>() {
-                Some(|ptr| {
-                    // SAFETY: This closure is only called from ComponentColumn::drop
-                    // with a valid pointer to an initialized T at the correct offset.
-                    // The pointer:
-                    // 1. Points to properly aligned memory (allocated for T)
-                    // 2. Points to an initialized T (written via get_ptr_mut)
-                    // 1. Points to properly aligned memory (allocated for T)
-                    // 2. Is valid for reads/writes
-                    // 3. Will not be aliased (exclusive access during drop)
-                    unsafe {
-                        std::ptr::drop_in_place(ptr as *mut T);
-                    }
-                })
-            } else {
-                None
-            },
+            item_size: layout.size(),
+            align: layout.align(),
+            drop_fn,
             added_ticks: Vec::new(),
             changed_ticks: Vec::new(),
             last_added_tick: 0,
@@ -595,6 +676,33 @@ impl ComponentColumn {
         unsafe { self.ptr.add(offset) }
     }
 
+    /// Get read-only pointer to the component at `index`
+    ///
+    /// Returns `None` if `index` is out of bounds.
+    pub fn get_ptr(&self, index: usize) -> Option<*const u8> {
+        if self.item_size == 0 {
+            // ZSTs are "in bounds" for every allocated row
+            return (index < self.added_ticks.len())
+                .then(|| std::ptr::without_provenance::<u8>(self.align));
+        }
+        if index >= self.len {
+            return None;
+        }
+        // SAFETY: index < len, so the offset is within the initialized buffer
+        Some(unsafe { self.ptr.add(index * self.item_size) as *const u8 })
+    }
+
+    /// Run the drop hook for the component at `index` without removing the row
+    ///
+    /// # Safety
+    /// The component at `index` must be initialized and must not be read or
+    /// dropped again afterwards (the row is expected to be overwritten or removed).
+    pub(crate) unsafe fn drop_at(&mut self, index: usize) {
+        if let (Some(drop_fn), Some(ptr)) = (self.drop_fn, self.get_ptr(index)) {
+            drop_fn(ptr as *mut u8);
+        }
+    }
+
     /// Mark component as changed at given row
     pub fn mark_changed(&mut self, row: usize, tick: u32) {
         if row < self.changed_ticks.len() {
@@ -603,6 +711,15 @@ impl ComponentColumn {
         }
     }
 
+    /// Shift every tick down for a world tick rebase
+    pub(crate) fn rebase_ticks(&mut self) {
+        for tick in self.added_ticks.iter_mut().chain(&mut self.changed_ticks) {
+            *tick = rebase_tick(*tick);
+        }
+        self.last_added_tick = rebase_tick(self.last_added_tick);
+        self.last_change_tick = rebase_tick(self.last_change_tick);
+    }
+
     /// Check if this column has changed since the given tick
     pub fn changed_since(&self, tick: u32) -> bool {
         self.last_change_tick > tick
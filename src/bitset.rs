@@ -46,7 +46,7 @@ impl BitSet {
     }
 
     /// Returns iterator over indices of set bits
-    pub fn ones(&self) -> OnesIter {
+    pub fn ones(&self) -> OnesIter<'_> {
         OnesIter {
             bitset: self,
             word_idx: 0,
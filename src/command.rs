@@ -98,7 +98,10 @@ impl CommandBuffer {
     }
 
     /// Apply all commands to the world and clear the buffer
+    ///
+    /// Entities reserved while the commands were queued are made alive first.
     pub fn apply(&mut self, world: &mut World) -> Result<()> {
+        world.flush_entities();
         for command in self.commands.drain(..) {
             match command {
                 Command::Spawn(f) => {
@@ -23,9 +23,17 @@ use smallvec::{smallvec, SmallVec};
 
 use crate::archetype::Archetype;
 
-/// Maximum number of components supported by Bundle implementations
+pub use archetype_ecs_derive::Bundle;
+
+/// Number of bundle components handled without heap allocation
+///
+/// Tuple bundles are implemented up to this size. Larger bundles can be
+/// built with `#[derive(Bundle)]` on a struct.
 pub const MAX_BUNDLE_COMPONENTS: usize = 8;
 
+/// Component type IDs of a bundle, as returned by [`Bundle::type_ids`]
+pub type BundleTypeIds = SmallVec<[TypeId; MAX_BUNDLE_COMPONENTS]>;
+
 /// Marker trait for components
 ///
 /// Components must be 'static (no borrowed data)
@@ -37,6 +45,51 @@ impl<T: 'static + Send + Sync> Component for T {}
 /// Bundle of components
 ///
 /// Allows spawning entities with multiple components at once.
+///
+/// Implemented for tuples of components, and derivable for structs whose
+/// fields are components. Fields marked `#[bundle]` are nested bundles and
+/// are flattened into the parent's signature:
+///
+/// ```
+/// use archetype_ecs::{Bundle, World};
+///
+/// #[derive(Bundle)]
+/// struct Physics {
+///     velocity: (f32, f32),
+///     mass: f32,
+/// }
+///
+/// #[derive(Bundle)]
+/// struct Player {
+///     name: String,
+///     #[bundle]
+///     physics: Physics,
+/// }
+///
+/// let mut world = World::new();
+/// let player = world.spawn_entity(Player {
+///     name: "hero".to_string(),
+///     physics: Physics { velocity: (0.0, 0.0), mass: 80.0 },
+/// });
+/// assert_eq!(world.get_component::<f32>(player), Some(&80.0));
+/// ```
+///
+/// Each component type may appear only once per bundle. As a best effort,
+/// the derive rejects fields whose types are spelled the same. Other
+/// repeats, e.g. through aliases, nested bundles or tuples, make
+/// `try_spawn_entity` and `spawn_batch` return
+/// [`SpawnError::DuplicateComponent`](crate::SpawnError::DuplicateComponent)
+/// and `spawn_entity` panic:
+///
+/// ```compile_fail
+/// use archetype_ecs::Bundle;
+///
+/// #[derive(Bundle)]
+/// struct Twice {
+///     first: f32,
+///     second: f32,
+/// }
+/// ```
 pub trait Bundle: Send + Sync + 'static {
     /// Get type IDs of all components in bundle
     fn type_ids() -> SmallVec<[TypeId; MAX_BUNDLE_COMPONENTS]>
@@ -48,8 +101,18 @@ pub trait Bundle: Send + Sync + 'static {
     where
         Self: Sized;
 
+    /// Number of components in the bundle
+    fn component_count() -> usize
+    where
+        Self: Sized,
+    {
+        Self::type_ids().len()
+    }
+
     /// Write components to raw pointers
     ///
+    /// `ptrs` holds one pointer per component, in `type_ids` order.
+    ///
     /// # Safety
     /// Caller must ensure pointers are valid and properly aligned
     unsafe fn write_components(self, ptrs: &[*mut u8]);
@@ -0,0 +1,189 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Per-component lifecycle hooks
+//!
+//! Hooks run synchronously from every `World` mutation that adds, replaces
+//! or removes a component of their type, including spawns, despawns and
+//! applied commands:
+//!
+//! - `on_add` when the entity didn't have the component before
+//! - `on_replace` before an insertion overwrites an existing value, while
+//!   the old value is still readable
+//! - `on_insert` on every insertion, after `on_add` or `on_replace`
+//! - `on_remove` before the component is removed or its entity despawned,
+//!   while the value is still readable
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//!
+//! struct Position(i32);
+//! #[derive(Default)]
+//! struct Index(Vec<(EntityId, i32)>);
+//!
+//! let mut world = World::new();
+//! world.insert_resource(Index::default());
+//! world
+//!     .register_component_hooks::<Position>()
+//!     .on_insert(|world, entity, position| {
+//!         world.resource_mut::<Index>().unwrap().0.push((entity, position.0));
+//!     })
+//!     .on_remove(|world, entity, _| {
+//!         world.resource_mut::<Index>().unwrap().0.retain(|(e, _)| *e != entity);
+//!     });
+//!
+//! let entity = world.spawn_entity((Position(3),));
+//! assert_eq!(world.resource::<Index>().unwrap().0, vec![(entity, 3)]);
+//! world.despawn(entity).unwrap();
+//! assert!(world.resource::<Index>().unwrap().0.is_empty());
+//! ```
+//!
+//! Hooks get a [`HookWorld`], which can read components and resources and
+//! write resources, but not change the world's structure.
+
+use std::marker::PhantomData;
+
+use crate::component::Component;
+use crate::entity::EntityId;
+use crate::resource::ResMut;
+use crate::world::World;
+
+/// World access available to component hooks
+///
+/// Components are read-only and entities can't be spawned or despawned,
+/// since the mutation that triggered the hook is still in progress.
+pub struct HookWorld<'w> {
+    world: &'w World,
+}
+
+impl<'w> HookWorld<'w> {
+    /// Read the whole world
+    pub fn world(&self) -> &World {
+        self.world
+    }
+
+    /// Get a component of an entity
+    pub fn get_component<T: Component>(&self, entity: EntityId) -> Option<&T> {
+        self.world.get_component(entity)
+    }
+
+    /// Get a resource
+    pub fn resource<R: 'static>(&self) -> Option<&R> {
+        self.world.resource()
+    }
+
+    /// Get a resource mutably
+    pub fn resource_mut<R: 'static>(&mut self) -> Option<ResMut<'_, R>> {
+        // SAFETY: Hooks run while the world is exclusively borrowed by the
+        // triggering mutation, and `&mut self` keeps this the only borrow
+        // handed out through the hook
+        unsafe { self.world.resources().borrow_mut(self.world.tick).ok() }
+    }
+}
+
+/// Which lifecycle event a hook reacts to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum HookKind {
+    Add,
+    Insert,
+    Replace,
+    Remove,
+}
+
+pub(crate) type ErasedHook = Box<dyn Fn(&World, EntityId) + Send + Sync>;
+
+/// Hooks of one component type
+#[derive(Default)]
+pub(crate) struct ComponentHooks {
+    on_add: Vec<ErasedHook>,
+    on_insert: Vec<ErasedHook>,
+    on_replace: Vec<ErasedHook>,
+    on_remove: Vec<ErasedHook>,
+}
+
+impl ComponentHooks {
+    pub(crate) fn get(&self, kind: HookKind) -> &[ErasedHook] {
+        match kind {
+            HookKind::Add => &self.on_add,
+            HookKind::Insert => &self.on_insert,
+            HookKind::Replace => &self.on_replace,
+            HookKind::Remove => &self.on_remove,
+        }
+    }
+}
+
+/// Registers hooks for component `T`, see [`World::register_component_hooks`]
+pub struct ComponentHooksBuilder<'a, T> {
+    hooks: &'a mut ComponentHooks,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: Component> ComponentHooksBuilder<'a, T> {
+    pub(crate) fn new(hooks: &'a mut ComponentHooks) -> Self {
+        Self {
+            hooks,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Run `hook` when an entity gains a `T`
+    pub fn on_add<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&mut HookWorld<'_>, EntityId, &T) + Send + Sync + 'static,
+    {
+        self.hooks.on_add.push(erase(hook));
+        self
+    }
+
+    /// Run `hook` whenever a `T` is inserted, new or replacing another
+    pub fn on_insert<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&mut HookWorld<'_>, EntityId, &T) + Send + Sync + 'static,
+    {
+        self.hooks.on_insert.push(erase(hook));
+        self
+    }
+
+    /// Run `hook` before an inserted `T` overwrites the entity's current one
+    ///
+    /// The hook sees the old value, which is dropped right after.
+    pub fn on_replace<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&mut HookWorld<'_>, EntityId, &T) + Send + Sync + 'static,
+    {
+        self.hooks.on_replace.push(erase(hook));
+        self
+    }
+
+    /// Run `hook` before a `T` is removed or its entity despawned
+    pub fn on_remove<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: Fn(&mut HookWorld<'_>, EntityId, &T) + Send + Sync + 'static,
+    {
+        self.hooks.on_remove.push(erase(hook));
+        self
+    }
+}
+
+fn erase<T, F>(hook: F) -> ErasedHook
+where
+    T: Component,
+    F: Fn(&mut HookWorld<'_>, EntityId, &T) + Send + Sync + 'static,
+{
+    Box::new(move |world, entity| {
+        if let Some(component) = world.get_component::<T>(entity) {
+            hook(&mut HookWorld { world }, entity, component);
+        }
+    })
+}
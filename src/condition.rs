@@ -0,0 +1,168 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run conditions for systems and stages
+//!
+//! A condition is checked by the executor before dispatch; when it returns
+//! `false` the system (or every system of the stage) is skipped for the
+//! frame and reported in `ExecutionProfile::skipped_systems`. Any
+//! `FnMut(&World) -> bool` closure is a condition:
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//! use archetype_ecs::condition::resource_exists;
+//!
+//! struct Paused;
+//! fn ai() {}
+//!
+//! let mut schedule = Schedule::new();
+//! schedule.add_system_with_condition(Box::new(ai.into_system()), |world: &World| {
+//!     !world.has_resource::<Paused>()
+//! });
+//! schedule.add_stage("debug").unwrap();
+//! schedule
+//!     .add_stage_run_condition("debug", resource_exists::<Diagnostics>())
+//!     .unwrap();
+//! ```
+//!
+//! Conditions attached to the same system or stage must all pass.
+
+use std::time::{Duration, Instant};
+
+use crate::time::Time;
+use crate::world::{SavedTick, World};
+
+/// A predicate deciding whether a system or stage runs this frame
+///
+/// Conditions are evaluated once per frame, so stateful ones such as
+/// [`on_timer`] can count frames or time between checks.
+pub trait Condition: Send + Sync {
+    /// Whether the guarded systems should run
+    fn evaluate(&mut self, world: &World) -> bool;
+}
+
+impl<F> Condition for F
+where
+    F: FnMut(&World) -> bool + Send + Sync,
+{
+    fn evaluate(&mut self, world: &World) -> bool {
+        self(world)
+    }
+}
+
+/// Boxed run condition stored by the schedule
+pub type BoxedCondition = Box<dyn Condition>;
+
+/// Run while resource `R` exists
+pub fn resource_exists<R: 'static>() -> impl Condition {
+    |world: &World| world.has_resource::<R>()
+}
+
+/// Run when resource `R` was inserted or mutably accessed since the last check
+///
+/// The first check passes if `R` exists. The last check is kept as a
+/// [`SavedTick`], so a tick rebase doesn't count as a change. Changes are
+/// told apart by tick: a change made after a check, in the same tick as a
+/// change that check already reported, isn't reported again.
+pub fn resource_changed<R: 'static>() -> impl Condition {
+    // Tick of the last check, and whether it saw a change made in that tick
+    let mut last_check: Option<(SavedTick, bool)> = None;
+    move |world: &World| {
+        let Some(changed) = world.resource_changed_tick::<R>() else {
+            return false;
+        };
+        let fire = last_check.is_none_or(|(saved, seen)| {
+            let checked = world.saved_tick(saved);
+            changed > checked || (changed == checked && !seen)
+        });
+        last_check = Some((world.save_tick(), changed == world.tick()));
+        fire
+    }
+}
+
+/// Run once every `period`
+///
+/// Measures the `Time` resource's elapsed time if the world has one, wall
+/// clock time otherwise. The first check starts the timer. Missed periods
+/// don't accumulate; a long frame fires once.
+pub fn on_timer(period: Duration) -> impl Condition {
+    let start = Instant::now();
+    let mut next = None;
+    move |world: &World| {
+        let now = world
+            .resource::<Time>()
+            .map_or_else(|| start.elapsed(), Time::elapsed);
+        let deadline = *next.get_or_insert(now + period);
+        if now < deadline {
+            return false;
+        }
+        next = Some(if now - deadline >= period {
+            now + period
+        } else {
+            deadline + period
+        });
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resource_changed_tracks_ticks() {
+        let mut world = World::new();
+        let mut changed = resource_changed::<u32>();
+        assert!(!changed.evaluate(&world));
+
+        world.insert_resource(1u32);
+        assert!(changed.evaluate(&world));
+        assert!(!changed.evaluate(&world));
+
+        world.increment_tick();
+        *world.resource_mut::<u32>().unwrap() += 1;
+        assert!(changed.evaluate(&world));
+        assert!(!changed.evaluate(&world));
+    }
+
+    #[test]
+    fn test_resource_changed_after_check_in_same_tick() {
+        let mut world = World::new();
+        world.insert_resource(1u32);
+        let mut changed = resource_changed::<u32>();
+        assert!(changed.evaluate(&world));
+
+        world.increment_tick();
+        assert!(!changed.evaluate(&world));
+        *world.resource_mut::<u32>().unwrap() += 1;
+        assert!(changed.evaluate(&world));
+        assert!(!changed.evaluate(&world));
+    }
+
+    #[test]
+    fn test_resource_changed_ignores_tick_rebase() {
+        let mut world = World::new();
+        world.tick = crate::TICK_REBASE_THRESHOLD - 1;
+        world.insert_resource(1u32);
+        let mut changed = resource_changed::<u32>();
+        assert!(changed.evaluate(&world));
+
+        world.increment_tick();
+        assert!(world.tick() < crate::TICK_REBASE_THRESHOLD);
+        assert!(!changed.evaluate(&world));
+
+        *world.resource_mut::<u32>().unwrap() += 1;
+        assert!(changed.evaluate(&world));
+    }
+}
@@ -328,10 +328,12 @@ mod tests {
         let access1 = SystemAccess {
             reads: vec![ComponentId::of::<i32>()],
             writes: vec![],
+            event_writes: vec![],
         };
         let access2 = SystemAccess {
             reads: vec![ComponentId::of::<f32>()],
             writes: vec![],
+            event_writes: vec![],
         };
 
         let graph = DependencyGraph::new(vec![access1, access2]);
@@ -343,10 +345,12 @@ mod tests {
         let access1 = SystemAccess {
             reads: vec![ComponentId::of::<i32>()],
             writes: vec![ComponentId::of::<f32>()],
+            event_writes: vec![],
         };
         let access2 = SystemAccess {
             reads: vec![ComponentId::of::<f32>()],
             writes: vec![],
+            event_writes: vec![],
         };
 
         let graph = DependencyGraph::new(vec![access1, access2]);
@@ -359,14 +363,17 @@ mod tests {
         let access_a = SystemAccess {
             reads: vec![],
             writes: vec![ComponentId::of::<i32>()],
+            event_writes: vec![],
         };
         let access_b = SystemAccess {
             reads: vec![ComponentId::of::<i32>()],
             writes: vec![ComponentId::of::<f32>()],
+            event_writes: vec![],
         };
         let access_c = SystemAccess {
             reads: vec![ComponentId::of::<f32>()],
             writes: vec![],
+            event_writes: vec![],
         };
 
         let graph = DependencyGraph::new(vec![access_a, access_b, access_c]);
@@ -388,22 +395,27 @@ mod tests {
             SystemAccess {
                 reads: vec![],
                 writes: vec![ComponentId::of::<i32>()],
+                event_writes: vec![],
             },
             SystemAccess {
                 reads: vec![],
                 writes: vec![ComponentId::of::<f32>()],
+                event_writes: vec![],
             },
             SystemAccess {
                 reads: vec![ComponentId::of::<i32>()],
                 writes: vec![ComponentId::of::<i64>()],
+                event_writes: vec![],
             },
             SystemAccess {
                 reads: vec![ComponentId::of::<f32>()],
                 writes: vec![ComponentId::of::<f64>()],
+                event_writes: vec![],
             },
             SystemAccess {
                 reads: vec![ComponentId::of::<i64>(), ComponentId::of::<f64>()],
                 writes: vec![],
+                event_writes: vec![],
             },
         ];
 
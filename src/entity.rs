@@ -14,7 +14,9 @@
 
 //! Entity identifiers and location metadata.
 
-use slotmap::new_key_type;
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use slotmap::{new_key_type, Key, KeyData};
 
 new_key_type! {
     /// Unique entity identifier backed by slotmap's generational keys.
@@ -23,7 +25,6 @@ new_key_type! {
 
 impl std::fmt::Display for EntityId {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use slotmap::Key;
         let ffi = self.data().as_ffi();
         write!(f, "E{}v{}", ffi & 0xFFFFFFFF, ffi >> 32)
     }
@@ -35,3 +36,221 @@ pub struct EntityLocation {
     pub archetype_id: usize,
     pub archetype_row: usize,
 }
+
+/// Generational entity allocator
+///
+/// Behaves like a `SlotMap<EntityId, EntityLocation>` but can also hand out
+/// IDs through `&self` with [`Entities::reserve`]. Reserved IDs take freed
+/// slots first, then slots past the end, and only become alive at the next
+/// [`Entities::flush`].
+pub(crate) struct Entities {
+    slots: Vec<EntitySlot>,
+    /// Freed slot indices, reused last in, first out
+    free: Vec<u32>,
+    /// Number of `free` entries not yet reserved; below zero it counts the
+    /// reservations past the end of `slots`
+    free_cursor: AtomicI64,
+    len: usize,
+}
+
+struct EntitySlot {
+    /// Version of the current or last occupant, always odd
+    version: u32,
+    location: Option<EntityLocation>,
+}
+
+fn make_id(index: u32, version: u32) -> EntityId {
+    KeyData::from_ffi((u64::from(version) << 32) | u64::from(index)).into()
+}
+
+fn split_id(id: EntityId) -> (usize, u32) {
+    let ffi = id.data().as_ffi();
+    ((ffi & 0xFFFF_FFFF) as usize, (ffi >> 32) as u32)
+}
+
+impl Entities {
+    pub(crate) fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+            free_cursor: AtomicI64::new(0),
+            len: 0,
+        }
+    }
+
+    /// Reserve an ID, usable once `flush` has run
+    pub(crate) fn reserve(&self) -> EntityId {
+        let cursor = self.free_cursor.fetch_sub(1, Ordering::Relaxed);
+        if cursor > 0 {
+            let index = self.free[cursor as usize - 1];
+            make_id(index, self.slots[index as usize].version.wrapping_add(2))
+        } else {
+            let index = self.slots.len() as i64 - cursor;
+            make_id(index as u32, 1)
+        }
+    }
+
+    /// Whether reserved IDs are waiting for `flush`
+    pub(crate) fn needs_flush(&mut self) -> bool {
+        *self.free_cursor.get_mut() != self.free.len() as i64
+    }
+
+    /// Make every reserved ID alive, placing each with `place`
+    pub(crate) fn flush(&mut self, mut place: impl FnMut(EntityId) -> EntityLocation) {
+        let cursor = *self.free_cursor.get_mut();
+        let reused = cursor.max(0) as usize;
+
+        for position in (reused..self.free.len()).rev() {
+            let index = self.free[position];
+            let slot = &mut self.slots[index as usize];
+            slot.version = slot.version.wrapping_add(2);
+            let id = make_id(index, slot.version);
+            self.slots[index as usize].location = Some(place(id));
+            self.len += 1;
+        }
+        self.free.truncate(reused);
+
+        for _ in 0..(-cursor).max(0) {
+            let id = make_id(self.slots.len() as u32, 1);
+            self.slots.push(EntitySlot {
+                version: 1,
+                location: None,
+            });
+            let location = place(id);
+            self.slots.last_mut().unwrap().location = Some(location);
+            self.len += 1;
+        }
+
+        *self.free_cursor.get_mut() = self.free.len() as i64;
+    }
+
+    /// Allocate a live ID
+    ///
+    /// Pending reservations must be flushed first.
+    pub(crate) fn insert(&mut self, location: EntityLocation) -> EntityId {
+        debug_assert!(!self.needs_flush(), "reserved entities were not flushed");
+        self.len += 1;
+        let id = match self.free.pop() {
+            Some(index) => {
+                let slot = &mut self.slots[index as usize];
+                slot.version = slot.version.wrapping_add(2);
+                slot.location = Some(location);
+                make_id(index, slot.version)
+            }
+            None => {
+                self.slots.push(EntitySlot {
+                    version: 1,
+                    location: Some(location),
+                });
+                make_id(self.slots.len() as u32 - 1, 1)
+            }
+        };
+        *self.free_cursor.get_mut() = self.free.len() as i64;
+        id
+    }
+
+    pub(crate) fn get(&self, id: EntityId) -> Option<&EntityLocation> {
+        let (index, version) = split_id(id);
+        let slot = self.slots.get(index)?;
+        if slot.version != version {
+            return None;
+        }
+        slot.location.as_ref()
+    }
+
+    pub(crate) fn get_mut(&mut self, id: EntityId) -> Option<&mut EntityLocation> {
+        let (index, version) = split_id(id);
+        let slot = self.slots.get_mut(index)?;
+        if slot.version != version {
+            return None;
+        }
+        slot.location.as_mut()
+    }
+
+    pub(crate) fn contains_key(&self, id: EntityId) -> bool {
+        self.get(id).is_some()
+    }
+
+    /// Free an ID, returning its location
+    pub(crate) fn remove(&mut self, id: EntityId) -> Option<EntityLocation> {
+        debug_assert!(!self.needs_flush(), "reserved entities were not flushed");
+        let (index, version) = split_id(id);
+        let slot = self.slots.get_mut(index)?;
+        if slot.version != version {
+            return None;
+        }
+        let location = slot.location.take()?;
+        self.free.push(index as u32);
+        *self.free_cursor.get_mut() = self.free.len() as i64;
+        self.len -= 1;
+        Some(location)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.len
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    pub(crate) fn reserve_capacity(&mut self, additional: usize) {
+        self.slots.reserve(additional);
+    }
+
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (EntityId, &EntityLocation)> {
+        self.slots.iter().enumerate().filter_map(|(index, slot)| {
+            let location = slot.location.as_ref()?;
+            Some((make_id(index as u32, slot.version), location))
+        })
+    }
+
+    /// Free every ID; old IDs stay invalid
+    pub(crate) fn clear(&mut self) {
+        debug_assert!(!self.needs_flush(), "reserved entities were not flushed");
+        for (index, slot) in self.slots.iter_mut().enumerate() {
+            if slot.location.take().is_some() {
+                self.free.push(index as u32);
+            }
+        }
+        *self.free_cursor.get_mut() = self.free.len() as i64;
+        self.len = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NOWHERE: EntityLocation = EntityLocation {
+        archetype_id: 0,
+        archetype_row: 0,
+    };
+
+    #[test]
+    fn test_reserved_ids_match_flushed_ids() {
+        let mut entities = Entities::new();
+        let a = entities.insert(NOWHERE);
+        let b = entities.insert(NOWHERE);
+        entities.remove(a).unwrap();
+
+        let reused = entities.reserve();
+        let fresh = entities.reserve();
+        assert_ne!(reused, a);
+        assert_ne!(fresh, b);
+        assert!(!entities.contains_key(reused));
+
+        let mut flushed = Vec::new();
+        entities.flush(|id| {
+            flushed.push(id);
+            NOWHERE
+        });
+        flushed.sort();
+        let mut expected = vec![reused, fresh];
+        expected.sort();
+        assert_eq!(flushed, expected);
+        assert!(entities.contains_key(reused) && entities.contains_key(fresh));
+        assert!(!entities.contains_key(a));
+        assert_eq!(entities.len(), 3);
+    }
+}
@@ -96,6 +96,18 @@ pub enum EcsError {
 
     /// Panic during hot-reload system execution
     HotReloadPanic,
+
+    /// Reflection path could not be parsed or resolved
+    ReflectPathError(String),
+
+    /// Entity relation operation failed
+    RelationError(String),
+
+    /// Resource borrowed in conflict with another borrow or undeclared
+    ResourceAccessError(String),
+
+    /// Plugin added twice, out of order or too late
+    PluginError(String),
 }
 
 /// Detailed spawn error types
@@ -110,6 +122,8 @@ pub enum SpawnError {
         component_count: usize,
         reason: String,
     },
+    /// Bundle contains the same component type more than once
+    DuplicateComponent { bundle: &'static str },
 }
 
 impl fmt::Display for SpawnError {
@@ -136,6 +150,9 @@ impl fmt::Display for SpawnError {
                     "Failed to create archetype for {component_count} components: {reason}"
                 )
             }
+            SpawnError::DuplicateComponent { bundle } => {
+                write!(f, "Bundle {bundle} contains the same component type twice")
+            }
         }
     }
 }
@@ -171,6 +188,10 @@ impl fmt::Display for EcsError {
             EcsError::SpawnError(spawn_err) => write!(f, "Spawn error: {spawn_err}"),
             EcsError::ValidationError(msg) => write!(f, "Validation error: {msg}"),
             EcsError::HotReloadPanic => write!(f, "Panic during hot-reload execution"),
+            EcsError::ReflectPathError(msg) => write!(f, "Reflection path error: {msg}"),
+            EcsError::RelationError(msg) => write!(f, "Relation error: {msg}"),
+            EcsError::ResourceAccessError(msg) => write!(f, "Resource access error: {msg}"),
+            EcsError::PluginError(msg) => write!(f, "Plugin error: {msg}"),
         }
     }
 }
@@ -0,0 +1,257 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed, pull-based event channels
+//!
+//! Unlike the push-based `EventBus`, an [`Events<T>`] resource keeps events
+//! around for systems to read at their own pace. Systems send with
+//! [`EventWriter<T>`] and read with [`EventReader<T>`], which remembers what
+//! it has already seen:
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//!
+//! struct Damage(u32);
+//! #[derive(Default)]
+//! struct Total(u32);
+//!
+//! fn attack(mut damage: EventWriter<Damage>) {
+//!     damage.send(Damage(5));
+//! }
+//!
+//! fn apply(mut damage: EventReader<Damage>, mut total: ResMut<Total>) {
+//!     for event in damage.read() {
+//!         total.0 += event.0;
+//!     }
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_event::<Damage>();
+//! app.world.insert_resource(Total::default());
+//! // Readers are ordered after writers regardless of insertion order
+//! app.add_system(Box::new(apply.into_system()));
+//! app.add_system(Box::new(attack.into_system()));
+//!
+//! app.update().unwrap();
+//! assert_eq!(app.world.resource::<Total>().unwrap().0, 5);
+//! ```
+//!
+//! Events are double-buffered: [`Events::update`], run at the start of every
+//! `App::update`, drops the events sent two frames ago. A system reading
+//! every frame therefore sees every event exactly once, even if it runs
+//! before the writer.
+//!
+//! Only `App::update` swaps the buffers. When driving an `Executor`
+//! directly, call [`Events::update`] once per frame yourself, or the channel
+//! keeps every event ever sent.
+
+use std::marker::PhantomData;
+
+use crate::resource::{Res, ResMut};
+use crate::world::World;
+
+/// Double-buffered queue of events of type `T`, stored as a resource
+///
+/// Grows until [`Events::update`] runs, which `App::update` does every frame.
+pub struct Events<T> {
+    /// Events sent before the last `update`
+    previous: Vec<T>,
+    /// Events sent since the last `update`
+    current: Vec<T>,
+    /// Id of the first event in `previous`
+    previous_start: usize,
+}
+
+impl<T> Default for Events<T> {
+    fn default() -> Self {
+        Self {
+            previous: Vec::new(),
+            current: Vec::new(),
+            previous_start: 0,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> Events<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Send an event
+    pub fn send(&mut self, event: T) {
+        self.current.push(event);
+    }
+
+    /// Send several events in order
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = T>) {
+        self.current.extend(events);
+    }
+
+    /// Advance one frame, dropping events older than the previous frame
+    pub fn update(&mut self) {
+        self.previous_start += self.previous.len();
+        self.previous = std::mem::take(&mut self.current);
+    }
+
+    /// Number of stored events, from both frames
+    pub fn len(&self) -> usize {
+        self.previous.len() + self.current.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Drop every stored event
+    pub fn clear(&mut self) {
+        self.previous_start += self.len();
+        self.previous.clear();
+        self.current.clear();
+    }
+
+    /// Id the next sent event will get
+    fn next_id(&self) -> usize {
+        self.previous_start + self.len()
+    }
+
+    /// Stored events with an id of at least `from`
+    fn since(&self, from: usize) -> impl Iterator<Item = &T> {
+        let current_start = self.previous_start + self.previous.len();
+        self.previous
+            .iter()
+            .skip(from.saturating_sub(self.previous_start))
+            .chain(self.current.iter().skip(from.saturating_sub(current_start)))
+    }
+}
+
+/// Advance the world's `Events<T>`, if any
+pub(crate) fn update_events<T: Send + Sync + 'static>(world: &mut World) {
+    if let Some(events) = world.resource_mut::<Events<T>>() {
+        events.update();
+    }
+}
+
+/// Read position in an [`Events<T>`] channel
+///
+/// `EventReader` keeps one per system; use it directly to read outside of
+/// systems.
+pub struct EventCursor<T> {
+    next: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for EventCursor<T> {
+    fn default() -> Self {
+        Self {
+            next: 0,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Send + Sync + 'static> EventCursor<T> {
+    /// Events not yet read through this cursor, oldest first
+    pub fn read<'a>(&mut self, events: &'a Events<T>) -> impl Iterator<Item = &'a T> {
+        let from = self.next;
+        self.next = events.next_id();
+        events.since(from)
+    }
+
+    /// Number of unread events
+    pub fn len(&self, events: &Events<T>) -> usize {
+        events.since(self.next).count()
+    }
+
+    pub fn is_empty(&self, events: &Events<T>) -> bool {
+        self.len(events) == 0
+    }
+
+    /// Mark every stored event as read
+    pub fn clear(&mut self, events: &Events<T>) {
+        self.next = events.next_id();
+    }
+}
+
+/// System parameter reading `Events<T>`
+///
+/// Each system has its own cursor, so several readers all see every event.
+pub struct EventReader<'w, T: Send + Sync + 'static> {
+    pub(crate) events: Res<'w, Events<T>>,
+    pub(crate) cursor: &'w mut EventCursor<T>,
+}
+
+impl<T: Send + Sync + 'static> EventReader<'_, T> {
+    /// Events sent since this system last read, oldest first
+    pub fn read(&mut self) -> impl Iterator<Item = &T> {
+        self.cursor.read(&self.events)
+    }
+
+    /// Number of unread events
+    pub fn len(&self) -> usize {
+        self.cursor.len(&self.events)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Skip every unread event
+    pub fn clear(&mut self) {
+        self.cursor.clear(&self.events);
+    }
+}
+
+/// System parameter sending to `Events<T>`
+pub struct EventWriter<'w, T: Send + Sync + 'static> {
+    pub(crate) events: ResMut<'w, Events<T>>,
+}
+
+impl<T: Send + Sync + 'static> EventWriter<'_, T> {
+    /// Send an event
+    pub fn send(&mut self, event: T) {
+        self.events.send(event);
+    }
+
+    /// Send several events in order
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = T>) {
+        self.events.send_batch(events);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_events_kept_for_two_updates() {
+        let mut events = Events::new();
+        let mut cursor = EventCursor::default();
+        events.send(1);
+        events.update();
+        events.send(2);
+        assert_eq!(cursor.read(&events).copied().collect::<Vec<_>>(), [1, 2]);
+        assert!(cursor.is_empty(&events));
+
+        events.send(3);
+        events.update();
+        events.update();
+        assert!(events.is_empty());
+
+        // A late cursor silently misses dropped events
+        let mut late = EventCursor::default();
+        events.send(4);
+        assert_eq!(late.read(&events).copied().collect::<Vec<_>>(), [4]);
+        assert_eq!(cursor.read(&events).copied().collect::<Vec<_>>(), [4]);
+    }
+}
@@ -69,7 +69,7 @@ macro_rules! define_event {
 }
 
 // Game State Enum (not an event itself, but data for one)
-#[derive(Clone, Debug, PartialEq, Eq)]
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
 pub enum GameState {
     Playing,
     Paused,
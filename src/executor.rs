@@ -88,6 +88,8 @@ pub struct SystemTiming {
 pub struct ExecutionProfile {
     pub total_frame_time: Duration,
     pub system_timings: Vec<SystemTiming>,
+    /// Systems whose run conditions failed this frame
+    pub skipped_systems: Vec<String>,
 }
 
 /// Enhanced profiling statistics
@@ -132,73 +134,47 @@ impl<'s> Executor<'s> {
     }
 
     /// Execute one frame
+    ///
+    /// Runs stages in dependency order and systems in plan order. Each
+    /// system writes to its own command buffer; buffers are applied in plan
+    /// order at the plan's sync points and after each stage.
     pub fn execute_frame(&mut self, world: &mut World) -> Result<()> {
+        let result = self.run_frame_sequential(world);
+        if result.is_err() {
+            self.schedule.clear_commands();
+        }
+        result
+    }
+
+    fn run_frame_sequential(&mut self, world: &mut World) -> Result<()> {
         world.increment_tick();
 
         self.schedule.ensure_built()?;
 
-        // Check if we have named stages with dependencies (not default stages)
-        let has_named_stages = self.schedule.stages.iter().any(|s| s.name != "default");
-
-        if has_named_stages {
-            let frame_start = Instant::now();
-            let mut system_timings = Vec::with_capacity(self.schedule.systems.len());
-            let mut commands = CommandBuffer::new();
-            let parallel_plan = self.schedule.parallel_plan.clone();
-
-            for stage_plan in parallel_plan {
-                for group in stage_plan.parallel_groups {
-                    for &system_id_idx in &group.system_indices {
-                        let system_id = SystemId(system_id_idx as u32);
-                        let system = self
-                            .schedule
-                            .system_mut_by_id(system_id)
-                            .ok_or(EcsError::SystemNotFound)?;
-                        let system_name = system.name();
+        let frame_start = Instant::now();
+        let mut system_timings = Vec::with_capacity(self.schedule.systems.len());
+        let mut skipped_systems = Vec::new();
+        let mut pending = Vec::new();
+        let parallel_plan = self.schedule.parallel_plan.clone();
 
-                        let start = Instant::now();
-                        system.run(world, &mut commands)?;
-                        let duration = start.elapsed();
-
-                        self.profiler.record_execution(system_id, duration);
-                        system_timings.push(SystemTiming {
-                            name: system_name.to_string(),
-                            duration,
-                        });
+        for stage_plan in parallel_plan {
+            let stage_runs = self.schedule.stage_should_run(&stage_plan.name, world);
+            for (group_index, group) in stage_plan.parallel_groups.iter().enumerate() {
+                for &system_id_idx in &group.system_indices {
+                    let system_id = SystemId(system_id_idx as u32);
+                    if !self.should_run(system_id, stage_runs, world, &mut skipped_systems)? {
+                        continue;
                     }
-                }
-                // Flush commands after each stage
-                commands.apply(world)?;
-            }
-
-            let frame_duration = frame_start.elapsed();
-            self.last_profile = Some(ExecutionProfile {
-                total_frame_time: frame_duration,
-                system_timings,
-            });
-        } else {
-            // Use the stage plan for backward compatibility
-            let stage_plan: Vec<Vec<SystemId>> = self
-                .schedule
-                .stage_plan()
-                .iter()
-                .map(|stage| stage.to_vec())
-                .collect();
-            let frame_start = Instant::now();
-            let mut system_timings = Vec::with_capacity(self.schedule.systems.len());
-            let mut commands = CommandBuffer::new();
-
-            for stage in stage_plan {
-                for system_id in stage {
-                    let system = self
+                    let (system, commands) = self
                         .schedule
-                        .system_mut_by_id(system_id)
+                        .system_and_commands(system_id)
                         .ok_or(EcsError::SystemNotFound)?;
                     let system_name = system.name();
 
                     let start = Instant::now();
-                    system.run(world, &mut commands)?;
+                    system.run(world, commands)?;
                     let duration = start.elapsed();
+                    pending.push(system_id_idx);
 
                     self.profiler.record_execution(system_id, duration);
                     system_timings.push(SystemTiming {
@@ -206,16 +182,25 @@ impl<'s> Executor<'s> {
                         duration,
                     });
                 }
-                commands.apply(world)?;
+                // Changes from here on must be newer than the group's last run
+                world.increment_tick();
+                if stage_plan.sync_points.contains(&group_index) {
+                    self.schedule.apply_commands(&pending, world)?;
+                    pending.clear();
+                }
             }
-
-            let frame_duration = frame_start.elapsed();
-            self.last_profile = Some(ExecutionProfile {
-                total_frame_time: frame_duration,
-                system_timings,
-            });
+            // Flush commands after each stage
+            self.schedule.apply_commands(&pending, world)?;
+            pending.clear();
         }
 
+        let frame_duration = frame_start.elapsed();
+        self.last_profile = Some(ExecutionProfile {
+            total_frame_time: frame_duration,
+            system_timings,
+            skipped_systems,
+        });
+
         Ok(())
     }
 
@@ -223,57 +208,113 @@ impl<'s> Executor<'s> {
     ///
     /// Uses the dependency graph to determine which systems can run concurrently.
     /// See `ParallelExecutor::execute_stage` for detailed safety documentation.
+    ///
+    /// Commands are applied exactly as in [`Executor::execute_frame`], so
+    /// both produce the same world state.
     pub fn execute_frame_parallel(&mut self, world: &mut World) -> Result<()> {
+        let result = self.run_frame_parallel(world);
+        if result.is_err() {
+            self.schedule.clear_commands();
+        }
+        result
+    }
+
+    fn run_frame_parallel(&mut self, world: &mut World) -> Result<()> {
         world.increment_tick();
         self.schedule.ensure_built()?;
 
-        let systems_ptr = self.schedule.systems.as_mut_ptr() as usize;
-        let systems_len = self.schedule.systems.len();
+        let frame_start = Instant::now();
+        let mut system_timings = Vec::with_capacity(self.schedule.systems.len());
+        let mut skipped_systems = Vec::new();
+        let mut pending = Vec::new();
         let parallel_plan = self.schedule.parallel_plan.clone();
 
         for stage_plan in parallel_plan {
             #[cfg(feature = "profiling")]
             let _stage_span = info_span!("stage", name = %stage_plan.name).entered();
 
-            for group in stage_plan.parallel_groups {
+            let stage_runs = self.schedule.stage_should_run(&stage_plan.name, world);
+            for (group_index, group) in stage_plan.parallel_groups.iter().enumerate() {
                 use rayon::prelude::*;
 
+                // Conditions read the world, so check them before dispatch
+                let mut system_indices = Vec::with_capacity(group.system_indices.len());
+                for &sys_idx in &group.system_indices {
+                    let system_id = SystemId(sys_idx as u32);
+                    if self.should_run(system_id, stage_runs, world, &mut skipped_systems)? {
+                        system_indices.push(sys_idx);
+                    }
+                }
+
+                let systems_ptr = self.schedule.systems.as_mut_ptr() as usize;
+                let buffers_ptr = self.schedule.command_buffers.as_mut_ptr() as usize;
+                let systems_len = self.schedule.systems.len();
+
                 // SAFETY: We use UnsafeWorldCell to provide disjoint access to threads.
                 // The scheduler (via DependencyGraph) guarantees that systems in the same
                 // group do not have conflicting component accesses.
                 let world_cell = unsafe { world.as_unsafe_world_cell() };
 
-                let results: Vec<(Result<()>, CommandBuffer)> = group
-                    .system_indices
+                let results: Vec<(usize, Result<()>, Duration)> = system_indices
                     .par_iter()
                     .map(move |&sys_idx| {
-                        let mut commands = CommandBuffer::new();
                         if sys_idx >= systems_len {
-                            return (Err(EcsError::SystemNotFound), commands);
+                            return (sys_idx, Err(EcsError::SystemNotFound), Duration::ZERO);
                         }
 
                         // SAFETY:
                         // 1. sys_idx is valid.
                         // 2. Systems in the same group have been proven non-conflicting by the scheduler.
-                        // 3. Each thread handles a unique sys_idx.
+                        // 3. Each thread handles a unique sys_idx, and so a unique
+                        //    command buffer (one per system, sized in `rebuild`).
                         let system =
                             unsafe { &mut *(systems_ptr as *mut Box<dyn System>).add(sys_idx) };
-                        let res = unsafe { system.run_parallel(world_cell, &mut commands) };
-                        (res, commands)
+                        let commands =
+                            unsafe { &mut *(buffers_ptr as *mut CommandBuffer).add(sys_idx) };
+                        // Resource borrows outside the declared access are
+                        // rejected in debug builds
+                        #[cfg(debug_assertions)]
+                        let access = system.accesses();
+                        #[cfg(debug_assertions)]
+                        let world_cell = world_cell.with_access(&access);
+                        let start = Instant::now();
+                        let res = unsafe { system.run_parallel(world_cell, commands) };
+                        (sys_idx, res, start.elapsed())
                     })
                     .collect();
 
-                // Check for errors and collect commands
-                for (result, mut commands) in results {
+                // Results keep plan order regardless of thread timing
+                for (sys_idx, result, duration) in results {
                     result?;
-                    commands.apply(world)?;
+                    pending.push(sys_idx);
+
+                    let system_id = SystemId(sys_idx as u32);
+                    self.profiler.record_execution(system_id, duration);
+                    system_timings.push(SystemTiming {
+                        name: self.schedule.systems[sys_idx].name().to_string(),
+                        duration,
+                    });
                 }
+                // Changes from here on must be newer than the group's last run
+                world.increment_tick();
 
-                // Sync point after each parallel group
+                if stage_plan.sync_points.contains(&group_index) {
+                    self.schedule.apply_commands(&pending, world)?;
+                    pending.clear();
+                }
                 self.barrier(world)?;
             }
+            // Flush commands after each stage
+            self.schedule.apply_commands(&pending, world)?;
+            pending.clear();
         }
 
+        self.last_profile = Some(ExecutionProfile {
+            total_frame_time: frame_start.elapsed(),
+            system_timings,
+            skipped_systems,
+        });
+
         Ok(())
     }
 
@@ -289,12 +330,8 @@ impl<'s> Executor<'s> {
 
     /// Execute systems and process observer events
     pub fn execute_frame_with_events(&mut self, world: &mut World) -> Result<()> {
-        let mut commands = CommandBuffer::new();
         // Execute systems
-        for system in &mut self.schedule.systems {
-            system.run(world, &mut commands)?;
-        }
-        commands.apply(world)?;
+        self.execute_frame(world)?;
 
         // Process queued events
         world.process_events()?;
@@ -307,12 +344,8 @@ impl<'s> Executor<'s> {
         #[cfg(feature = "profiling")]
         let _span = info_span!("execute_frame_full");
 
-        let mut commands = CommandBuffer::new();
         // Execute systems
-        for system in &mut self.schedule.systems {
-            system.run(world, &mut commands)?;
-        }
-        commands.apply(world)?;
+        self.execute_frame(world)?;
 
         // Process all events
         world.process_events()?;
@@ -322,18 +355,11 @@ impl<'s> Executor<'s> {
 
     /// Execute with hierarchy system
     pub fn execute_with_hierarchy(&mut self, world: &mut World) -> Result<()> {
-        use crate::hierarchy_system::HierarchyUpdateSystem;
-
-        let mut commands = CommandBuffer::new();
         // Run hierarchy update first (transforms)
-        let mut hierarchy_system = HierarchyUpdateSystem::new();
-        hierarchy_system.run(world, &mut commands)?;
+        Self::run_hierarchy(world)?;
 
         // Then run user systems
-        for system in &mut self.schedule.systems {
-            system.run(world, &mut commands)?;
-        }
-        commands.apply(world)?;
+        self.execute_frame(world)?;
 
         // Process events if Phase 3 is enabled
         world.process_events()?;
@@ -343,18 +369,11 @@ impl<'s> Executor<'s> {
 
     /// Execute with everything (hierarchy + systems + events)
     pub fn execute_full(&mut self, world: &mut World) -> Result<()> {
-        use crate::hierarchy_system::HierarchyUpdateSystem;
-
-        let mut commands = CommandBuffer::new();
         // Execute hierarchy system
-        let mut hierarchy_system = HierarchyUpdateSystem::new();
-        hierarchy_system.run(world, &mut commands)?;
+        Self::run_hierarchy(world)?;
 
         // Execute user systems
-        for system in &mut self.schedule.systems {
-            system.run(world, &mut commands)?;
-        }
-        commands.apply(world)?;
+        self.execute_frame(world)?;
 
         // Process events
         world.process_events()?;
@@ -364,12 +383,8 @@ impl<'s> Executor<'s> {
 
     /// Execute with global event processing (Phase 6)
     pub fn execute_with_global_events(&mut self, world: &mut World) -> Result<()> {
-        let mut commands = CommandBuffer::new();
         // Execute systems
-        for system in &mut self.schedule.systems {
-            system.run(world, &mut commands)?;
-        }
-        commands.apply(world)?;
+        self.execute_frame(world)?;
 
         // Process global events published by systems
         world.process_global_events()?;
@@ -379,18 +394,11 @@ impl<'s> Executor<'s> {
 
     /// Execute complete frame (hierarchy + systems + global events + entity events)
     pub fn execute_complete_frame(&mut self, world: &mut World) -> Result<()> {
-        use crate::hierarchy_system::HierarchyUpdateSystem;
-
-        let mut commands = CommandBuffer::new();
         // 1. Update hierarchy transforms
-        let mut hierarchy_system = HierarchyUpdateSystem::new();
-        hierarchy_system.run(world, &mut commands)?;
+        Self::run_hierarchy(world)?;
 
         // 2. Execute systems
-        for system in &mut self.schedule.systems {
-            system.run(world, &mut commands)?;
-        }
-        commands.apply(world)?;
+        self.execute_frame(world)?;
 
         // 3. Process global events (Phase 6)
         world.process_global_events()?;
@@ -401,6 +409,37 @@ impl<'s> Executor<'s> {
         Ok(())
     }
 
+    /// Run the hierarchy update and apply its commands
+    fn run_hierarchy(world: &mut World) -> Result<()> {
+        use crate::hierarchy_system::HierarchyUpdateSystem;
+
+        let mut commands = CommandBuffer::new();
+        HierarchyUpdateSystem::new().run(world, &mut commands)?;
+        commands.apply(world)
+    }
+
+    /// Check a system's run conditions, recording it if skipped
+    ///
+    /// `stage_runs` is the result of its stage's conditions; when false the
+    /// system's own conditions aren't evaluated.
+    fn should_run(
+        &mut self,
+        id: SystemId,
+        stage_runs: bool,
+        world: &World,
+        skipped: &mut Vec<String>,
+    ) -> Result<bool> {
+        if stage_runs && self.schedule.system_should_run(id, world) {
+            return Ok(true);
+        }
+        let system = self
+            .schedule
+            .system_mut_by_id(id)
+            .ok_or(EcsError::SystemNotFound)?;
+        skipped.push(system.name().to_string());
+        Ok(false)
+    }
+
     fn barrier(&mut self, _world: &mut World) -> Result<()> {
         // Flush command buffers
         // Compact archetypes (optional)
@@ -532,7 +571,7 @@ impl<'s> Executor<'s> {
         if !stats.system_timings.is_empty() {
             println!("\nSlowest Systems:");
             let mut sorted_timings = stats.system_timings.clone();
-            sorted_timings.sort_by(|a, b| b.duration.cmp(&a.duration));
+            sorted_timings.sort_by_key(|b| std::cmp::Reverse(b.duration));
 
             for (i, timing) in sorted_timings.iter().take(5).enumerate() {
                 let percentage =
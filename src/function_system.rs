@@ -0,0 +1,198 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Plain functions as systems
+//!
+//! Any function whose parameters are [`SystemParam`]s converts into a
+//! [`System`] with [`IntoSystem::into_system`]. Its `SystemAccess` is derived
+//! from the parameter types, so the scheduler always sees what it touches:
+//!
+//! ```
+//! use archetype_ecs::{Commands, IntoSystem, Query, Res, ResMut, System};
+//!
+//! struct Position(f32);
+//! struct Velocity(f32);
+//! struct Gravity(f32);
+//! #[derive(Default)]
+//! struct Moved(usize);
+//!
+//! fn movement(
+//!     mut query: Query<(&mut Position, &Velocity)>,
+//!     gravity: Res<Gravity>,
+//!     mut moved: ResMut<Moved>,
+//!     _commands: Commands,
+//! ) {
+//!     for (pos, vel) in query.iter_mut() {
+//!         pos.0 += vel.0 - gravity.0;
+//!         moved.0 += 1;
+//!     }
+//! }
+//!
+//! let system = movement.into_system();
+//! assert_eq!(system.accesses().writes.len(), 2); // Position, Moved
+//! ```
+//!
+//! Systems may return `()` or `Result<()>`.
+
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::command::CommandBuffer;
+use crate::error::Result;
+use crate::system::{System, SystemAccess};
+use crate::system_param::{SystemParam, SystemParamItem};
+use crate::world::{SavedTick, UnsafeWorldCell, World};
+
+/// Return types allowed for function systems
+pub trait SystemOutput {
+    fn into_result(self) -> Result<()>;
+}
+
+impl SystemOutput for () {
+    fn into_result(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl SystemOutput for Result<()> {
+    fn into_result(self) -> Result<()> {
+        self
+    }
+}
+
+/// A function callable with the items of its system parameters
+///
+/// `Marker` is the function pointer type (`fn(P0, P1) -> Out`) and only
+/// exists to keep the per-arity implementations apart.
+pub trait SystemParamFunction<Marker>: Send + Sync + 'static {
+    /// The parameters as a tuple
+    type Param: SystemParam;
+
+    /// Call the function with fetched parameters
+    fn run(&mut self, param: SystemParamItem<'_, Self::Param>) -> Result<()>;
+}
+
+macro_rules! impl_system_param_function {
+    ($($P:ident),*) => {
+        #[allow(non_snake_case)]
+        impl<Func, Out, $($P: SystemParam),*> SystemParamFunction<fn($($P,)*) -> Out> for Func
+        where
+            Func: Send + Sync + 'static,
+            for<'a> &'a mut Func:
+                FnMut($($P),*) -> Out + FnMut($(SystemParamItem<'_, $P>),*) -> Out,
+            Out: SystemOutput,
+        {
+            type Param = ($($P,)*);
+
+            fn run(&mut self, param: SystemParamItem<'_, Self::Param>) -> Result<()> {
+                // Calling through a helper picks the `FnMut(items)` impl
+                #[allow(clippy::too_many_arguments)]
+                fn call_inner<Out, $($P),*>(mut f: impl FnMut($($P),*) -> Out, $($P: $P),*) -> Out {
+                    f($($P),*)
+                }
+                let ($($P,)*) = param;
+                call_inner(self, $($P),*).into_result()
+            }
+        }
+    };
+}
+
+impl_system_param_function!();
+impl_system_param_function!(A);
+impl_system_param_function!(A, B);
+impl_system_param_function!(A, B, C);
+impl_system_param_function!(A, B, C, D);
+impl_system_param_function!(A, B, C, D, E);
+impl_system_param_function!(A, B, C, D, E, F);
+impl_system_param_function!(A, B, C, D, E, F, G);
+impl_system_param_function!(A, B, C, D, E, F, G, H);
+
+/// Conversion into a [`System`]
+pub trait IntoSystem<Marker> {
+    type System: System;
+
+    /// Convert into a system
+    ///
+    /// # Panics
+    /// Panics if the system's parameters conflict with each other, e.g.
+    /// `Query<&mut A>` alongside `Query<&A>`.
+    fn into_system(self) -> Self::System;
+}
+
+impl<Marker: 'static, F: SystemParamFunction<Marker>> IntoSystem<Marker> for F {
+    type System = FunctionSystem<Marker, F>;
+
+    fn into_system(self) -> Self::System {
+        FunctionSystem::new(self)
+    }
+}
+
+/// A function running as a system
+pub struct FunctionSystem<Marker, F: SystemParamFunction<Marker>> {
+    func: F,
+    access: SystemAccess,
+    param_state: <F::Param as SystemParam>::State,
+    last_run: SavedTick,
+    _marker: PhantomData<fn() -> Marker>,
+}
+
+impl<Marker, F: SystemParamFunction<Marker>> FunctionSystem<Marker, F> {
+    fn new(func: F) -> Self {
+        Self {
+            func,
+            access: F::Param::access(SystemAccess::new()),
+            param_state: Default::default(),
+            last_run: SavedTick::default(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Marker: 'static, F: SystemParamFunction<Marker>> System for FunctionSystem<Marker, F> {
+    fn accesses(&self) -> SystemAccess {
+        self.access.clone()
+    }
+
+    fn name(&self) -> &'static str {
+        std::any::type_name::<F>()
+    }
+
+    fn has_deferred(&self) -> bool {
+        F::Param::has_deferred()
+    }
+
+    fn run(&mut self, world: &mut World, commands: &mut CommandBuffer) -> Result<()> {
+        // SAFETY: Exclusive world access covers every declared access
+        unsafe { self.run_parallel(world.as_unsafe_world_cell(), commands) }
+    }
+
+    unsafe fn run_parallel(
+        &mut self,
+        world: UnsafeWorldCell,
+        commands: &mut CommandBuffer,
+    ) -> Result<()> {
+        let this_run = world.save_tick();
+        // SAFETY: Scheduler guarantees the accesses declared from the
+        // parameters; the command buffer outlives this call
+        let param = F::Param::fetch(
+            world,
+            NonNull::from(commands),
+            world.saved_tick(self.last_run),
+            &mut self.param_state,
+        )?;
+        let result = self.func.run(param);
+        self.last_run = this_run;
+        result
+    }
+}
@@ -1,5 +1,7 @@
 use crate::entity::EntityId;
+use crate::serialization::{EntityMap, MapEntities};
 use serde::{Deserialize, Serialize};
+use slotmap::Key;
 
 /// Parent relationship component
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -15,6 +17,14 @@ impl Parent {
     }
 }
 
+crate::impl_reflect!(Parent);
+
+impl MapEntities for Parent {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.0 = map.get(self.0).unwrap_or_else(EntityId::null);
+    }
+}
+
 /// Children relationship component
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Children {
@@ -74,6 +84,14 @@ impl Default for Children {
     }
 }
 
+crate::impl_reflect!(Children);
+
+impl MapEntities for Children {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.children = self.children.iter().filter_map(|&c| map.get(c)).collect();
+    }
+}
+
 /// Tracks if transform changed (for dirty propagation)
 #[derive(Clone, Copy, Debug)]
 pub struct TransformChanged {
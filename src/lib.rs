@@ -28,20 +28,27 @@
 //!
 //! See `examples/16_profiling_basics.rs` for a complete setup guide.
 
+// Lets `#[derive(...)]` output, which refers to `::archetype_ecs`, work inside this crate
+extern crate self as archetype_ecs;
+
 pub mod app;
 pub mod archetype;
 pub mod bitset;
 pub mod command;
+pub mod condition;
 pub mod component;
+pub mod component_hooks;
 pub mod debug;
 pub mod dependency;
 pub mod entity;
 pub mod error;
 pub mod event;
 pub mod event_bus;
+pub mod event_channel;
 pub mod event_subscriber;
 pub mod event_types;
 pub mod executor;
+pub mod function_system;
 pub mod hierarchy;
 pub mod hierarchy_system;
 pub mod hot_reload;
@@ -52,12 +59,20 @@ pub mod prelude;
 pub mod profiling;
 pub mod query;
 pub mod reflection;
+pub mod relation;
+pub mod removed_components;
+pub mod resource;
+pub mod runner;
 pub mod schedule;
 pub mod serialization;
 pub mod simd;
+pub mod state;
+pub mod storage;
 pub mod system;
+pub mod system_param;
 pub mod time;
 pub mod transform;
+pub mod trigger;
 pub mod world;
 
 #[cfg(test)]
@@ -66,15 +81,19 @@ mod tests;
 pub use app::*;
 pub use archetype::*;
 pub use command::*;
+pub use condition::*;
 pub use component::*;
+pub use component_hooks::{ComponentHooksBuilder, HookWorld};
 pub use dependency::*;
 pub use entity::*;
 pub use error::*;
 pub use event::*;
 pub use event_bus::*;
+pub use event_channel::*;
 pub use event_subscriber::*;
 pub use event_types::*;
 pub use executor::*;
+pub use function_system::*;
 pub use hierarchy::*;
 pub use hierarchy_system::*;
 pub use observer::*;
@@ -82,10 +101,18 @@ pub use parallel::*;
 pub use plugin::*;
 pub use query::*;
 pub use reflection::*;
+pub use relation::*;
+pub use removed_components::{RemovedComponents, RemovedCursor};
+pub use resource::*;
+pub use runner::*;
 pub use schedule::*;
 pub use serialization::*;
+pub use state::*;
+pub use storage::*;
 pub use system::*;
+pub use system_param::*;
 pub use transform::*;
+pub use trigger::{ObserverId, Trigger};
 pub use world::*;
 
 #[cfg(all(test, not(target_env = "msvc")))]
@@ -1,3 +1,4 @@
+use crate::command::CommandBuffer;
 use crate::dependency::{DependencyGraph, ExecutionStage};
 use crate::error::Result;
 use crate::system::System;
@@ -208,6 +209,8 @@ impl Default for TaskScheduler {
 /// Parallel executor using rayon work-stealing with advanced scheduling
 pub struct ParallelExecutor {
     pub systems: Vec<Box<dyn System>>,
+    /// One command buffer per system, applied in stage order after each stage
+    command_buffers: Vec<CommandBuffer>,
     dependency_graph: DependencyGraph,
     scheduler: TaskScheduler,
 }
@@ -224,14 +227,20 @@ impl ParallelExecutor {
         // Debug: print schedule
         graph.print_schedule();
 
+        let command_buffers = systems.iter().map(|_| CommandBuffer::new()).collect();
+
         Self {
             systems,
+            command_buffers,
             dependency_graph: graph,
             scheduler: TaskScheduler::new(),
         }
     }
 
     /// Execute all systems in parallel with optimal scheduling
+    ///
+    /// Commands are applied after each stage in the stage's system order, not
+    /// in completion order, so results don't depend on thread timing.
     pub fn execute_parallel(&mut self, world: &mut World) -> Result<()> {
         // Reset load tracking for this frame
         self.scheduler.reset_load_tracking();
@@ -245,12 +254,35 @@ impl ParallelExecutor {
             let tasks = self.scheduler.schedule_stage(stage, &critical_path);
 
             // Execute stage with scheduled tasks
-            self.execute_stage_scheduled(&tasks, world)?;
+            let result = self.execute_stage_scheduled(&tasks, world);
+            self.finish_stage(stage, result, world)?;
         }
 
         Ok(())
     }
 
+    /// Apply the stage's command buffers in order, or drop them on error
+    fn finish_stage(
+        &mut self,
+        stage: &ExecutionStage,
+        result: Result<()>,
+        world: &mut World,
+    ) -> Result<()> {
+        // Commands must look newer than the stage's last run
+        world.increment_tick();
+        let mut result = result;
+        for &sys_idx in &stage.system_indices {
+            let Some(commands) = self.command_buffers.get_mut(sys_idx) else {
+                continue;
+            };
+            if result.is_ok() {
+                result = commands.apply(world);
+            }
+            commands.clear();
+        }
+        result
+    }
+
     /// Execute a stage with scheduled tasks (priority-based)
     fn execute_stage_scheduled(
         &mut self,
@@ -259,6 +291,7 @@ impl ParallelExecutor {
     ) -> Result<()> {
         // Convert pointers to usize for Send + Sync
         let systems_ptr = self.systems.as_mut_ptr() as usize;
+        let buffers_ptr = self.command_buffers.as_mut_ptr() as usize;
         let world_ptr = world as *mut World as usize;
 
         // SAFETY: Parallel Execution Invariants
@@ -267,6 +300,7 @@ impl ParallelExecutor {
         //    - `systems_ptr` and `world_ptr` are captured BEFORE Rayon spawns threads
         //    - `self.systems` vec is NOT modified during parallel execution
         //    - Each thread gets a unique `sys_idx`, no aliasing of system references
+        //      or of command buffers (one per system)
         //
         // 2. Borrow Checker Satisfaction:
         //    - World is mutably borrowed for 'w (entire parallel section)
@@ -307,13 +341,10 @@ impl ParallelExecutor {
 
                 // SAFETY: Same safety guarantees as before
                 let system = unsafe { &mut *(systems_ptr as *mut Box<dyn System>).add(sys_idx) };
+                let commands = unsafe { &mut *(buffers_ptr as *mut CommandBuffer).add(sys_idx) };
                 let world = unsafe { &mut *(world_ptr as *mut World) };
 
-                let mut commands = crate::command::CommandBuffer::new();
-                let result = system.run(world, &mut commands);
-                if result.is_ok() {
-                    let _ = commands.apply(world);
-                }
+                let result = system.run(world, commands);
                 let duration = start.elapsed();
 
                 (sys_idx, duration, result)
@@ -368,6 +399,7 @@ impl ParallelExecutor {
     fn execute_stage(&mut self, stage: &ExecutionStage, world: &mut World) -> Result<()> {
         // Convert pointers to usize for Send + Sync across thread boundaries
         let systems_ptr = self.systems.as_mut_ptr() as usize;
+        let buffers_ptr = self.command_buffers.as_mut_ptr() as usize;
         let world_ptr = world as *mut World as usize;
 
         // Execute all systems in this stage in parallel using Rayon's work-stealing
@@ -386,6 +418,8 @@ impl ParallelExecutor {
                 // 3. The pointer is valid for the lifetime of this function
                 // 4. No other code is accessing self.systems during parallel execution
                 let system = unsafe { &mut *(systems_ptr as *mut Box<dyn System>).add(sys_idx) };
+                // SAFETY: One buffer per system, so also unique to this thread
+                let commands = unsafe { &mut *(buffers_ptr as *mut CommandBuffer).add(sys_idx) };
 
                 // SAFETY: This is safe because:
                 // 1. The world pointer is valid for the duration of this function
@@ -394,21 +428,13 @@ impl ParallelExecutor {
                 // 4. The ECS architecture prevents data races through archetype isolation
                 let world = unsafe { &mut *(world_ptr as *mut World) };
 
-                let mut commands = crate::command::CommandBuffer::new();
-                let result = system.run(world, &mut commands);
-                if result.is_ok() {
-                    let _ = commands.apply(world);
-                }
-                result
+                system.run(world, commands)
             })
             .collect();
 
         // Propagate any errors from system execution
-        for result in results {
-            result?;
-        }
-
-        Ok(())
+        let result = results.into_iter().collect::<Result<()>>();
+        self.finish_stage(stage, result, world)
     }
 
     /// Get dependency graph for inspection
@@ -1,10 +1,114 @@
 use crate::app::App;
 
 /// Plugin trait for modular application architecture
+///
+/// A plugin's name is its identity: dependencies refer to it, and a unique
+/// plugin can only be added once per app.
 pub trait Plugin {
     /// Get the name of this plugin (for logging and debugging)
     fn plugin_name(&self) -> &'static str;
-    
+
     /// Build the plugin into the app
     fn build(&self, app: &mut App);
+
+    /// Names of plugins that must be added before this one
+    fn dependencies(&self) -> Vec<&'static str> {
+        Vec::new()
+    }
+
+    /// Whether adding this plugin twice is an error
+    fn is_unique(&self) -> bool {
+        true
+    }
+
+    /// Called once every plugin is built, in the order they were added
+    ///
+    /// Use this for setup that needs other plugins' resources or systems.
+    fn finish(&self, _app: &mut App) {}
+
+    /// Called after every plugin's `finish`, in the order they were added
+    fn cleanup(&self, _app: &mut App) {}
+}
+
+/// A bundle of plugins added together
+///
+/// ```
+/// use archetype_ecs::prelude::*;
+/// use archetype_ecs::{PluginGroup, PluginGroupBuilder};
+///
+/// struct Physics;
+/// impl Plugin for Physics {
+///     fn plugin_name(&self) -> &'static str {
+///         "Physics"
+///     }
+///     fn build(&self, _app: &mut App) {}
+/// }
+///
+/// struct Audio;
+/// impl Plugin for Audio {
+///     fn plugin_name(&self) -> &'static str {
+///         "Audio"
+///     }
+///     fn build(&self, _app: &mut App) {}
+/// }
+///
+/// struct DefaultPlugins;
+/// impl PluginGroup for DefaultPlugins {
+///     fn build(self) -> PluginGroupBuilder {
+///         PluginGroupBuilder::new()
+///             .with_plugin(Physics)
+///             .with_plugin(Audio)
+///     }
+/// }
+///
+/// let mut app = App::new();
+/// app.add_plugins(DefaultPlugins.build().disable("Audio"));
+/// assert!(app.is_plugin_added("Physics"));
+/// assert!(!app.is_plugin_added("Audio"));
+/// ```
+pub trait PluginGroup {
+    /// List the plugins of this group, in the order they are added
+    fn build(self) -> PluginGroupBuilder;
+}
+
+/// Ordered list of plugins making up a [`PluginGroup`]
+#[derive(Default)]
+pub struct PluginGroupBuilder {
+    plugins: Vec<Box<dyn Plugin>>,
+}
+
+impl PluginGroupBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a plugin
+    pub fn with_plugin<P: Plugin + 'static>(mut self, plugin: P) -> Self {
+        self.plugins.push(Box::new(plugin));
+        self
+    }
+
+    /// Remove every plugin with the given name
+    pub fn disable(mut self, name: &str) -> Self {
+        self.plugins.retain(|plugin| plugin.plugin_name() != name);
+        self
+    }
+
+    /// Names of the plugins in order
+    pub fn names(&self) -> Vec<&'static str> {
+        self.plugins
+            .iter()
+            .map(|plugin| plugin.plugin_name())
+            .collect()
+    }
+
+    pub(crate) fn into_plugins(self) -> Vec<Box<dyn Plugin>> {
+        self.plugins
+    }
+}
+
+impl PluginGroup for PluginGroupBuilder {
+    fn build(self) -> PluginGroupBuilder {
+        self
+    }
 }
@@ -11,13 +11,21 @@ pub use crate::component::Component;
 pub use crate::debug::{Diagnostics, WorldInspector};
 pub use crate::entity::EntityId;
 pub use crate::error::Result;
+pub use crate::event_channel::{EventReader, EventWriter, Events};
 pub use crate::executor::Executor;
+pub use crate::function_system::IntoSystem;
 pub use crate::hierarchy::{Children, Parent};
 pub use crate::plugin::Plugin;
 pub use crate::query::{Entity, Query, QueryMut, QueryState};
 pub use crate::reflection::{Reflect, TypeRegistry};
+pub use crate::removed_components::RemovedComponents;
+pub use crate::resource::{Res, ResMut};
+pub use crate::runner::AppExit;
 pub use crate::schedule::Schedule;
+pub use crate::state::{in_state, NextState, OnEnter, OnExit, OnTransition, State};
 pub use crate::system::{System, SystemAccess};
+pub use crate::system_param::{Commands, EntityCommands};
 pub use crate::time::{FixedTime, Time};
 pub use crate::transform::{GlobalTransform, LocalTransform, Quat, Vec3};
+pub use crate::trigger::Trigger;
 pub use crate::world::World;
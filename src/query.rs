@@ -36,10 +36,12 @@ pub struct QueryProfilingData {
 use crate::archetype::{Archetype, ComponentColumn};
 use crate::component::Component;
 use crate::entity::EntityId;
-use crate::world::World;
+use crate::storage::{SparseSet, SparseStorage};
+use crate::system::SystemAccess;
+use crate::world::{SavedTick, UnsafeWorldCell, World};
 use smallvec::{smallvec, SmallVec};
 
-const MAX_FILTER_COMPONENTS: usize = 8;
+pub(crate) const MAX_FILTER_COMPONENTS: usize = 8;
 
 /// Component signature for query caching
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
@@ -48,6 +50,8 @@ pub struct QuerySignature {
     pub required: SmallVec<[TypeId; 8]>,
     /// Components that must be absent
     pub excluded: SmallVec<[TypeId; 8]>,
+    /// Disjunctions: each group must have at least one matching signature
+    pub any_of: Vec<Vec<QuerySignature>>,
 }
 
 impl Default for QuerySignature {
@@ -62,14 +66,17 @@ impl QuerySignature {
         Self {
             required: smallvec![],
             excluded: smallvec![],
+            any_of: Vec::new(),
         }
     }
 
     /// Check if an archetype matches this signature
     pub fn matches(&self, archetype: &Archetype) -> bool {
         // Check required components
+        // Sparse-set components aren't part of any archetype, so rows are
+        // filtered per entity at fetch time instead
         for &req in &self.required {
-            if archetype.column_index(req).is_none() {
+            if archetype.column_index(req).is_none() && !archetype.is_sparse(req) {
                 return false;
             }
         }
@@ -81,7 +88,10 @@ impl QuerySignature {
             }
         }
 
-        true
+        // Check disjunctions
+        self.any_of
+            .iter()
+            .all(|group| group.iter().any(|sig| sig.matches(archetype)))
     }
 }
 
@@ -136,7 +146,7 @@ impl CachedQueryResult {
     /// ```
     /// # let mut world = archetype_ecs::World::new();
     /// # for i in 0..100 {
-    /// #     world.spawn((i as f32, 0.0f32));
+    /// #     world.spawn((i as f32, i as u32));
     /// # }
     /// # let mut query = archetype_ecs::CachedQuery::<&mut f32>::new(&world);
     /// // Process components in SIMD chunks for better performance
@@ -305,7 +315,10 @@ where
 
         matched.par_iter().for_each(|&arch_id| {
             if let Some(archetype_ptr) = unsafe { world_cell.get_archetype_ptr(arch_id) } {
-                // SAFETY: We have unique access to this archetype in this thread.
+                // SAFETY: We have unique access to this archetype's columns in
+                // this thread. Sparse components are shared across archetypes
+                // but only borrowed per entity, and each entity is in one
+                // archetype.
                 let archetype_w = unsafe { &mut *archetype_ptr.as_ptr() };
                 let len = archetype_w.len();
                 if let Some(mut state) = Q::prepare(archetype_w, 0, current_tick) {
@@ -587,11 +600,84 @@ pub unsafe trait QueryFetch<'w>: QueryFilter {
     unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item>;
 }
 
+/// Where a component fetch reads from within one archetype
+pub enum ComponentSource<'w, T: Component> {
+    /// Archetype column, indexed by row
+    Table(&'w ComponentColumn),
+    /// Sparse set, indexed by the row's entity
+    Sparse(&'w [EntityId], &'w SparseSet<T>),
+}
+
+impl<'w, T: Component> ComponentSource<'w, T> {
+    fn new(archetype: &'w Archetype) -> Option<Self> {
+        if let Some(column) = archetype.get_column(TypeId::of::<T>()) {
+            return Some(Self::Table(column));
+        }
+        let set = archetype.sparse_set::<T>()?;
+        Some(Self::Sparse(archetype.entities(), set))
+    }
+
+    fn get(&self, row: usize) -> Option<&'w T> {
+        match *self {
+            Self::Table(column) => column.get::<T>(row),
+            Self::Sparse(entities, set) => set.get(*entities.get(row)?),
+        }
+    }
+}
+
+/// Mutable counterpart of [`ComponentSource`]
+pub enum ComponentSourceMut<T: Component> {
+    /// Archetype column, indexed by row
+    Table(*mut ComponentColumn),
+    /// Sparse set, indexed by the row's entity
+    ///
+    /// Only ever borrowed shared: the set is world-wide, so fetches on
+    /// other archetypes (e.g. other `ParQuery` tasks) use it concurrently.
+    Sparse(*const [EntityId], *const SparseSet<T>),
+}
+
+impl<T: Component> ComponentSourceMut<T> {
+    fn new(archetype: &mut Archetype) -> Option<Self> {
+        let entities = archetype.entities() as *const [EntityId];
+        if let Some(column) = archetype.get_column_mut(TypeId::of::<T>()) {
+            return Some(Self::Table(column as *mut ComponentColumn));
+        }
+        let set = archetype.sparse_set::<T>()? as *const SparseSet<T>;
+        Some(Self::Sparse(entities, set))
+    }
+
+    /// # Safety
+    /// The archetype used in `new` must outlive `'w`
+    unsafe fn get<'w>(&self, row: usize) -> Option<&'w T> {
+        match *self {
+            Self::Table(column) => (*column).get::<T>(row),
+            Self::Sparse(entities, set) => (*set).get(*(&*entities).get(row)?),
+        }
+    }
+
+    /// # Safety
+    /// The archetype used in `new` must outlive `'w`, and `row` must not be
+    /// borrowed elsewhere
+    unsafe fn get_mut<'w>(&mut self, row: usize, tick: u32) -> Option<&'w mut T> {
+        match *self {
+            Self::Table(column) => {
+                let column = &mut *column;
+                column.set_changed_tick(row, tick);
+                column.get_mut::<T>(row)
+            }
+            // Each row is a distinct entity, so this is the only borrow of
+            // its value even while other archetypes fetch from the same set
+            Self::Sparse(entities, set) => (*set).get_unchecked_mut(*(&*entities).get(row)?, tick),
+        }
+    }
+}
+
 // QueryFetch implementations for immutable component access
 
 impl<T: Component> QueryFilter for &T {
     fn matches_archetype(archetype: &Archetype) -> bool {
-        archetype.column_index(TypeId::of::<T>()).is_some()
+        let type_id = TypeId::of::<T>();
+        archetype.column_index(type_id).is_some() || archetype.is_sparse(type_id)
     }
 
     fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
@@ -601,15 +687,14 @@ impl<T: Component> QueryFilter for &T {
 
 unsafe impl<'w, T: Component> QueryFetch<'w> for &'w T {
     type Item = &'w T;
-    type State = &'w ComponentColumn;
+    type State = ComponentSource<'w, T>;
 
     fn prepare(archetype: &'w Archetype, _change_tick: u32) -> Option<Self::State> {
-        let type_id = TypeId::of::<T>();
-        archetype.get_column(type_id)
+        ComponentSource::new(archetype)
     }
 
     unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
-        state.get::<T>(row)
+        state.get(row)
     }
 }
 
@@ -640,7 +725,8 @@ pub unsafe trait QueryFetchMut<'w>: QueryFilter {
 
 impl<T: Component> QueryFilter for &mut T {
     fn matches_archetype(archetype: &Archetype) -> bool {
-        archetype.column_index(TypeId::of::<T>()).is_some()
+        let type_id = TypeId::of::<T>();
+        archetype.column_index(type_id).is_some() || archetype.is_sparse(type_id)
     }
 
     fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
@@ -650,24 +736,20 @@ impl<T: Component> QueryFilter for &mut T {
 
 unsafe impl<'w, T: Component> QueryFetchMut<'w> for &'w mut T {
     type Item = &'w mut T;
-    type State = (*mut ComponentColumn, u32);
+    type State = (ComponentSourceMut<T>, u32);
 
     fn prepare(
         archetype: &'w mut Archetype,
         _change_tick: u32,
         current_tick: u32,
     ) -> Option<Self::State> {
-        let type_id = TypeId::of::<T>();
-        let column = archetype.get_column_mut(type_id)?;
-        Some((column as *mut ComponentColumn, current_tick))
+        Some((ComponentSourceMut::new(archetype)?, current_tick))
     }
 
     unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
-        let (column_ptr, current_tick) = state;
-        // SAFETY: Column pointer valid for 'w, row bounds checked
-        let column = unsafe { &mut **column_ptr };
-        column.set_changed_tick(row, *current_tick);
-        column.get_mut::<T>(row)
+        let (source, current_tick) = state;
+        // SAFETY: Source valid for 'w, row bounds checked
+        unsafe { source.get_mut(row, *current_tick) }
     }
 }
 
@@ -675,23 +757,98 @@ unsafe impl<'w, T: Component> QueryFetchMut<'w> for &'w mut T {
 /// Example: `world.query_mut::<(&Position, &mut Velocity)>()`
 unsafe impl<'w, T: Component> QueryFetchMut<'w> for &'w T {
     type Item = &'w T;
-    type State = *const ComponentColumn;
+    type State = ComponentSourceMut<T>;
 
     fn prepare(
         archetype: &'w mut Archetype,
         _change_tick: u32,
         _current_tick: u32,
     ) -> Option<Self::State> {
-        let type_id = TypeId::of::<T>();
-        archetype
-            .get_column(type_id)
-            .map(|col| col as *const ComponentColumn)
+        ComponentSourceMut::new(archetype)
+    }
+
+    unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+        // SAFETY: Source valid for 'w lifetime
+        unsafe { state.get(row) }
+    }
+}
+
+// Optional component access
+//
+// `Option<&T>` and `Option<&mut T>` match every archetype and add nothing to
+// the query signature, so they never narrow which archetypes a query visits.
+// Rows from archetypes without a `T` column yield `None`.
+
+impl<T: Component> QueryFilter for Option<&T> {
+    fn matches_archetype(_archetype: &Archetype) -> bool {
+        true
+    }
+
+    fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
+        smallvec![]
+    }
+}
+
+unsafe impl<'w, T: Component> QueryFetch<'w> for Option<&'w T> {
+    type Item = Option<&'w T>;
+    type State = Option<ComponentSource<'w, T>>;
+
+    fn prepare(archetype: &'w Archetype, _change_tick: u32) -> Option<Self::State> {
+        Some(ComponentSource::new(archetype))
+    }
+
+    unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+        Some(state.as_ref().and_then(|source| source.get(row)))
+    }
+}
+
+unsafe impl<'w, T: Component> QueryFetchMut<'w> for Option<&'w T> {
+    type Item = Option<&'w T>;
+    type State = Option<ComponentSourceMut<T>>;
+
+    fn prepare(
+        archetype: &'w mut Archetype,
+        _change_tick: u32,
+        _current_tick: u32,
+    ) -> Option<Self::State> {
+        Some(ComponentSourceMut::new(archetype))
+    }
+
+    unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+        // SAFETY: Source valid for 'w lifetime
+        Some(state.as_ref().and_then(|source| unsafe { source.get(row) }))
+    }
+}
+
+impl<T: Component> QueryFilter for Option<&mut T> {
+    fn matches_archetype(_archetype: &Archetype) -> bool {
+        true
+    }
+
+    fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
+        smallvec![]
+    }
+}
+
+unsafe impl<'w, T: Component> QueryFetchMut<'w> for Option<&'w mut T> {
+    type Item = Option<&'w mut T>;
+    type State = (Option<ComponentSourceMut<T>>, u32);
+
+    fn prepare(
+        archetype: &'w mut Archetype,
+        _change_tick: u32,
+        current_tick: u32,
+    ) -> Option<Self::State> {
+        Some((ComponentSourceMut::new(archetype), current_tick))
     }
 
     unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
-        // SAFETY: Pointer valid for 'w lifetime
-        let column = unsafe { &**state };
-        column.get::<T>(row)
+        let (source, current_tick) = state;
+        let Some(source) = source else {
+            return Some(None);
+        };
+        // SAFETY: Source valid for 'w, row bounds checked
+        Some(unsafe { source.get_mut(row, *current_tick) })
     }
 }
 
@@ -894,6 +1051,119 @@ unsafe impl<'w, A: QueryFetch<'w>, B: QueryFetch<'w>, C: QueryFetch<'w>, D: Quer
     }
 }
 
+// Larger tuples follow the same pattern as the manual impls above
+
+macro_rules! impl_query_fetch_tuple {
+    ($($T:ident $index:tt),*) => {
+        unsafe impl<'w, $($T: QueryFetch<'w>),*> QueryFetch<'w> for ($($T,)*) {
+            type Item = ($($T::Item,)*);
+            type State = ($($T::State,)*);
+
+            fn prepare(archetype: &'w Archetype, change_tick: u32) -> Option<Self::State> {
+                Some(($($T::prepare(archetype, change_tick)?,)*))
+            }
+
+            unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+                Some(($($T::fetch(&state.$index, row)?,)*))
+            }
+        }
+
+        unsafe impl<'w, $($T: QueryFetchMut<'w>),*> QueryFetchMut<'w> for ($($T,)*)
+        where
+            $($T: QueryFilter,)*
+        {
+            type Item = ($($T::Item,)*);
+            type State = ($($T::State,)*);
+
+            fn prepare(
+                archetype: &'w mut Archetype,
+                change_tick: u32,
+                current_tick: u32,
+            ) -> Option<Self::State> {
+                let ptr = archetype as *mut Archetype;
+                Some(($($T::prepare(unsafe { &mut *ptr }, change_tick, current_tick)?,)*))
+            }
+
+            unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+                Some(($($T::fetch(&mut state.$index, row)?,)*))
+            }
+        }
+    };
+}
+
+impl_query_fetch_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_query_fetch_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_query_fetch_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_query_fetch_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Query terms usable as a function system parameter
+///
+/// Function systems are written with elided lifetimes
+/// (`Query<(&A, &mut B)>`); `Fetch<'w>` is the same query re-borrowed from
+/// the world for one run. `access` reports the components the query touches
+/// so the system's `SystemAccess` can be derived from its signature.
+pub trait QueryData: QueryFilter {
+    /// This query borrowing from the world for `'w`
+    type Fetch<'w>: QueryFilter + QueryFetchMut<'w>;
+
+    /// Add this query's component reads and writes to `access`
+    fn access(access: SystemAccess) -> SystemAccess;
+}
+
+impl<T: Component> QueryData for &T {
+    type Fetch<'w> = &'w T;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.read::<T>()
+    }
+}
+
+impl<T: Component> QueryData for &mut T {
+    type Fetch<'w> = &'w mut T;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.write::<T>()
+    }
+}
+
+impl<T: Component> QueryData for Option<&T> {
+    type Fetch<'w> = Option<&'w T>;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.read::<T>()
+    }
+}
+
+impl<T: Component> QueryData for Option<&mut T> {
+    type Fetch<'w> = Option<&'w mut T>;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.write::<T>()
+    }
+}
+
+macro_rules! impl_query_data {
+    ($($T:ident),*) => {
+        impl<$($T: QueryData),*> QueryData for ($($T,)*) {
+            type Fetch<'w> = ($($T::Fetch<'w>,)*);
+
+            fn access(access: SystemAccess) -> SystemAccess {
+                $(let access = $T::access(access);)*
+                access
+            }
+        }
+    };
+}
+
+impl_query_data!(A);
+impl_query_data!(A, B);
+impl_query_data!(A, B, C);
+impl_query_data!(A, B, C, D);
+impl_query_data!(A, B, C, D, E);
+impl_query_data!(A, B, C, D, E, F);
+impl_query_data!(A, B, C, D, E, F, G);
+impl_query_data!(A, B, C, D, E, F, G, H);
+
 /// Cached query state
 ///
 /// Pre-computes which archetypes match the query filter.
@@ -1028,7 +1298,7 @@ impl<F: QueryFilter> QueryState<F> {
     /// ```
     /// # let mut world = archetype_ecs::World::new();
     /// # for i in 0..100 {
-    /// #     world.spawn((i as f32, 0.0f32));
+    /// #     world.spawn((i as f32, i as u32));
     /// # }
     /// # let mut query = archetype_ecs::CachedQuery::<&mut f32>::new(&world);
     /// // Process components in SIMD chunks for better performance
@@ -1056,47 +1326,112 @@ impl<F: QueryFilter> QueryState<F> {
     }
 }
 
-/// Stateless query wrapper
+/// Query wrapper
+///
+/// `World::query` creates a read-only query. Function systems receive queries
+/// with exclusive access to their declared components, which also allow
+/// `iter_mut` for queries containing `&mut T`.
 pub struct Query<'w, Q>
 where
-    Q: QueryFilter + QueryFetch<'w>,
+    Q: QueryFilter,
 {
-    world: &'w World,
-    _phantom: PhantomData<Q>,
+    world: NonNull<World>,
+    change_tick: u32,
+    exclusive: bool,
+    _phantom: PhantomData<(&'w World, Q)>,
 }
 
 impl<'w, Q> Query<'w, Q>
 where
-    Q: QueryFilter + QueryFetch<'w>,
+    Q: QueryFilter,
 {
     /// Create query
-    pub fn new(world: &'w World) -> Self {
+    pub fn new(world: &'w World) -> Self
+    where
+        Q: QueryFetch<'w>,
+    {
         Self {
-            world,
+            world: NonNull::from(world),
+            change_tick: 0,
+            exclusive: false,
+            _phantom: PhantomData,
+        }
+    }
+
+    /// Create a query for a function system
+    ///
+    /// `change_tick` is the tick the system last ran at, used by `Changed<T>`
+    /// and `Added<T>`.
+    ///
+    /// # Safety
+    /// The caller must have exclusive access to every component `Q` writes
+    /// and shared access to every component it reads for `'w`.
+    pub(crate) unsafe fn from_world_cell(world: UnsafeWorldCell<'w>, change_tick: u32) -> Self {
+        Self {
+            // SAFETY: World pointer is never null
+            world: unsafe { NonNull::new_unchecked(world.world_ptr()) },
+            change_tick,
+            exclusive: true,
             _phantom: PhantomData,
         }
     }
 
+    pub(crate) fn world(&self) -> &'w World {
+        // SAFETY: Pointer valid for 'w, created from a live world
+        unsafe { self.world.as_ref() }
+    }
+
+    /// Tick `Changed<T>` and `Added<T>` compare against
+    pub(crate) fn change_tick(&self) -> u32 {
+        self.change_tick
+    }
+
     /// Iterate query - uses world cache for performance
-    pub fn iter(&self) -> QueryIterOwned<'w, Q> {
-        let matched = self.world.get_cached_query_indices::<Q>();
+    pub fn iter(&self) -> QueryIterOwned<'w, Q>
+    where
+        Q: QueryFetch<'w>,
+    {
+        let world = self.world();
+        let matched = world.get_cached_query_indices::<Q>();
         QueryIterOwned {
-            world: self.world,
+            world,
             matches: matched,
             archetype_index: 0,
             entity_index: 0,
-            change_tick: 0, // Stateless query matches everything
+            change_tick: self.change_tick,
             state: None,
             _phantom: PhantomData,
         }
     }
 
+    /// Iterate query mutably
+    ///
+    /// # Panics
+    /// Panics if the query was created by `World::query`, which only grants
+    /// shared access. Use `World::query_mut` there instead.
+    pub fn iter_mut(&mut self) -> QueryIterMut<'_, Q::Fetch<'_>>
+    where
+        Q: QueryData,
+    {
+        assert!(
+            self.exclusive,
+            "Query::iter_mut requires a query owned by a function system"
+        );
+        // SAFETY: Exclusive queries come from a system holding the accesses
+        // this query declares; `&mut self` prevents overlapping iterators
+        let world = unsafe { &mut *self.world.as_ptr() };
+        let matched = world.get_cached_query_indices::<Q::Fetch<'_>>();
+        let current_tick = world.tick();
+        QueryIterMut::new(world, &matched, self.change_tick, current_tick)
+    }
+
     /// Count matching entities - uses world cache
     pub fn count(&self) -> usize {
-        let matched = self.world.get_cached_query_indices::<Q>();
+        let world = self.world();
+        let matched = world.get_cached_query_indices::<Q>();
         matched
             .iter()
-            .filter_map(|&id| self.world.get_archetype(id))
+            .filter_map(|&id| world.get_archetype(id))
             .map(|arch| arch.len())
             .sum()
     }
@@ -1182,7 +1517,7 @@ where
 /// Automatically updates when new archetypes are added.
 pub struct CachedQuery<F: QueryFilter> {
     state: QueryState<F>,
-    last_run_tick: u32,
+    last_run: SavedTick,
 }
 
 impl<F: QueryFilter> CachedQuery<F> {
@@ -1190,7 +1525,7 @@ impl<F: QueryFilter> CachedQuery<F> {
     pub fn new(world: &World) -> Self {
         Self {
             state: QueryState::new(world),
-            last_run_tick: 0,
+            last_run: SavedTick::default(),
         }
     }
 
@@ -1200,8 +1535,8 @@ impl<F: QueryFilter> CachedQuery<F> {
         F: QueryFetch<'w>,
     {
         self.state.update(world);
-        let iter = self.state.iter(world, self.last_run_tick);
-        self.last_run_tick = world.tick();
+        let iter = self.state.iter(world, world.saved_tick(self.last_run));
+        self.last_run = world.save_tick();
         iter
     }
 
@@ -1214,9 +1549,10 @@ impl<F: QueryFilter> CachedQuery<F> {
         // Ideally, update should be called before getting mutable access
         // For now, we assume state is up to date or user called update manually if needed
         // self.state.update(world);
-        let tick = world.tick();
-        let iter = self.state.iter_mut(world, self.last_run_tick);
-        self.last_run_tick = tick;
+        let this_run = world.save_tick();
+        let last_run = world.saved_tick(self.last_run);
+        let iter = self.state.iter_mut(world, last_run);
+        self.last_run = this_run;
         iter
     }
 }
@@ -1228,7 +1564,8 @@ pub struct With<T>(PhantomData<T>);
 
 impl<T: 'static> QueryFilter for With<T> {
     fn matches_archetype(archetype: &Archetype) -> bool {
-        archetype.signature().contains(&TypeId::of::<T>())
+        let type_id = TypeId::of::<T>();
+        archetype.signature().contains(&type_id) || archetype.is_sparse(type_id)
     }
 
     fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
@@ -1236,33 +1573,56 @@ impl<T: 'static> QueryFilter for With<T> {
     }
 }
 
+/// Per-row presence check for a sparse-set component, `None` for table storage
+type SparseCheck<'w> = Option<(&'w [EntityId], &'w SparseStorage)>;
+
+fn sparse_check<T: 'static>(archetype: &Archetype) -> SparseCheck<'_> {
+    if archetype.is_sparse(TypeId::of::<T>()) {
+        Some((archetype.entities(), archetype.sparse_storage()?))
+    } else {
+        None
+    }
+}
+
+fn sparse_contains<T: 'static>(check: &SparseCheck<'_>, row: usize) -> Option<bool> {
+    let (entities, storage) = (*check)?;
+    Some(
+        entities
+            .get(row)
+            .is_some_and(|&entity| storage.contains(TypeId::of::<T>(), entity)),
+    )
+}
+
 unsafe impl<'w, T: 'static> QueryFetch<'w> for With<T> {
     type Item = ();
-    type State = ();
+    type State = SparseCheck<'w>;
 
-    fn prepare(_archetype: &'w Archetype, _change_tick: u32) -> Option<Self::State> {
-        Some(())
+    fn prepare(archetype: &'w Archetype, _change_tick: u32) -> Option<Self::State> {
+        Some(sparse_check::<T>(archetype))
     }
 
-    unsafe fn fetch(_state: &Self::State, _row: usize) -> Option<Self::Item> {
-        Some(())
+    unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+        match sparse_contains::<T>(state, row) {
+            Some(false) => None,
+            _ => Some(()),
+        }
     }
 }
 
 unsafe impl<'w, T: 'static> QueryFetchMut<'w> for With<T> {
     type Item = ();
-    type State = ();
+    type State = SparseCheck<'w>;
 
     fn prepare(
-        _archetype: &'w mut Archetype,
+        archetype: &'w mut Archetype,
         _change_tick: u32,
         _current_tick: u32,
     ) -> Option<Self::State> {
-        Some(())
+        <With<T> as QueryFetch>::prepare(archetype, 0)
     }
 
-    unsafe fn fetch(_state: &mut Self::State, _row: usize) -> Option<Self::Item> {
-        Some(())
+    unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+        <With<T> as QueryFetch>::fetch(state, row)
     }
 }
 
@@ -1287,31 +1647,34 @@ impl<T: 'static> QueryFilter for Without<T> {
 
 unsafe impl<'w, T: 'static> QueryFetch<'w> for Without<T> {
     type Item = ();
-    type State = ();
+    type State = SparseCheck<'w>;
 
-    fn prepare(_archetype: &'w Archetype, _change_tick: u32) -> Option<Self::State> {
-        Some(())
+    fn prepare(archetype: &'w Archetype, _change_tick: u32) -> Option<Self::State> {
+        Some(sparse_check::<T>(archetype))
     }
 
-    unsafe fn fetch(_state: &Self::State, _row: usize) -> Option<Self::Item> {
-        Some(())
+    unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+        match sparse_contains::<T>(state, row) {
+            Some(true) => None,
+            _ => Some(()),
+        }
     }
 }
 
 unsafe impl<'w, T: 'static> QueryFetchMut<'w> for Without<T> {
     type Item = ();
-    type State = ();
+    type State = SparseCheck<'w>;
 
     fn prepare(
-        _archetype: &'w mut Archetype,
+        archetype: &'w mut Archetype,
         _change_tick: u32,
         _current_tick: u32,
     ) -> Option<Self::State> {
-        Some(())
+        <Without<T> as QueryFetch>::prepare(archetype, 0)
     }
 
-    unsafe fn fetch(_state: &mut Self::State, _row: usize) -> Option<Self::Item> {
-        Some(())
+    unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+        <Without<T> as QueryFetch>::fetch(state, row)
     }
 }
 
@@ -1377,7 +1740,8 @@ pub struct Changed<T: Component>(PhantomData<T>);
 
 impl<T: Component> QueryFilter for Changed<T> {
     fn matches_archetype(archetype: &Archetype) -> bool {
-        archetype.column_index(TypeId::of::<T>()).is_some()
+        let type_id = TypeId::of::<T>();
+        archetype.column_index(type_id).is_some() || archetype.is_sparse(type_id)
     }
 
     fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
@@ -1412,6 +1776,7 @@ macro_rules! impl_query_filter {
                     let child_sig = $T::signature();
                     sig.required.extend(child_sig.required);
                     sig.excluded.extend(child_sig.excluded);
+                    sig.any_of.extend(child_sig.any_of);
                 )*
                 sig.required.sort();
                 sig.excluded.sort();
@@ -1442,12 +1807,47 @@ impl_query_filter!(A, B, C, D, E, F, G, H);
 // Let's check if there are manual implementations of QueryFilter.
 // It seems `impl_query_filter!` covers all tuples up to H (8).
 // The macro above is what I need to replace.
+/// Where a change filter reads `T`'s ticks within one archetype
+pub enum TickSource<'w, T: Component> {
+    /// Column tick array, indexed by row
+    Table(&'w [u32]),
+    /// Sparse set, indexed by the row's entity
+    Sparse(&'w [EntityId], &'w SparseSet<T>),
+}
+
+impl<'w, T: Component> TickSource<'w, T> {
+    /// Read the sparse set of `T`, if `T` uses sparse-set storage
+    fn sparse(archetype: &'w Archetype) -> Option<Self> {
+        Some(Self::Sparse(
+            archetype.entities(),
+            archetype.sparse_set::<T>()?,
+        ))
+    }
+
+    /// Whether the row's tick, `changed` or added, is newer than `change_tick`
+    fn is_newer(&self, row: usize, change_tick: u32, changed: bool) -> bool {
+        let tick = match *self {
+            Self::Table(ticks) => ticks.get(row).copied(),
+            Self::Sparse(entities, set) => entities.get(row).and_then(|&entity| {
+                if changed {
+                    set.changed_tick(entity)
+                } else {
+                    set.added_tick(entity)
+                }
+            }),
+        };
+        tick.is_some_and(|tick| tick > change_tick)
+    }
+}
+
 unsafe impl<'w, T: Component> QueryFetch<'w> for Changed<T> {
     type Item = ();
-    type State = (&'w [u32], u32);
+    type State = (TickSource<'w, T>, u32);
 
     fn prepare(archetype: &'w Archetype, change_tick: u32) -> Option<Self::State> {
-        let idx = archetype.column_index(TypeId::of::<T>())?;
+        let Some(idx) = archetype.column_index(TypeId::of::<T>()) else {
+            return Some((TickSource::sparse(archetype)?, change_tick));
+        };
         let col = archetype.get_column_by_index(idx)?;
 
         // Chunk-level optimization: skip if no changes in this archetype
@@ -1455,21 +1855,17 @@ unsafe impl<'w, T: Component> QueryFetch<'w> for Changed<T> {
             return None;
         }
 
-        Some((&col.changed_ticks, change_tick))
+        Some((TickSource::Table(&col.changed_ticks), change_tick))
     }
 
     unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
-        if row < state.0.len() && state.0[row] > state.1 {
-            Some(())
-        } else {
-            None
-        }
+        state.0.is_newer(row, state.1, true).then_some(())
     }
 }
 
 unsafe impl<'w, T: Component> QueryFetchMut<'w> for Changed<T> {
     type Item = ();
-    type State = (&'w [u32], u32);
+    type State = (TickSource<'w, T>, u32);
 
     fn prepare(
         archetype: &'w mut Archetype,
@@ -1489,7 +1885,8 @@ pub struct Added<T>(PhantomData<T>);
 
 impl<T: Component> QueryFilter for Added<T> {
     fn matches_archetype(archetype: &Archetype) -> bool {
-        archetype.signature().contains(&TypeId::of::<T>())
+        let type_id = TypeId::of::<T>();
+        archetype.signature().contains(&type_id) || archetype.is_sparse(type_id)
     }
 
     fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
@@ -1505,10 +1902,12 @@ impl<T: Component> QueryFilter for Added<T> {
 
 unsafe impl<'w, T: Component> QueryFetch<'w> for Added<T> {
     type Item = ();
-    type State = (&'w [u32], u32);
+    type State = (TickSource<'w, T>, u32);
 
     fn prepare(archetype: &'w Archetype, change_tick: u32) -> Option<Self::State> {
-        let idx = archetype.column_index(TypeId::of::<T>())?;
+        let Some(idx) = archetype.column_index(TypeId::of::<T>()) else {
+            return Some((TickSource::sparse(archetype)?, change_tick));
+        };
         let col = archetype.get_column_by_index(idx)?;
 
         // Chunk-level optimization: skip if no additions in this archetype
@@ -1516,21 +1915,17 @@ unsafe impl<'w, T: Component> QueryFetch<'w> for Added<T> {
             return None;
         }
 
-        Some((&col.added_ticks, change_tick))
+        Some((TickSource::Table(&col.added_ticks), change_tick))
     }
 
     unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
-        if row < state.0.len() && state.0[row] > state.1 {
-            Some(())
-        } else {
-            None
-        }
+        state.0.is_newer(row, state.1, false).then_some(())
     }
 }
 
 unsafe impl<'w, T: Component> QueryFetchMut<'w> for Added<T> {
     type Item = ();
-    type State = (&'w [u32], u32);
+    type State = (TickSource<'w, T>, u32);
 
     fn prepare(
         archetype: &'w mut Archetype,
@@ -1545,6 +1940,227 @@ unsafe impl<'w, T: Component> QueryFetchMut<'w> for Added<T> {
     }
 }
 
+/// Filter matching entities that satisfy at least one of the filters in `T`
+///
+/// Usage: `Query<(&Health, Or<(With<Enemy>, With<Boss>)>)>`. Row-level
+/// filters such as `Changed<T>` are supported inside the disjunction.
+pub struct Or<T>(PhantomData<T>);
+
+/// Fetch yielding each of the components in `T` that the entity has
+///
+/// Usage: `Query<AnyOf<(&Sprite, &Mesh)>>` yields `(Option<&Sprite>, Option<&Mesh>)`
+/// for every entity that has at least one of the two components.
+pub struct AnyOf<T>(PhantomData<T>);
+
+macro_rules! impl_any_of {
+    ($($T:ident),*) => {
+        impl<$($T: QueryFilter),*> QueryFilter for Or<($($T,)*)> {
+            fn matches_archetype(archetype: &Archetype) -> bool {
+                $($T::matches_archetype(archetype))||*
+            }
+
+            fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
+                smallvec![]
+            }
+
+            fn signature() -> QuerySignature {
+                let mut sig = QuerySignature::new();
+                sig.any_of.push(vec![$($T::signature()),*]);
+                sig
+            }
+        }
+
+        #[allow(non_snake_case)]
+        unsafe impl<'w, $($T: QueryFetch<'w>),*> QueryFetch<'w> for Or<($($T,)*)> {
+            type Item = ();
+            type State = ($(Option<$T::State>,)*);
+
+            fn prepare(archetype: &'w Archetype, change_tick: u32) -> Option<Self::State> {
+                $(
+                    let $T = if $T::matches_archetype(archetype) {
+                        $T::prepare(archetype, change_tick)
+                    } else {
+                        None
+                    };
+                )*
+                if $($T.is_none())&&* {
+                    return None;
+                }
+                Some(($($T,)*))
+            }
+
+            unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+                let ($($T,)*) = state;
+                if $($T.as_ref().is_some_and(|s| $T::fetch(s, row).is_some()))||* {
+                    Some(())
+                } else {
+                    None
+                }
+            }
+        }
+
+        #[allow(non_snake_case)]
+        unsafe impl<'w, $($T: QueryFetchMut<'w>),*> QueryFetchMut<'w> for Or<($($T,)*)> {
+            type Item = ();
+            type State = ($(Option<$T::State>,)*);
+
+            fn prepare(
+                archetype: &'w mut Archetype,
+                change_tick: u32,
+                current_tick: u32,
+            ) -> Option<Self::State> {
+                // SAFETY: Each filter only reads its own columns
+                let ptr = archetype as *mut Archetype;
+                $(
+                    let $T = if $T::matches_archetype(unsafe { &*ptr }) {
+                        $T::prepare(unsafe { &mut *ptr }, change_tick, current_tick)
+                    } else {
+                        None
+                    };
+                )*
+                if $($T.is_none())&&* {
+                    return None;
+                }
+                Some(($($T,)*))
+            }
+
+            unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+                let ($($T,)*) = state;
+                if $($T.as_mut().is_some_and(|s| $T::fetch(s, row).is_some()))||* {
+                    Some(())
+                } else {
+                    None
+                }
+            }
+        }
+
+        impl<$($T: QueryFilter),*> QueryFilter for AnyOf<($($T,)*)> {
+            fn matches_archetype(archetype: &Archetype) -> bool {
+                $($T::matches_archetype(archetype))||*
+            }
+
+            fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
+                smallvec![]
+            }
+
+            fn signature() -> QuerySignature {
+                <Or<($($T,)*)> as QueryFilter>::signature()
+            }
+        }
+
+        #[allow(non_snake_case)]
+        unsafe impl<'w, $($T: QueryFetch<'w>),*> QueryFetch<'w> for AnyOf<($($T,)*)> {
+            type Item = ($(Option<$T::Item>,)*);
+            type State = ($(Option<$T::State>,)*);
+
+            fn prepare(archetype: &'w Archetype, change_tick: u32) -> Option<Self::State> {
+                <Or<($($T,)*)> as QueryFetch<'w>>::prepare(archetype, change_tick)
+            }
+
+            unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+                let ($($T,)*) = state;
+                $(let $T = $T.as_ref().and_then(|s| $T::fetch(s, row));)*
+                if $($T.is_none())&&* {
+                    return None;
+                }
+                Some(($($T,)*))
+            }
+        }
+
+        #[allow(non_snake_case)]
+        unsafe impl<'w, $($T: QueryFetchMut<'w>),*> QueryFetchMut<'w> for AnyOf<($($T,)*)> {
+            type Item = ($(Option<$T::Item>,)*);
+            type State = ($(Option<$T::State>,)*);
+
+            fn prepare(
+                archetype: &'w mut Archetype,
+                change_tick: u32,
+                current_tick: u32,
+            ) -> Option<Self::State> {
+                <Or<($($T,)*)> as QueryFetchMut<'w>>::prepare(archetype, change_tick, current_tick)
+            }
+
+            unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+                let ($($T,)*) = state;
+                $(let $T = $T.as_mut().and_then(|s| $T::fetch(s, row));)*
+                if $($T.is_none())&&* {
+                    return None;
+                }
+                Some(($($T,)*))
+            }
+        }
+
+        impl<$($T: QueryData),*> QueryData for Or<($($T,)*)> {
+            type Fetch<'w> = Or<($($T::Fetch<'w>,)*)>;
+
+            fn access(access: SystemAccess) -> SystemAccess {
+                $(let access = $T::access(access);)*
+                access
+            }
+        }
+
+        impl<$($T: QueryData),*> QueryData for AnyOf<($($T,)*)> {
+            type Fetch<'w> = AnyOf<($($T::Fetch<'w>,)*)>;
+
+            fn access(access: SystemAccess) -> SystemAccess {
+                $(let access = $T::access(access);)*
+                access
+            }
+        }
+    };
+}
+
+impl_any_of!(A, B);
+impl_any_of!(A, B, C);
+impl_any_of!(A, B, C, D);
+impl_any_of!(A, B, C, D, E);
+impl_any_of!(A, B, C, D, E, F);
+impl_any_of!(A, B, C, D, E, F, G);
+impl_any_of!(A, B, C, D, E, F, G, H);
+
+// Filters and markers read no component data, except change detection which
+// reads the ticks written alongside `T`
+
+impl<T: 'static> QueryData for With<T> {
+    type Fetch<'w> = Self;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access
+    }
+}
+
+impl<T: 'static> QueryData for Without<T> {
+    type Fetch<'w> = Self;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access
+    }
+}
+
+impl QueryData for Entity {
+    type Fetch<'w> = Self;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access
+    }
+}
+
+impl<T: Component> QueryData for Changed<T> {
+    type Fetch<'w> = Self;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.read::<T>()
+    }
+}
+
+impl<T: Component> QueryData for Added<T> {
+    type Fetch<'w> = Self;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.read::<T>()
+    }
+}
+
 /// Read access wrapper for CachedQuery
 pub struct Read<T>(PhantomData<T>);
 
@@ -1608,95 +2224,6 @@ unsafe impl<'w, T: Component> QueryFetchMut<'w> for Write<T> {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_query_state_creation() {
-        let world = crate::World::new();
-        let state = QueryState::<&i32>::new(&world);
-        // There are no archetypes containing i32 yet
-        // There are no archetypes containing i32 yet
-        assert_eq!(state.match_count(), 0);
-    }
-
-    #[test]
-    fn test_incremental_update() {
-        let mut world = crate::World::new();
-        let mut query = CachedQuery::<&i32>::new(&world);
-
-        // Initially empty (except potentially empty archetype)
-        let initial_count = query.state.match_count();
-
-        // Add archetype matching query
-        world.spawn_entity((10i32,));
-
-        // Iterating should update state
-        let count = query.iter(&world).count();
-        assert_eq!(count, 1);
-        assert!(query.state.match_count() > initial_count);
-    }
-
-    #[test]
-    fn test_query_filters() {
-        let mut world = crate::World::new();
-
-        #[derive(Debug, Clone, Copy)]
-        struct A;
-        #[derive(Debug, Clone, Copy)]
-        struct B;
-
-        world.spawn_entity((A, B));
-        world.spawn_entity((A,));
-        world.spawn_entity((B,));
-
-        // Query: A with B
-        let mut query = CachedQuery::<(&A, With<B>)>::new(&world);
-        assert_eq!(query.iter(&world).count(), 1);
-
-        // Query: A without B
-        let mut query = CachedQuery::<(&A, Without<B>)>::new(&world);
-        assert_eq!(query.iter(&world).count(), 1);
-    }
-
-    #[test]
-    fn test_change_detection() {
-        let mut world = crate::World::new();
-        struct Data(#[allow(dead_code)] i32);
-
-        let _e = world.spawn_entity((Data(1),));
-
-        // Frame 1
-        world.increment_tick(); // Tick = 2
-
-        {
-            // Query changes since tick 0 (everything changed)
-            let mut query = QueryMut::<(&Data, Changed<Data>)>::new(&mut world);
-            assert_eq!(query.iter_since(0).count(), 1);
-        }
-
-        {
-            // Query changes since tick 2 (nothing changed yet)
-            let mut query = QueryMut::<(&Data, Changed<Data>)>::new(&mut world);
-            assert_eq!(query.iter_since(2).count(), 0);
-        }
-
-        // Modify component
-        world.increment_tick(); // Tick = 3
-        {
-            let mut query = QueryMut::<(&Data, Changed<Data>)>::new(&mut world);
-            // Simulate system write
-            for (_data, _) in query.iter() {
-                // Loop to use iterator
-            }
-        }
-        // Let's use world.get_component_mut logic if available, or just overwrite archetype data
-        // For this test, we assume standard mutable queries update ticks.
-    }
-}
-// ... existing code ...
-
 /// Optimized view for repeated query iteration
 ///
 /// Pre-calculates component pointers to avoid hash lookups during iteration.
@@ -1784,3 +2311,91 @@ impl<'v, 'w, Q: QueryFilter + QueryFetch<'w>> Iterator for ViewIter<'v, 'w, Q> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_query_state_creation() {
+        let world = crate::World::new();
+        let state = QueryState::<&i32>::new(&world);
+        // There are no archetypes containing i32 yet
+        // There are no archetypes containing i32 yet
+        assert_eq!(state.match_count(), 0);
+    }
+
+    #[test]
+    fn test_incremental_update() {
+        let mut world = crate::World::new();
+        let mut query = CachedQuery::<&i32>::new(&world);
+
+        // Initially empty (except potentially empty archetype)
+        let initial_count = query.state.match_count();
+
+        // Add archetype matching query
+        world.spawn_entity((10i32,));
+
+        // Iterating should update state
+        let count = query.iter(&world).count();
+        assert_eq!(count, 1);
+        assert!(query.state.match_count() > initial_count);
+    }
+
+    #[test]
+    fn test_query_filters() {
+        let mut world = crate::World::new();
+
+        #[derive(Debug, Clone, Copy)]
+        struct A;
+        #[derive(Debug, Clone, Copy)]
+        struct B;
+
+        world.spawn_entity((A, B));
+        world.spawn_entity((A,));
+        world.spawn_entity((B,));
+
+        // Query: A with B
+        let mut query = CachedQuery::<(&A, With<B>)>::new(&world);
+        assert_eq!(query.iter(&world).count(), 1);
+
+        // Query: A without B
+        let mut query = CachedQuery::<(&A, Without<B>)>::new(&world);
+        assert_eq!(query.iter(&world).count(), 1);
+    }
+
+    #[test]
+    fn test_change_detection() {
+        let mut world = crate::World::new();
+        struct Data(#[allow(dead_code)] i32);
+
+        let _e = world.spawn_entity((Data(1),));
+
+        // Frame 1
+        world.increment_tick(); // Tick = 2
+
+        {
+            // Query changes since tick 0 (everything changed)
+            let mut query = QueryMut::<(&Data, Changed<Data>)>::new(&mut world);
+            assert_eq!(query.iter_since(0).count(), 1);
+        }
+
+        {
+            // Query changes since tick 2 (nothing changed yet)
+            let mut query = QueryMut::<(&Data, Changed<Data>)>::new(&mut world);
+            assert_eq!(query.iter_since(2).count(), 0);
+        }
+
+        // Modify component
+        world.increment_tick(); // Tick = 3
+        {
+            let mut query = QueryMut::<(&Data, Changed<Data>)>::new(&mut world);
+            // Simulate system write
+            for (_data, _) in query.iter() {
+                // Loop to use iterator
+            }
+        }
+        // Let's use world.get_component_mut logic if available, or just overwrite archetype data
+        // For this test, we assume standard mutable queries update ticks.
+    }
+}
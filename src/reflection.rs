@@ -1,7 +1,17 @@
+use std::alloc::Layout;
 use std::any::{Any, TypeId};
 use std::collections::HashMap;
 
+use crate::error::{EcsError, Result};
+
+pub use archetype_ecs_derive::Reflect;
+
 /// Trait for runtime type reflection
+///
+/// Usually implemented with `#[derive(Reflect)]`, which generates field access
+/// for structs and for the active variant of enums. Derived field names are
+/// also exposed through `static_field_name`, so they can be recorded in a
+/// `TypeRegistry` at registration time.
 pub trait Reflect: Any + Send + Sync {
     /// Get TypeId of the concrete type
     fn type_id(&self) -> TypeId {
@@ -25,7 +35,7 @@ pub trait Reflect: Any + Send + Sync {
     /// Clone into a boxed Reflect
     fn reflect_clone(&self) -> Box<dyn Reflect>;
 
-    // Field access for structs
+    // Field access for structs (and the active variant of enums)
     fn field_count(&self) -> usize {
         0
     }
@@ -35,7 +45,11 @@ pub trait Reflect: Any + Send + Sync {
     fn field_at_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
         None
     }
-    fn field_name(&self, _index: usize) -> Option<&str> {
+    fn field_name(&self, index: usize) -> Option<&str> {
+        self.static_field_name(index)
+    }
+    /// Field name at `index`, for names known at compile time
+    fn static_field_name(&self, _index: usize) -> Option<&'static str> {
         None
     }
     fn field_by_name(&self, _name: &str) -> Option<&dyn Reflect> {
@@ -44,6 +58,25 @@ pub trait Reflect: Any + Send + Sync {
     fn field_by_name_mut(&mut self, _name: &str) -> Option<&mut dyn Reflect> {
         None
     }
+
+    // Element access for lists
+    fn element_count(&self) -> usize {
+        0
+    }
+    fn element_at(&self, _index: usize) -> Option<&dyn Reflect> {
+        None
+    }
+    fn element_at_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+        None
+    }
+
+    // Variant access for enums
+    fn variant_name(&self) -> Option<&'static str> {
+        None
+    }
+    fn variant_index(&self) -> Option<usize> {
+        None
+    }
 }
 
 /// Dynamic value storage for reflection
@@ -58,6 +91,162 @@ pub enum ReflectValue {
     Usize(usize),
 }
 
+impl ReflectValue {
+    /// Read a value out of a reflected primitive
+    ///
+    /// Returns `None` if the value is not one of the supported primitive types.
+    pub fn from_reflect(value: &dyn Reflect) -> Option<Self> {
+        let any = value.as_any();
+        if let Some(v) = any.downcast_ref::<bool>() {
+            Some(ReflectValue::Bool(*v))
+        } else if let Some(v) = any.downcast_ref::<i32>() {
+            Some(ReflectValue::I32(*v))
+        } else if let Some(v) = any.downcast_ref::<u32>() {
+            Some(ReflectValue::U32(*v))
+        } else if let Some(v) = any.downcast_ref::<f32>() {
+            Some(ReflectValue::F32(*v))
+        } else if let Some(v) = any.downcast_ref::<f64>() {
+            Some(ReflectValue::F64(*v))
+        } else if let Some(v) = any.downcast_ref::<String>() {
+            Some(ReflectValue::String(v.clone()))
+        } else {
+            any.downcast_ref::<usize>().map(|v| ReflectValue::Usize(*v))
+        }
+    }
+
+    /// Write this value into a reflected primitive of the same type
+    ///
+    /// Returns `false` (leaving the target untouched) on a type mismatch.
+    pub fn apply_to(&self, target: &mut dyn Reflect) -> bool {
+        let any = target.as_any_mut();
+        match self {
+            ReflectValue::Bool(v) => any.downcast_mut::<bool>().map(|t| *t = *v),
+            ReflectValue::I32(v) => any.downcast_mut::<i32>().map(|t| *t = *v),
+            ReflectValue::U32(v) => any.downcast_mut::<u32>().map(|t| *t = *v),
+            ReflectValue::F32(v) => any.downcast_mut::<f32>().map(|t| *t = *v),
+            ReflectValue::F64(v) => any.downcast_mut::<f64>().map(|t| *t = *v),
+            ReflectValue::String(v) => any.downcast_mut::<String>().map(|t| t.clone_from(v)),
+            ReflectValue::Usize(v) => any.downcast_mut::<usize>().map(|t| *t = *v),
+        }
+        .is_some()
+    }
+}
+
+/// One step of a reflection path
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PathSegment<'a> {
+    /// Named field (`position`), or positional field of a tuple struct (`0`)
+    Field(&'a str),
+    /// List element (`[2]`)
+    Index(usize),
+}
+
+/// Parse a reflection path such as `"position.x"` or `"items[2].count"`
+pub fn parse_path(path: &str) -> Result<Vec<PathSegment<'_>>> {
+    let error =
+        |reason: &str| EcsError::ReflectPathError(format!("invalid path '{path}': {reason}"));
+
+    let mut segments = Vec::new();
+    for part in path.split('.') {
+        let (name, mut indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+        if name.contains(|c: char| !c.is_alphanumeric() && c != '_') {
+            return Err(error("field names must be identifiers or tuple indices"));
+        } else if !name.is_empty() {
+            segments.push(PathSegment::Field(name));
+        } else if indices.is_empty() {
+            return Err(error("empty segment"));
+        }
+
+        while !indices.is_empty() {
+            let close = match (indices.strip_prefix('['), indices.find(']')) {
+                (Some(_), Some(close)) => close,
+                _ => return Err(error("unbalanced brackets")),
+            };
+            let index = indices[1..close]
+                .parse()
+                .map_err(|_| error("list index is not a number"))?;
+            segments.push(PathSegment::Index(index));
+            indices = &indices[close + 1..];
+        }
+    }
+    Ok(segments)
+}
+
+/// Path-based access into reflected values
+///
+/// Paths are dotted field names with optional list indices, e.g.
+/// `"position.x"` or `"items[2].count"`.
+pub trait ReflectPath {
+    /// Resolve a path to a nested value
+    fn reflect_path(&self, path: &str) -> Result<&dyn Reflect>;
+
+    /// Resolve a path to a nested value mutably
+    fn reflect_path_mut(&mut self, path: &str) -> Result<&mut dyn Reflect>;
+
+    /// Overwrite the primitive at `path` with `value`
+    ///
+    /// Fails if the path does not resolve or the target has a different type.
+    fn set_from_reflect_value(&mut self, path: &str, value: ReflectValue) -> Result<()> {
+        let target = self.reflect_path_mut(path)?;
+        if value.apply_to(target) {
+            Ok(())
+        } else {
+            Err(EcsError::ReflectPathError(format!(
+                "cannot assign {value:?} to '{path}' of type {}",
+                target.type_name()
+            )))
+        }
+    }
+}
+
+fn missing_segment(type_name: &str, segment: PathSegment<'_>) -> EcsError {
+    match segment {
+        PathSegment::Field(name) => {
+            EcsError::ReflectPathError(format!("no field '{name}' on {type_name}"))
+        }
+        PathSegment::Index(index) => {
+            EcsError::ReflectPathError(format!("index {index} out of bounds on {type_name}"))
+        }
+    }
+}
+
+impl ReflectPath for dyn Reflect {
+    fn reflect_path(&self, path: &str) -> Result<&dyn Reflect> {
+        let mut current = self;
+        for segment in parse_path(path)? {
+            let next = match segment {
+                PathSegment::Field(name) => current.field_by_name(name),
+                PathSegment::Index(index) => current.element_at(index),
+            };
+            current = next.ok_or_else(|| missing_segment(current.type_name(), segment))?;
+        }
+        Ok(current)
+    }
+
+    fn reflect_path_mut(&mut self, path: &str) -> Result<&mut dyn Reflect> {
+        let mut current = self;
+        for segment in parse_path(path)? {
+            let type_name = current.type_name();
+            let next = match segment {
+                PathSegment::Field(name) => current.field_by_name_mut(name),
+                PathSegment::Index(index) => current.element_at_mut(index),
+            };
+            current = next.ok_or_else(|| missing_segment(type_name, segment))?;
+        }
+        Ok(current)
+    }
+}
+
+impl<T: Reflect> ReflectPath for T {
+    fn reflect_path(&self, path: &str) -> Result<&dyn Reflect> {
+        (self as &dyn Reflect).reflect_path(path)
+    }
+
+    fn reflect_path_mut(&mut self, path: &str) -> Result<&mut dyn Reflect> {
+        (self as &mut dyn Reflect).reflect_path_mut(path)
+    }
+}
+
 /// Registry for reflected types
 #[derive(Default)]
 pub struct TypeRegistry {
@@ -71,8 +260,15 @@ impl TypeRegistry {
     }
 
     /// Register a type
+    ///
+    /// Field names are taken from the type's `Reflect` implementation (for
+    /// enums, from the variant returned by `Default`).
     pub fn register<T: Reflect + Default + Clone>(&mut self) {
-        self.register_with_fields::<T>(vec![]);
+        let value = T::default();
+        let field_names = (0..value.field_count())
+            .filter_map(|index| value.static_field_name(index))
+            .collect();
+        self.register_with_fields::<T>(field_names);
     }
 
     /// Register a type with field names
@@ -88,14 +284,32 @@ impl TypeRegistry {
     pub fn get(&self, type_id: TypeId) -> Option<&TypeRegistration> {
         self.registrations.get(&type_id)
     }
+
+    /// Check if a type is registered
+    pub fn contains(&self, type_id: TypeId) -> bool {
+        self.registrations.contains_key(&type_id)
+    }
 }
 
 /// Type registration data
+///
+/// Besides metadata, a registration carries the layout and raw storage hooks
+/// needed to store the type in an archetype column without knowing it statically.
 pub struct TypeRegistration {
     pub type_name: &'static str,
     pub type_id: TypeId,
     pub default_fn: fn() -> Box<dyn Reflect>,
     pub field_names: Vec<&'static str>,
+    /// Memory layout of the concrete type
+    pub layout: Layout,
+    /// Drop the value behind a pointer in place (`None` if the type needs no drop)
+    pub drop_fn: Option<unsafe fn(*mut u8)>,
+    /// Clone the value behind `src` into uninitialized memory at `dst`
+    pub clone_fn: unsafe fn(src: *const u8, dst: *mut u8),
+    /// View the value behind a pointer as `dyn Reflect`
+    pub as_reflect_fn: unsafe fn(*const u8) -> *const dyn Reflect,
+    /// View the value behind a pointer as mutable `dyn Reflect`
+    pub as_reflect_mut_fn: unsafe fn(*mut u8) -> *mut dyn Reflect,
 }
 
 impl TypeRegistration {
@@ -105,8 +319,34 @@ impl TypeRegistration {
             type_id: TypeId::of::<T>(),
             default_fn: || Box::new(T::default()),
             field_names,
+            layout: Layout::new::<T>(),
+            drop_fn: if std::mem::needs_drop::<T>() {
+                Some(|ptr| unsafe { std::ptr::drop_in_place(ptr as *mut T) })
+            } else {
+                None
+            },
+            clone_fn: |src, dst| unsafe {
+                std::ptr::write(dst as *mut T, (*(src as *const T)).clone())
+            },
+            as_reflect_fn: |ptr| ptr as *const T as *const dyn Reflect,
+            as_reflect_mut_fn: |ptr| ptr as *mut T as *mut dyn Reflect,
         }
     }
+
+    /// Clone a reflected value of this type into uninitialized memory
+    ///
+    /// Returns `false` (writing nothing) if `value` is not of this type.
+    ///
+    /// # Safety
+    /// `dst` must be valid for writes of `self.layout` and properly aligned.
+    pub unsafe fn clone_reflect_into(&self, value: &dyn Reflect, dst: *mut u8) -> bool {
+        if value.as_any().type_id() != self.type_id {
+            return false;
+        }
+        // The data half of the fat pointer points at the concrete value
+        (self.clone_fn)(value as *const dyn Reflect as *const u8, dst);
+        true
+    }
 }
 
 // Implement Reflect for common primitives
@@ -136,7 +376,107 @@ macro_rules! impl_reflect_primitive {
     };
 }
 
-impl_reflect_primitive!(i32, u32, f32, f64, bool, String, usize);
+impl_reflect_primitive!(
+    i8, i16, i32, i64, isize, u8, u16, u32, u64, usize, f32, f64, bool, char, String
+);
+
+impl<T: Reflect + Clone> Reflect for Vec<T> {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let Some(v) = value.as_any().downcast_ref::<Vec<T>>() {
+            self.clone_from(v);
+        }
+    }
+
+    fn reflect_clone(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+
+    fn element_at(&self, index: usize) -> Option<&dyn Reflect> {
+        self.get(index).map(|v| v as &dyn Reflect)
+    }
+
+    fn element_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.get_mut(index).map(|v| v as &mut dyn Reflect)
+    }
+}
+
+// Implement Reflect for math types, exposing their components as fields
+macro_rules! impl_reflect_math {
+    ($($t:ty { $($field:ident),* }),* $(,)?) => {
+        $(
+            impl Reflect for $t {
+                fn as_any(&self) -> &dyn Any {
+                    self
+                }
+
+                fn as_any_mut(&mut self) -> &mut dyn Any {
+                    self
+                }
+
+                fn apply(&mut self, value: &dyn Reflect) {
+                    if let Some(v) = value.as_any().downcast_ref::<$t>() {
+                        *self = *v;
+                    }
+                }
+
+                fn reflect_clone(&self) -> Box<dyn Reflect> {
+                    Box::new(*self)
+                }
+
+                fn field_count(&self) -> usize {
+                    [$(stringify!($field)),*].len()
+                }
+
+                fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+                    let name = self.static_field_name(index)?;
+                    self.field_by_name(name)
+                }
+
+                fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                    let name = self.static_field_name(index)?;
+                    self.field_by_name_mut(name)
+                }
+
+                fn static_field_name(&self, index: usize) -> Option<&'static str> {
+                    [$(stringify!($field)),*].get(index).copied()
+                }
+
+                fn field_by_name(&self, name: &str) -> Option<&dyn Reflect> {
+                    match name {
+                        $(stringify!($field) => Some(&self.$field),)*
+                        _ => None,
+                    }
+                }
+
+                fn field_by_name_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+                    match name {
+                        $(stringify!($field) => Some(&mut self.$field),)*
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_reflect_math!(
+    glam::Vec2 { x, y },
+    glam::Vec3 { x, y, z },
+    glam::Vec4 { x, y, z, w },
+    glam::Quat { x, y, z, w },
+);
 
 /// Macro to implement Reflect for structs
 #[macro_export]
@@ -193,7 +533,7 @@ macro_rules! impl_reflect {
                 count
             }
 
-            fn field_name(&self, index: usize) -> Option<&str> {
+            fn static_field_name(&self, index: usize) -> Option<&'static str> {
                 let names = &[$(stringify!($field)),*];
                 names.get(index).copied()
             }
@@ -0,0 +1,487 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed entity relationships
+//!
+//! A relation kind `R` is any marker type (`struct Targets;`). Relating a
+//! source to a target stores a [`Relation<R>`] on the source and a
+//! [`RelationSources<R>`] on the target, so both directions are O(1) lookups:
+//!
+//! ```
+//! use archetype_ecs::World;
+//!
+//! struct Targets;
+//!
+//! let mut world = World::new();
+//! let archer = world.spawn_entity((1u32,));
+//! let goblin = world.spawn_entity((2u32,));
+//!
+//! world.add_relation::<Targets>(archer, goblin).unwrap();
+//! assert_eq!(world.relation_targets::<Targets>(archer), &[goblin]);
+//! assert_eq!(world.relation_sources::<Targets>(goblin), &[archer]);
+//!
+//! // Despawning either side cleans up the other
+//! world.despawn(goblin).unwrap();
+//! assert!(world.relation_targets::<Targets>(archer).is_empty());
+//! ```
+//!
+//! Always go through the `World` relation methods; removing these components
+//! directly leaves the other side of the relation stale. To save and load
+//! relations, register them with `SerializationRegistry::register_relation`.
+//!
+//! Inside queries and systems, [`RelatedTo<R>`] matches the sources of `R`
+//! relations and yields their targets. [`Query::iter_related_to`] narrows
+//! such a query to the sources of one target:
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//! use archetype_ecs::RelatedTo;
+//!
+//! struct Targets;
+//! struct Goblin(EntityId);
+//! #[derive(Default)]
+//! struct Aiming(usize);
+//!
+//! fn count_aiming(
+//!     archers: Query<(Entity, RelatedTo<Targets>)>,
+//!     goblin: Res<Goblin>,
+//!     mut aiming: ResMut<Aiming>,
+//! ) {
+//!     aiming.0 = archers.iter_related_to::<Targets>(goblin.0).count();
+//! }
+//!
+//! let mut world = World::new();
+//! let archer = world.spawn_entity((1u32,));
+//! let goblin = world.spawn_entity((2u32,));
+//! world.add_relation::<Targets>(archer, goblin).unwrap();
+//! world.insert_resource(Goblin(goblin));
+//! world.insert_resource(Aiming::default());
+//!
+//! let mut schedule = Schedule::new();
+//! schedule.add_system(Box::new(count_aiming.into_system()));
+//! Executor::new(&mut schedule).execute_frame(&mut world).unwrap();
+//! assert_eq!(world.resource::<Aiming>().unwrap().0, 1);
+//! ```
+//!
+//! `Parent`/`Children` predate relations and keep their own bookkeeping in
+//! `hierarchy.rs`; they aren't built on `Relation<R>`.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+
+use serde::{Deserialize, Serialize};
+use smallvec::SmallVec;
+
+use crate::archetype::Archetype;
+use crate::entity::EntityId;
+use crate::query::{
+    Query, QueryData, QueryFetch, QueryFetchMut, QueryFilter, MAX_FILTER_COMPONENTS,
+};
+use crate::reflection::Reflect;
+use crate::serialization::{EntityMap, MapEntities};
+use crate::system::SystemAccess;
+use crate::world::World;
+
+/// Marker trait for relation kinds
+///
+/// Automatically implemented for any `Send + Sync + 'static` type.
+pub trait RelationKind: Send + Sync + 'static {}
+
+impl<T: Send + Sync + 'static> RelationKind for T {}
+
+/// Outgoing relations of kind `R`, stored on the source entity
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct Relation<R: RelationKind> {
+    targets: Vec<EntityId>,
+    #[serde(skip)]
+    _marker: PhantomData<R>,
+}
+
+impl<R: RelationKind> Relation<R> {
+    pub fn new() -> Self {
+        Self {
+            targets: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn targets(&self) -> &[EntityId] {
+        &self.targets
+    }
+
+    pub fn contains(&self, target: EntityId) -> bool {
+        self.targets.contains(&target)
+    }
+
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    pub(crate) fn add(&mut self, target: EntityId) -> bool {
+        if self.targets.contains(&target) {
+            false
+        } else {
+            self.targets.push(target);
+            true
+        }
+    }
+
+    pub(crate) fn remove(&mut self, target: EntityId) -> bool {
+        if let Some(pos) = self.targets.iter().position(|&t| t == target) {
+            self.targets.remove(pos);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<R: RelationKind> Default for Relation<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: RelationKind> Clone for Relation<R> {
+    fn clone(&self) -> Self {
+        Self {
+            targets: self.targets.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: RelationKind> Reflect for Relation<R> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let Some(v) = value.as_any().downcast_ref::<Self>() {
+            *self = v.clone();
+        }
+    }
+
+    fn reflect_clone(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+}
+
+impl<R: RelationKind> MapEntities for Relation<R> {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.targets = self.targets.iter().filter_map(|&e| map.get(e)).collect();
+    }
+}
+
+/// Incoming relations of kind `R`, stored on the target entity
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(bound = "")]
+pub struct RelationSources<R: RelationKind> {
+    sources: Vec<EntityId>,
+    #[serde(skip)]
+    _marker: PhantomData<R>,
+}
+
+impl<R: RelationKind> RelationSources<R> {
+    pub fn new() -> Self {
+        Self {
+            sources: Vec::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn sources(&self) -> &[EntityId] {
+        &self.sources
+    }
+
+    pub fn contains(&self, source: EntityId) -> bool {
+        self.sources.contains(&source)
+    }
+
+    pub fn len(&self) -> usize {
+        self.sources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sources.is_empty()
+    }
+
+    pub(crate) fn add(&mut self, source: EntityId) {
+        if !self.sources.contains(&source) {
+            self.sources.push(source);
+        }
+    }
+
+    pub(crate) fn remove(&mut self, source: EntityId) -> bool {
+        if let Some(pos) = self.sources.iter().position(|&s| s == source) {
+            self.sources.remove(pos);
+            true
+        } else {
+            false
+        }
+    }
+}
+
+impl<R: RelationKind> Default for RelationSources<R> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<R: RelationKind> Clone for RelationSources<R> {
+    fn clone(&self) -> Self {
+        Self {
+            sources: self.sources.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: RelationKind> Reflect for RelationSources<R> {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let Some(v) = value.as_any().downcast_ref::<Self>() {
+            *self = v.clone();
+        }
+    }
+
+    fn reflect_clone(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+}
+
+impl<R: RelationKind> MapEntities for RelationSources<R> {
+    fn map_entities(&mut self, map: &EntityMap) {
+        self.sources = self.sources.iter().filter_map(|&e| map.get(e)).collect();
+    }
+}
+
+/// Query term matching the sources of `R` relations
+///
+/// Yields the source's [`Relation<R>`]; use [`Query::iter_related_to`] to
+/// keep only the sources of one target. Systems using it declare a read of
+/// `Relation<R>`.
+pub struct RelatedTo<R>(PhantomData<R>);
+
+impl<R: RelationKind> QueryFilter for RelatedTo<R> {
+    fn matches_archetype(archetype: &Archetype) -> bool {
+        <&Relation<R> as QueryFilter>::matches_archetype(archetype)
+    }
+
+    fn type_ids() -> SmallVec<[TypeId; MAX_FILTER_COMPONENTS]> {
+        <&Relation<R> as QueryFilter>::type_ids()
+    }
+}
+
+unsafe impl<'w, R: RelationKind> QueryFetch<'w> for RelatedTo<R> {
+    type Item = &'w Relation<R>;
+    type State = <&'w Relation<R> as QueryFetch<'w>>::State;
+
+    fn prepare(archetype: &'w Archetype, change_tick: u32) -> Option<Self::State> {
+        <&'w Relation<R> as QueryFetch<'w>>::prepare(archetype, change_tick)
+    }
+
+    unsafe fn fetch(state: &Self::State, row: usize) -> Option<Self::Item> {
+        <&'w Relation<R> as QueryFetch<'w>>::fetch(state, row)
+    }
+}
+
+unsafe impl<'w, R: RelationKind> QueryFetchMut<'w> for RelatedTo<R> {
+    type Item = &'w Relation<R>;
+    type State = <&'w Relation<R> as QueryFetchMut<'w>>::State;
+
+    fn prepare(
+        archetype: &'w mut Archetype,
+        change_tick: u32,
+        current_tick: u32,
+    ) -> Option<Self::State> {
+        <&'w Relation<R> as QueryFetchMut<'w>>::prepare(archetype, change_tick, current_tick)
+    }
+
+    unsafe fn fetch(state: &mut Self::State, row: usize) -> Option<Self::Item> {
+        <&'w Relation<R> as QueryFetchMut<'w>>::fetch(state, row)
+    }
+}
+
+impl<R: RelationKind> QueryData for RelatedTo<R> {
+    type Fetch<'w> = Self;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.read::<Relation<R>>()
+    }
+}
+
+impl<'w, Q> Query<'w, Q>
+where
+    Q: QueryFilter + QueryFetch<'w>,
+{
+    /// Iterate the matches whose `R` relation targets `target`
+    ///
+    /// # Panics
+    /// Panics if `Q` doesn't read `Relation<R>`, e.g. through
+    /// [`RelatedTo<R>`]; systems only declare the read for terms in `Q`.
+    pub fn iter_related_to<R: RelationKind>(&self, target: EntityId) -> RelatedToIter<'w, R, Q> {
+        assert!(
+            Q::type_ids().contains(&TypeId::of::<Relation<R>>()),
+            "Query::iter_related_to requires RelatedTo<R> in the query"
+        );
+        let world = self.world();
+        RelatedToIter {
+            world,
+            matches: world.get_cached_query_indices::<Q>(),
+            target,
+            archetype_index: 0,
+            entity_index: 0,
+            change_tick: self.change_tick(),
+            state: None,
+        }
+    }
+}
+
+/// Iterator returned by [`Query::iter_related_to`]
+pub struct RelatedToIter<'w, R: RelationKind, Q: QueryFetch<'w>> {
+    world: &'w World,
+    matches: Vec<usize>,
+    target: EntityId,
+    archetype_index: usize,
+    entity_index: usize,
+    change_tick: u32,
+    state: Option<(Q::State, <&'w Relation<R> as QueryFetch<'w>>::State)>,
+}
+
+impl<'w, R, Q> Iterator for RelatedToIter<'w, R, Q>
+where
+    R: RelationKind,
+    Q: QueryFetch<'w>,
+{
+    type Item = Q::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let archetype = self
+                .world
+                .get_archetype(*self.matches.get(self.archetype_index)?)?;
+
+            if self.state.is_none() {
+                self.state =
+                    Q::prepare(archetype, self.change_tick).zip(<&'w Relation<R> as QueryFetch<
+                        'w,
+                    >>::prepare(
+                        archetype, self.change_tick
+                    ));
+                self.entity_index = 0;
+
+                if self.state.is_none() {
+                    self.archetype_index += 1;
+                    continue;
+                }
+            }
+
+            if self.entity_index < archetype.len() {
+                let row = self.entity_index;
+                self.entity_index += 1;
+
+                let (state, relations) = self.state.as_ref().unwrap();
+                // SAFETY: We checked bounds above. Both states are valid for this archetype.
+                let related = unsafe { <&'w Relation<R> as QueryFetch<'w>>::fetch(relations, row) }
+                    .is_some_and(|relation| relation.contains(self.target));
+                if related {
+                    if let Some(item) = unsafe { Q::fetch(state, row) } {
+                        return Some(item);
+                    }
+                }
+            } else {
+                self.state = None;
+                self.archetype_index += 1;
+            }
+        }
+    }
+}
+
+/// Per-kind cleanup run by `World::despawn`
+pub(crate) type RelationHook = fn(&mut World, EntityId);
+
+/// Despawn hook removing `entity` from both sides of its `R` relations
+///
+/// Registered by `World::add_relation` the first time a kind is used.
+pub(crate) fn cleanup_relations<R: RelationKind>(world: &mut World, entity: EntityId) {
+    // Entity as source: forget it on every target
+    let targets = world
+        .get_component::<Relation<R>>(entity)
+        .map(|r| r.targets.clone())
+        .unwrap_or_default();
+    for target in targets {
+        if let Some(sources) = world.get_component_mut::<RelationSources<R>>(target) {
+            sources.remove(entity);
+        }
+    }
+
+    // Entity as target: drop it from every source, removing emptied relations
+    let sources = world
+        .get_component::<RelationSources<R>>(entity)
+        .map(|s| s.sources.clone())
+        .unwrap_or_default();
+    for source in sources {
+        if source == entity {
+            continue;
+        }
+        let now_empty = match world.get_component_mut::<Relation<R>>(source) {
+            Some(relation) => {
+                relation.remove(entity);
+                relation.is_empty()
+            }
+            None => false,
+        };
+        if now_empty {
+            let _ = world.remove_component::<Relation<R>>(source);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Likes;
+
+    #[test]
+    fn test_relation_no_duplicates() {
+        let mut world = World::new();
+        let target = world.spawn_entity((0u8,));
+
+        let mut relation = Relation::<Likes>::new();
+        assert!(relation.add(target));
+        assert!(!relation.add(target));
+        assert_eq!(relation.len(), 1);
+
+        assert!(relation.remove(target));
+        assert!(relation.is_empty());
+    }
+}
@@ -0,0 +1,199 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Removal tracking for systems
+//!
+//! [`RemovedComponents<T>`] lists the entities that lost a `T`, through
+//! `remove_component`, `despawn` or `flush_removals`, since the system last
+//! ran:
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//!
+//! struct Replicated;
+//! #[derive(Default)]
+//! struct Outbox(Vec<EntityId>);
+//!
+//! fn send_removals(removed: RemovedComponents<Replicated>, mut outbox: ResMut<Outbox>) {
+//!     outbox.0.extend(removed.read());
+//! }
+//!
+//! let mut world = World::new();
+//! world.insert_resource(Outbox::default());
+//! let entity = world.spawn_entity((Replicated,));
+//! let mut schedule = Schedule::new();
+//! schedule.add_system(Box::new(send_removals.into_system()));
+//! let mut executor = Executor::new(&mut schedule);
+//!
+//! executor.execute_frame(&mut world).unwrap();
+//! world.despawn(entity).unwrap();
+//! executor.execute_frame(&mut world).unwrap();
+//! assert_eq!(world.resource::<Outbox>().unwrap().0, vec![entity]);
+//! ```
+//!
+//! Removals of a type are only recorded once a system reads them, and each
+//! is dropped as soon as every reader has seen it.
+
+use std::any::TypeId;
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+
+use ahash::AHashMap;
+use parking_lot::RwLock;
+
+use crate::entity::EntityId;
+
+/// Entities that lost one component type, and who still has to read them
+#[derive(Default)]
+struct RemovalLog {
+    entities: Vec<EntityId>,
+    /// Id of the first entry in `entities`
+    start: usize,
+    /// Id of the next entry each reader will read
+    readers: Vec<Weak<AtomicUsize>>,
+}
+
+impl RemovalLog {
+    fn end(&self) -> usize {
+        self.start + self.entities.len()
+    }
+
+    /// Drop dead readers and the entries every live reader has read
+    fn trim(&mut self) {
+        let mut seen = self.end();
+        self.readers.retain(|reader| match reader.upgrade() {
+            Some(position) => {
+                seen = seen.min(position.load(Ordering::Relaxed));
+                true
+            }
+            None => false,
+        });
+        self.entities.drain(..seen - self.start);
+        self.start = seen;
+    }
+}
+
+/// Removal logs of a world, one per component type read by a system
+#[derive(Default)]
+pub(crate) struct RemovalLogs {
+    logs: RwLock<AHashMap<TypeId, RemovalLog>>,
+}
+
+impl RemovalLogs {
+    /// Whether any component type is being tracked
+    pub(crate) fn is_tracking(&mut self) -> bool {
+        !self.logs.get_mut().is_empty()
+    }
+
+    /// Log that `entity` lost its `type_id` component, if anyone is reading
+    pub(crate) fn record(&mut self, type_id: TypeId, entity: EntityId) {
+        if let Some(log) = self.logs.get_mut().get_mut(&type_id) {
+            if log.readers.iter().any(|reader| reader.strong_count() > 0) {
+                log.entities.push(entity);
+            }
+        }
+    }
+
+    /// Entities logged for `type_id` since `cursor` last read
+    ///
+    /// A new cursor starts tracking `type_id` and reads nothing.
+    pub(crate) fn read(
+        &self,
+        type_id: TypeId,
+        cursor: &mut Option<Arc<AtomicUsize>>,
+    ) -> Vec<EntityId> {
+        let mut logs = self.logs.write();
+        let log = logs.entry(type_id).or_default();
+        let end = log.end();
+        let Some(position) = cursor else {
+            let position = Arc::new(AtomicUsize::new(end));
+            log.readers.push(Arc::downgrade(&position));
+            *cursor = Some(position);
+            return Vec::new();
+        };
+        let from = position.swap(end, Ordering::Relaxed).max(log.start);
+        let unread = log.entities[from - log.start..].to_vec();
+        log.trim();
+        unread
+    }
+}
+
+/// Read position of a [`RemovedComponents<T>`] parameter
+pub struct RemovedCursor<T> {
+    pub(crate) position: Option<Arc<AtomicUsize>>,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for RemovedCursor<T> {
+    fn default() -> Self {
+        Self {
+            position: None,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// System parameter listing the entities that lost a `T` since the system
+/// last ran
+///
+/// Despawned entities are included. The first run starts tracking and
+/// reads nothing.
+pub struct RemovedComponents<T> {
+    pub(crate) entities: Vec<EntityId>,
+    pub(crate) _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> RemovedComponents<T> {
+    /// Entities that lost a `T`, in removal order
+    pub fn read(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.entities.iter().copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_entries_dropped_once_every_reader_has_read() {
+        let mut logs = RemovalLogs::default();
+        let type_id = TypeId::of::<u32>();
+        let (mut fast, mut slow) = (None, None);
+        assert!(logs.read(type_id, &mut fast).is_empty());
+        assert!(logs.read(type_id, &mut slow).is_empty());
+
+        let entity = EntityId::default();
+        logs.record(type_id, entity);
+        assert_eq!(logs.read(type_id, &mut fast), [entity]);
+        assert_eq!(logs.logs.get_mut()[&type_id].entities.len(), 1);
+
+        assert_eq!(logs.read(type_id, &mut slow), [entity]);
+        assert!(logs.logs.get_mut()[&type_id].entities.is_empty());
+
+        // Dropped readers no longer hold entries back
+        drop(slow);
+        logs.record(type_id, entity);
+        assert_eq!(logs.read(type_id, &mut fast), [entity]);
+        assert!(logs.logs.get_mut()[&type_id].entities.is_empty());
+    }
+}
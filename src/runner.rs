@@ -0,0 +1,123 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! App runners and exit requests
+//!
+//! `App::run` hands the app to its runner, which calls `App::update` until
+//! a system inserts the [`AppExit`] resource:
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//! use archetype_ecs::runner::{run_frames, AppExit};
+//!
+//! fn quit(mut commands: Commands) {
+//!     commands.add(|world| {
+//!         world.insert_resource(AppExit::Success);
+//!         Ok(())
+//!     });
+//! }
+//!
+//! let mut app = App::new();
+//! app.add_system(Box::new(quit.into_system()))
+//!     .set_runner(run_frames(100));
+//! assert_eq!(app.run().unwrap(), AppExit::Success);
+//! ```
+//!
+//! The default runner is [`run_loop`] at 60 Hz.
+
+use std::time::{Duration, Instant};
+
+use crate::app::App;
+use crate::error::Result;
+
+/// Request to stop the app, inserted as a resource
+///
+/// Checked by the runner after every frame.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AppExit {
+    Success,
+    /// Failure with a process exit code
+    Error(u8),
+}
+
+impl AppExit {
+    /// Process exit code, 0 on success
+    pub fn code(&self) -> u8 {
+        match self {
+            AppExit::Success => 0,
+            AppExit::Error(code) => *code,
+        }
+    }
+}
+
+/// Drives `App::update` until the app exits
+pub type Runner = Box<dyn FnMut(&mut App) -> Result<AppExit>>;
+
+/// Run a single frame
+pub fn run_once(app: &mut App) -> Result<AppExit> {
+    app.update()?;
+    Ok(app.take_exit().unwrap_or(AppExit::Success))
+}
+
+/// Run frames back to back, as fast as possible, until exit
+///
+/// Meant for dedicated servers and batch simulation.
+pub fn run_headless(app: &mut App) -> Result<AppExit> {
+    loop {
+        app.update()?;
+        if let Some(exit) = app.take_exit() {
+            return Ok(exit);
+        }
+    }
+}
+
+/// Run at most `frames` frames, stopping early on exit
+///
+/// Returns `AppExit::Success` if the frame budget runs out.
+pub fn run_frames(frames: u64) -> Runner {
+    Box::new(move |app| {
+        for _ in 0..frames {
+            app.update()?;
+            if let Some(exit) = app.take_exit() {
+                return Ok(exit);
+            }
+        }
+        Ok(AppExit::Success)
+    })
+}
+
+/// Run at a fixed frame rate until exit
+///
+/// Sleeps away the rest of each frame. A late frame starts the next one
+/// immediately rather than trying to catch up.
+pub fn run_loop(hz: u32) -> Runner {
+    let frame_time = Duration::from_secs_f64(1.0 / hz.max(1) as f64);
+    Box::new(move |app| {
+        let mut next_frame = Instant::now();
+        loop {
+            app.update()?;
+            if let Some(exit) = app.take_exit() {
+                return Ok(exit);
+            }
+
+            next_frame += frame_time;
+            let now = Instant::now();
+            if next_frame > now {
+                std::thread::sleep(next_frame - now);
+            } else {
+                next_frame = now;
+            }
+        }
+    })
+}
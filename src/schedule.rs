@@ -2,11 +2,15 @@
 //!
 //! Constructs system execution schedule via topological sort.
 
-use rustc_hash::FxHashMap;
+use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::VecDeque;
 
+use crate::bitset::BitSet;
+use crate::command::CommandBuffer;
+use crate::condition::{BoxedCondition, Condition};
 use crate::error::{EcsError, Result};
 use crate::system::{BoxedSystem, System, SystemAccess, SystemId};
+use crate::world::World;
 
 /// System node in dependency graph
 #[derive(Debug, Clone)]
@@ -20,6 +24,8 @@ pub struct SystemNode {
 pub struct StageExecutionPlan {
     pub name: String,
     pub parallel_groups: Vec<crate::dependency::ExecutionStage>,
+    /// Indices of groups after which queued commands are applied
+    pub sync_points: Vec<usize>,
 }
 
 /// Dependency graph for systems
@@ -116,6 +122,8 @@ pub struct Stage {
     pub name: String,
     pub systems: Vec<SystemId>,
     pub depends_on: Vec<String>, // Names of stages this depends on
+    /// Explicit `apply_deferred` points, as the number of `systems` before each
+    pub sync_points: Vec<usize>,
 }
 
 impl Stage {
@@ -124,6 +132,7 @@ impl Stage {
             name: name.to_string(),
             systems: Vec::new(),
             depends_on: Vec::new(),
+            sync_points: Vec::new(),
         }
     }
 
@@ -169,6 +178,33 @@ pub struct Schedule {
     pub(crate) graph: Option<SystemGraph>,
     pub(crate) ordering_constraints: Vec<OrderingConstraint>,
     pub(crate) parallel_plan: Vec<StageExecutionPlan>,
+    pub(crate) system_conditions: FxHashMap<SystemId, Vec<BoxedCondition>>,
+    pub(crate) stage_conditions: FxHashMap<String, Vec<BoxedCondition>>,
+    /// Explicit `apply_deferred` points among systems added without a stage
+    pub(crate) default_sync_points: Vec<usize>,
+    /// One command buffer per system, indexed by `SystemId`
+    pub(crate) command_buffers: Vec<CommandBuffer>,
+}
+
+/// Nodes reachable from each of `node_count` nodes along `edges`
+fn reachability(edges: &[(usize, usize, bool)], node_count: usize) -> Vec<BitSet> {
+    let mut successors = vec![Vec::new(); node_count];
+    for &(from, to, _) in edges {
+        successors[from].push(to);
+    }
+    (0..node_count)
+        .map(|source| {
+            let mut reached = BitSet::with_capacity(node_count);
+            let mut stack = successors[source].clone();
+            while let Some(node) = stack.pop() {
+                if !reached.contains(node) {
+                    reached.set(node);
+                    stack.extend(&successors[node]);
+                }
+            }
+            reached
+        })
+        .collect()
 }
 
 impl Default for Schedule {
@@ -186,6 +222,10 @@ impl Schedule {
             graph: None,
             ordering_constraints: Vec::new(),
             parallel_plan: Vec::new(),
+            system_conditions: FxHashMap::default(),
+            stage_conditions: FxHashMap::default(),
+            default_sync_points: Vec::new(),
+            command_buffers: Vec::new(),
         }
         .build()
     }
@@ -198,6 +238,10 @@ impl Schedule {
             graph: None,
             ordering_constraints: Vec::new(),
             parallel_plan: Vec::new(),
+            system_conditions: FxHashMap::default(),
+            stage_conditions: FxHashMap::default(),
+            default_sync_points: Vec::new(),
+            command_buffers: Vec::new(),
         }
     }
 
@@ -208,11 +252,7 @@ impl Schedule {
                 "Stage '{name}' already exists"
             )));
         }
-        self.stages.push(Stage {
-            name: name.to_string(),
-            systems: Vec::new(),
-            depends_on: Vec::new(),
-        });
+        self.stages.push(Stage::new(name));
         Ok(())
     }
 
@@ -246,9 +286,106 @@ impl Schedule {
         let system_id = SystemId(self.systems.len() as u32);
         stage_def.systems.push(system_id);
         self.systems.push(system);
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Apply queued commands after every system added so far
+    ///
+    /// Systems added afterwards run after the sync point and see the
+    /// entities and components its commands created.
+    pub fn add_apply_deferred(&mut self) {
+        self.default_sync_points.push(self.systems.len());
+        self.invalidate();
+    }
+
+    /// Apply queued commands after the systems added to `stage` so far
+    pub fn add_apply_deferred_to_stage(&mut self, stage: &str) -> Result<()> {
+        let stage_def = self
+            .stages
+            .iter_mut()
+            .find(|s| s.name == stage)
+            .ok_or_else(|| EcsError::ScheduleError(format!("Stage '{stage}' not found")))?;
+        stage_def.sync_points.push(stage_def.systems.len());
+        self.invalidate();
+        Ok(())
+    }
+
+    /// Add a system that only runs while `condition` holds
+    pub fn add_system_with_condition(
+        &mut self,
+        system: BoxedSystem,
+        condition: impl Condition + 'static,
+    ) {
+        let system_id = SystemId(self.systems.len() as u32);
+        self.add_system(system);
+        self.system_conditions
+            .entry(system_id)
+            .or_default()
+            .push(Box::new(condition));
+    }
+
+    /// Add a run condition to a system by name
+    ///
+    /// Only the first system added under `system` is guarded: conditions
+    /// may keep state, so one can't be shared between systems. Use
+    /// `add_system_with_condition` to guard each instance of a system that
+    /// is added more than once.
+    ///
+    /// # Errors
+    /// Returns `EcsError::ScheduleError` if no system is named `system`.
+    pub fn add_run_condition(
+        &mut self,
+        system: &str,
+        condition: impl Condition + 'static,
+    ) -> Result<()> {
+        let index = self
+            .systems
+            .iter()
+            .position(|sys| sys.name() == system)
+            .ok_or_else(|| EcsError::ScheduleError(format!("System '{system}' not found")))?;
+        self.system_conditions
+            .entry(SystemId(index as u32))
+            .or_default()
+            .push(Box::new(condition));
         Ok(())
     }
 
+    /// Add a run condition to a named stage
+    ///
+    /// The condition is checked once per frame before the stage; if it
+    /// fails, none of the stage's systems run.
+    pub fn add_stage_run_condition(
+        &mut self,
+        stage: &str,
+        condition: impl Condition + 'static,
+    ) -> Result<()> {
+        if !self.stages.iter().any(|s| s.name == stage) {
+            return Err(EcsError::ScheduleError(format!(
+                "Stage '{stage}' not found"
+            )));
+        }
+        self.stage_conditions
+            .entry(stage.to_string())
+            .or_default()
+            .push(Box::new(condition));
+        Ok(())
+    }
+
+    /// Evaluate a stage's run conditions
+    pub(crate) fn stage_should_run(&mut self, stage: &str, world: &World) -> bool {
+        self.stage_conditions
+            .get_mut(stage)
+            .is_none_or(|conditions| evaluate_all(conditions, world))
+    }
+
+    /// Evaluate a system's own run conditions
+    pub(crate) fn system_should_run(&mut self, id: SystemId, world: &World) -> bool {
+        self.system_conditions
+            .get_mut(&id)
+            .is_none_or(|conditions| evaluate_all(conditions, world))
+    }
+
     /// Validate stage dependencies (detect cycles)
     pub fn validate_stages(&self) -> Result<()> {
         // Topological sort to detect cycles
@@ -377,13 +514,14 @@ impl Schedule {
     }
 
     fn rebuild(&mut self) -> Result<()> {
-        // 1. If no stages defined, create a default one
-        if self.stages.is_empty() {
+        // 1. Without named stages, every system runs in the default stage
+        if self.stages.iter().all(|s| s.name == "default") {
             let mut default_stage = Stage::new("default");
             for i in 0..self.systems.len() {
                 default_stage.systems.push(SystemId(i as u32));
             }
-            self.stages.push(default_stage);
+            default_stage.sync_points = self.default_sync_points.clone();
+            self.stages = vec![default_stage];
         }
 
         // 2. Validate and sort stages
@@ -395,39 +533,153 @@ impl Schedule {
         let all_accesses = self.get_accesses();
 
         for stage in sorted_stages {
-            // Filter accesses for systems in this stage
-            let stage_system_ids = &stage.systems;
-            if stage_system_ids.is_empty() {
+            if stage.systems.is_empty() {
                 continue;
             }
 
-            let stage_accesses: Vec<SystemAccess> = stage_system_ids
-                .iter()
-                .map(|id| all_accesses[id.0 as usize].clone())
-                .collect();
-
-            let dep_graph = crate::dependency::DependencyGraph::new(stage_accesses);
-            let mut stage_parallel_groups = dep_graph.stages().to_vec();
-
-            // Map indices back to global SystemId indices
-            for group in &mut stage_parallel_groups {
-                for idx in &mut group.system_indices {
-                    *idx = stage_system_ids[*idx].0 as usize;
-                }
-            }
-
+            let (parallel_groups, sync_points) = self.plan_stage(&stage, &all_accesses)?;
             parallel_plan.push(StageExecutionPlan {
                 name: stage.name.clone(),
-                parallel_groups: stage_parallel_groups,
+                parallel_groups,
+                sync_points,
             });
         }
 
         self.parallel_plan = parallel_plan;
         self.graph = Some(SystemGraph::build(&self.systems));
+        self.command_buffers
+            .resize_with(self.systems.len(), CommandBuffer::new);
 
         Ok(())
     }
 
+    /// Order a stage's systems into parallel groups and sync points
+    ///
+    /// Ordering constraints and explicit sync points split the stage into
+    /// layers that run one after another; each layer is grouped by access
+    /// conflicts. Commands are applied between layers wherever a system
+    /// that may queue commands is ordered before another system.
+    fn plan_stage(
+        &self,
+        stage: &Stage,
+        accesses: &[SystemAccess],
+    ) -> Result<(Vec<crate::dependency::ExecutionStage>, Vec<usize>)> {
+        let count = stage.systems.len();
+        let system = |i: usize| &self.systems[stage.systems[i].0 as usize];
+
+        // Edges (from, to, flush). Nodes past `count` are explicit sync points.
+        let mut edges = Vec::new();
+        for (k, &position) in stage.sync_points.iter().enumerate() {
+            let node = count + k;
+            for i in 0..count {
+                if i < position {
+                    edges.push((i, node, false));
+                } else {
+                    edges.push((node, i, true));
+                }
+            }
+        }
+        for constraint in &self.ordering_constraints {
+            for i in (0..count).filter(|&i| system(i).name() == constraint.system_name) {
+                for j in (0..count).filter(|&j| j != i) {
+                    let other = system(j).name();
+                    if constraint.before.iter().any(|name| name == other) {
+                        edges.push((i, j, system(i).has_deferred()));
+                    }
+                    if constraint.after.iter().any(|name| name == other) {
+                        edges.push((j, i, system(j).has_deferred()));
+                    }
+                }
+            }
+        }
+        // Event writers run before the channel's readers, unless explicit
+        // ordering already says otherwise
+        let node_count = count + stage.sync_points.len();
+        let mut reachable = reachability(&edges, node_count);
+        let access = |i: usize| &accesses[stage.systems[i].0 as usize];
+        for i in 0..count {
+            for j in (0..count).filter(|&j| j != i) {
+                let reads_channel = access(i).event_writes.iter().any(|channel| {
+                    access(j).reads.contains(channel) && !access(j).writes.contains(channel)
+                });
+                if reads_channel && !reachable[j].contains(i) {
+                    edges.push((i, j, false));
+                    // Whatever reaches `i` now also reaches `j` and beyond
+                    let gained: Vec<usize> = reachable[j].ones().chain([j]).collect();
+                    for (source, reached) in reachable.iter_mut().enumerate() {
+                        if source == i || reached.contains(i) {
+                            for &node in &gained {
+                                reached.set(node);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        // Longest-path layering (Kahn's algorithm)
+        let mut successors = vec![Vec::new(); node_count];
+        let mut in_degree = vec![0; node_count];
+        for &(from, to, _) in &edges {
+            successors[from].push(to);
+            in_degree[to] += 1;
+        }
+        let mut layer = vec![0; node_count];
+        let mut queue: VecDeque<usize> = (0..node_count).filter(|&n| in_degree[n] == 0).collect();
+        let mut visited = 0;
+        while let Some(node) = queue.pop_front() {
+            visited += 1;
+            for &next in &successors[node] {
+                layer[next] = layer[next].max(layer[node] + 1);
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+        if visited != node_count {
+            return Err(EcsError::SystemCycleDetected);
+        }
+
+        let flush_after: FxHashSet<usize> = edges
+            .iter()
+            .filter(|&&(_, _, flush)| flush)
+            .map(|&(_, to, _)| layer[to] - 1)
+            .collect();
+
+        let max_layer = layer.iter().copied().max().unwrap_or(0);
+        let mut groups = Vec::new();
+        let mut sync_points = Vec::new();
+        for current in 0..=max_layer {
+            let members: Vec<usize> = (0..count).filter(|&i| layer[i] == current).collect();
+            if !members.is_empty() {
+                let layer_accesses = members
+                    .iter()
+                    .map(|&i| accesses[stage.systems[i].0 as usize].clone())
+                    .collect();
+                let dep_graph = crate::dependency::DependencyGraph::new(layer_accesses);
+
+                // Map indices back to global SystemId indices
+                for mut group in dep_graph.stages().to_vec() {
+                    for idx in &mut group.system_indices {
+                        *idx = stage.systems[members[*idx]].0 as usize;
+                    }
+                    groups.push(group);
+                }
+            }
+
+            let last_group = groups.len().checked_sub(1);
+            if flush_after.contains(&current)
+                && last_group.is_some()
+                && sync_points.last() != last_group.as_ref()
+            {
+                sync_points.extend(last_group);
+            }
+        }
+
+        Ok((groups, sync_points))
+    }
+
     fn topological_sort_stages_internal(&self) -> Result<Vec<Stage>> {
         let mut sorted = Vec::new();
         let mut visited = std::collections::HashSet::new();
@@ -487,11 +739,33 @@ impl Schedule {
         self.systems.get_mut(id.0 as usize)
     }
 
-    pub(crate) fn stage_plan(&self) -> Vec<&[SystemId]> {
-        self.stages
-            .iter()
-            .map(|stage| stage.systems.as_slice())
-            .collect()
+    /// A system together with the command buffer it owns
+    pub(crate) fn system_and_commands(
+        &mut self,
+        id: SystemId,
+    ) -> Option<(&mut BoxedSystem, &mut CommandBuffer)> {
+        let index = id.0 as usize;
+        Some((
+            self.systems.get_mut(index)?,
+            self.command_buffers.get_mut(index)?,
+        ))
+    }
+
+    /// Drop every queued command
+    pub(crate) fn clear_commands(&mut self) {
+        for buffer in &mut self.command_buffers {
+            buffer.clear();
+        }
+    }
+
+    /// Apply the command buffers of `systems`, in the given order
+    ///
+    /// Stops at the first failing command.
+    pub(crate) fn apply_commands(&mut self, systems: &[usize], world: &mut World) -> Result<()> {
+        for &index in systems {
+            self.command_buffers[index].apply(world)?;
+        }
+        Ok(())
     }
 
     /// Get system accesses for dependency analysis
@@ -512,6 +786,15 @@ impl Schedule {
     }
 }
 
+/// Evaluate every condition, so stateful ones observe each frame
+fn evaluate_all(conditions: &mut [BoxedCondition], world: &World) -> bool {
+    let mut run = true;
+    for condition in conditions {
+        run &= condition.evaluate(world);
+    }
+    run
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -539,10 +822,51 @@ mod tests {
             crate::system::SystemAccess {
                 reads: vec![],
                 writes: vec![],
+                event_writes: vec![],
             }
         }
     }
 
+    struct Named(&'static str, bool);
+    impl crate::system::System for Named {
+        fn run(
+            &mut self,
+            _world: &mut crate::World,
+            _commands: &mut crate::command::CommandBuffer,
+        ) -> crate::error::Result<()> {
+            Ok(())
+        }
+        fn name(&self) -> &'static str {
+            self.0
+        }
+        fn accesses(&self) -> crate::system::SystemAccess {
+            crate::system::SystemAccess::new()
+        }
+        fn has_deferred(&self) -> bool {
+            self.1
+        }
+    }
+
+    #[test]
+    fn test_sync_points_follow_deferred_ordering() {
+        let mut schedule = Schedule::new();
+        schedule.add_system(Box::new(Named("reader", false)));
+        schedule.add_system_after(Box::new(Named("after_reader", false)), "reader");
+        schedule.add_system_after(Box::new(Named("after_writer", false)), "writer");
+        schedule.add_system(Box::new(Named("writer", true)));
+        schedule.ensure_built().unwrap();
+
+        let plan = &schedule.parallel_plan[0];
+        let order: Vec<Vec<usize>> = plan
+            .parallel_groups
+            .iter()
+            .map(|g| g.system_indices.clone())
+            .collect();
+        // Ordering splits layers; only the writer's successor needs a flush
+        assert_eq!(order, [vec![0, 3], vec![1, 2]]);
+        assert_eq!(plan.sync_points, [0]);
+    }
+
     #[test]
     fn test_lazy_rebuild() {
         let mut schedule = Schedule::new();
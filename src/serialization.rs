@@ -17,13 +17,18 @@
 //! This module provides functionality to save and load ECS world state,
 //! enabling save/load systems, level serialization, and state persistence.
 
+use crate::archetype::ComponentColumn;
+use crate::component::Component;
 use crate::entity::EntityId;
 use crate::error::{EcsError, Result};
 use crate::reflection::Reflect;
-use crate::world::World;
+use crate::relation::{Relation, RelationKind, RelationSources};
+use crate::world::{ColumnSource, World};
 use serde::{Deserialize, Serialize};
+use slotmap::Key;
+use speedy::{LittleEndian, Readable, Writable};
 use std::any::TypeId;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// A serializable snapshot of world state
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -110,9 +115,235 @@ impl<T: Reflect + Serialize + for<'de> Deserialize<'de> + Clone + 'static> Compo
     }
 }
 
+/// Components that store entity IDs
+///
+/// Loading spawns every saved entity fresh, so IDs held inside components
+/// point at the saved world. Types registered with
+/// [`SerializationRegistry::register_mapped`] are rewritten through the
+/// [`EntityMap`] once the whole scene is spawned.
+pub trait MapEntities {
+    /// Replace every stored entity ID with its loaded counterpart
+    fn map_entities(&mut self, map: &EntityMap);
+}
+
+/// Saved-to-loaded entity translation handed to [`MapEntities`]
+pub struct EntityMap<'a> {
+    ids: &'a HashMap<u64, EntityId>,
+}
+
+impl<'a> EntityMap<'a> {
+    /// Wrap a saved-to-loaded ID map, as returned by `load_world`
+    pub fn new(ids: &'a HashMap<u64, EntityId>) -> Self {
+        Self { ids }
+    }
+
+    /// The loaded entity for `saved`, if the scene contained it
+    pub fn get(&self, saved: EntityId) -> Option<EntityId> {
+        self.ids.get(&saved.data().as_ffi()).copied()
+    }
+}
+
+/// Rewrite the entity IDs stored in one component type of `entities`
+pub type MapEntitiesHook = fn(&mut World, &[EntityId], &EntityMap);
+
+fn map_component_entities<T: Component + MapEntities>(
+    world: &mut World,
+    entities: &[EntityId],
+    map: &EntityMap,
+) {
+    for &entity in entities {
+        if let Some(component) = world.get_component_mut::<T>(entity) {
+            component.map_entities(map);
+        }
+    }
+}
+
+/// Like `map_component_entities`, also arming the `R` despawn cleanup
+fn map_relation_entities<R: RelationKind>(
+    world: &mut World,
+    entities: &[EntityId],
+    map: &EntityMap,
+) {
+    world.register_relation_kind::<R>();
+    map_component_entities::<Relation<R>>(world, entities, map);
+}
+
+/// Type-erased storage hooks for a registered component
+///
+/// These bridge the gap between raw archetype storage and `dyn Reflect`,
+/// allowing `save_world`/`load_world` to move components in and out of the
+/// world without knowing their concrete types.
+#[derive(Clone, Copy)]
+pub struct ComponentHooks {
+    /// Read the component stored at `row` of a column as `&dyn Reflect`
+    pub read_row: fn(&ComponentColumn, usize) -> Option<&dyn Reflect>,
+    /// Read an entity's component from any storage, used for sparse sets
+    pub read_entity: fn(&World, EntityId) -> Option<&dyn Reflect>,
+    /// Insert a boxed component into an entity
+    pub insert: fn(&mut World, EntityId, Box<dyn Reflect>) -> Result<()>,
+    /// Collect boxed components into a typed column, in order
+    pub collect_column: fn(Vec<Box<dyn Reflect>>) -> Result<DecodedColumn>,
+    /// Rewrite stored entity IDs after loading, for [`MapEntities`] types
+    pub map_entities: Option<MapEntitiesHook>,
+}
+
+impl ComponentHooks {
+    /// Create hooks for a concrete component type
+    pub fn of<T: Reflect + Clone>() -> Self {
+        fn downcast<T: Reflect + Clone>(component: &dyn Reflect) -> Result<T> {
+            component
+                .as_any()
+                .downcast_ref::<T>()
+                .cloned()
+                .ok_or_else(|| {
+                    EcsError::DeserializationError(format!(
+                        "Type mismatch: expected {}, got {}",
+                        std::any::type_name::<T>(),
+                        component.type_name()
+                    ))
+                })
+        }
+
+        Self {
+            read_row: |column, row| column.get::<T>(row).map(|c| c as &dyn Reflect),
+            read_entity: |world, entity| {
+                world.get_component::<T>(entity).map(|c| c as &dyn Reflect)
+            },
+            insert: |world, entity, component| {
+                world.add_component(entity, downcast::<T>(component.as_ref())?)
+            },
+            collect_column: |components| {
+                let items = components
+                    .iter()
+                    .map(|component| downcast::<T>(component.as_ref()))
+                    .collect::<Result<Vec<T>>>()?;
+                Ok(DecodedColumn(Box::new(items)))
+            },
+            map_entities: None,
+        }
+    }
+
+    /// Create hooks for a component type that stores entity IDs
+    pub fn mapped<T: Reflect + Clone + MapEntities>() -> Self {
+        Self {
+            map_entities: Some(map_component_entities::<T>),
+            ..Self::of::<T>()
+        }
+    }
+}
+
+/// Components decoded from one binary column, in row order
+///
+/// Produced by [`BinaryHooks::decode_column`] and
+/// [`ComponentHooks::collect_column`]; the loaders move the values into the
+/// world without boxing them.
+pub struct DecodedColumn(Box<dyn ColumnItems>);
+
+impl DecodedColumn {
+    /// Number of decoded components
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no components were decoded
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Typed values behind a [`DecodedColumn`]
+trait ColumnItems {
+    fn len(&self) -> usize;
+
+    /// Point `World::spawn_columns` at the values
+    fn source(&mut self) -> ColumnSource;
+
+    /// Forget the values once `World::spawn_columns` moved them out
+    ///
+    /// # Safety
+    /// Only valid after the values were moved.
+    unsafe fn forget(&mut self);
+}
+
+impl<T: Component> ColumnItems for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn source(&mut self) -> ColumnSource {
+        ColumnSource {
+            type_id: TypeId::of::<T>(),
+            column: ComponentColumn::new::<T>(),
+            items: self.as_mut_ptr().cast(),
+        }
+    }
+
+    unsafe fn forget(&mut self) {
+        self.set_len(0);
+    }
+}
+
+/// Binary (speedy) column codec for a registered component
+#[derive(Clone, Copy)]
+pub struct BinaryHooks {
+    /// Encode every component in a column, in row order
+    pub write_column: fn(&ComponentColumn) -> Result<Vec<u8>>,
+    /// Encode the components of `entities` from any storage, in order
+    pub write_entities: fn(&World, &[EntityId]) -> Result<Vec<u8>>,
+    /// Decode a column previously produced by `write_column`
+    pub decode_column: fn(&[u8]) -> Result<DecodedColumn>,
+}
+
+impl BinaryHooks {
+    /// Create binary hooks for a concrete component type
+    pub fn of<T>() -> Self
+    where
+        T: Reflect + Clone + for<'a> Readable<'a, LittleEndian> + Writable<LittleEndian>,
+    {
+        Self {
+            write_column: |column| {
+                let items: Vec<&T> = (0..column.len())
+                    .map(|row| column.get::<T>(row))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| {
+                        EcsError::SerializationError(format!(
+                            "Column data missing for {}",
+                            std::any::type_name::<T>()
+                        ))
+                    })?;
+                items
+                    .write_to_vec()
+                    .map_err(|e| EcsError::SerializationError(e.to_string()))
+            },
+            write_entities: |world, entities| {
+                let items: Vec<&T> = entities
+                    .iter()
+                    .map(|&entity| world.get_component::<T>(entity))
+                    .collect::<Option<_>>()
+                    .ok_or_else(|| {
+                        EcsError::SerializationError(format!(
+                            "Component data missing for {}",
+                            std::any::type_name::<T>()
+                        ))
+                    })?;
+                items
+                    .write_to_vec()
+                    .map_err(|e| EcsError::SerializationError(e.to_string()))
+            },
+            decode_column: |bytes| {
+                let items = Vec::<T>::read_from_buffer(bytes)
+                    .map_err(|e| EcsError::DeserializationError(e.to_string()))?;
+                Ok(DecodedColumn(Box::new(items)))
+            },
+        }
+    }
+}
+
 /// Extended type registry with serialization support
 pub struct SerializationRegistry {
     serializers: HashMap<TypeId, Box<dyn ComponentSerializer>>,
+    hooks: HashMap<TypeId, ComponentHooks>,
+    binary_hooks: HashMap<TypeId, BinaryHooks>,
     type_names: HashMap<String, TypeId>,
 }
 
@@ -121,6 +352,8 @@ impl SerializationRegistry {
     pub fn new() -> Self {
         Self {
             serializers: HashMap::new(),
+            hooks: HashMap::new(),
+            binary_hooks: HashMap::new(),
             type_names: HashMap::new(),
         }
     }
@@ -135,18 +368,88 @@ impl SerializationRegistry {
 
         self.serializers
             .insert(type_id, Box::new(TypedComponentSerializer::<T>::new()));
+        self.hooks.insert(type_id, ComponentHooks::of::<T>());
         self.type_names.insert(type_name, type_id);
     }
 
+    /// Register a component type whose entity IDs are remapped on load
+    pub fn register_mapped<T>(&mut self)
+    where
+        T: Reflect + Serialize + for<'de> Deserialize<'de> + Clone + MapEntities + 'static,
+    {
+        self.register::<T>();
+        self.hooks
+            .insert(TypeId::of::<T>(), ComponentHooks::mapped::<T>());
+    }
+
+    /// Register both sides of the `R` relation
+    ///
+    /// Loaded relations are remapped and cleaned up on despawn, like ones
+    /// added through `World::add_relation`.
+    pub fn register_relation<R: RelationKind>(&mut self) {
+        self.register_mapped::<Relation<R>>();
+        self.register_mapped::<RelationSources<R>>();
+        if let Some(hooks) = self.hooks.get_mut(&TypeId::of::<Relation<R>>()) {
+            hooks.map_entities = Some(map_relation_entities::<R>);
+        }
+    }
+
+    /// Register a component type for both JSON and binary serialization
+    pub fn register_binary<T>(&mut self)
+    where
+        T: Reflect
+            + Serialize
+            + for<'de> Deserialize<'de>
+            + for<'a> Readable<'a, LittleEndian>
+            + Writable<LittleEndian>
+            + Clone
+            + 'static,
+    {
+        self.register::<T>();
+        self.binary_hooks
+            .insert(TypeId::of::<T>(), BinaryHooks::of::<T>());
+    }
+
     /// Get serializer for a type
     pub fn get_serializer(&self, type_id: TypeId) -> Option<&dyn ComponentSerializer> {
         self.serializers.get(&type_id).map(|s| s.as_ref())
     }
 
+    /// Get storage hooks for a type
+    pub fn get_hooks(&self, type_id: TypeId) -> Option<&ComponentHooks> {
+        self.hooks.get(&type_id)
+    }
+
+    /// Get binary codec for a type
+    pub fn get_binary_hooks(&self, type_id: TypeId) -> Option<&BinaryHooks> {
+        self.binary_hooks.get(&type_id)
+    }
+
     /// Get type ID from type name
     pub fn get_type_id(&self, type_name: &str) -> Option<TypeId> {
         self.type_names.get(type_name).copied()
     }
+
+    /// Rewrite entity IDs stored in the components of freshly loaded entities
+    fn map_loaded_entities(&self, world: &mut World, id_map: &HashMap<u64, EntityId>) {
+        let entities: Vec<EntityId> = id_map.values().copied().collect();
+        let map = EntityMap::new(id_map);
+        for hook in self.hooks.values().filter_map(|hooks| hooks.map_entities) {
+            hook(world, &entities, &map);
+        }
+    }
+
+    /// Registered types stored in `world`'s sparse sets, by type name
+    fn sparse_types(&self, world: &World) -> Vec<(&str, TypeId)> {
+        let mut types: Vec<(&str, TypeId)> = self
+            .type_names
+            .iter()
+            .filter(|(_, &type_id)| world.sparse_storage().is_sparse(type_id))
+            .map(|(name, &type_id)| (name.as_str(), type_id))
+            .collect();
+        types.sort_unstable();
+        types
+    }
 }
 
 impl Default for SerializationRegistry {
@@ -156,41 +459,75 @@ impl Default for SerializationRegistry {
 }
 
 /// Save world state to a scene
+///
+/// Only components registered in `registry` are written, including
+/// sparse-set ones; entities without any registered component are skipped.
+/// Register the same sparse storage on the world a scene is loaded into to
+/// keep those components sparse.
 pub fn save_world(world: &World, registry: &SerializationRegistry) -> Result<Scene> {
     let mut scene = Scene::new();
 
-    // Iterate through all entities
+    // Sparse-set components aren't part of any archetype signature
+    let sparse: Vec<_> = registry
+        .sparse_types(world)
+        .into_iter()
+        .filter_map(|(_, type_id)| {
+            Some((
+                registry.get_serializer(type_id)?,
+                registry.get_hooks(type_id)?,
+            ))
+        })
+        .collect();
+
     for archetype in world.archetypes() {
-        for &entity_id in archetype.entities().iter() {
-            let entity_data = EntityData {
-                id: unsafe { std::mem::transmute::<EntityId, u64>(entity_id) },
-                components: HashMap::new(),
+        // Resolve serializable columns once per archetype, not per entity
+        let mut columns = Vec::new();
+        for &component_type in archetype.signature() {
+            let (Some(serializer), Some(hooks), Some(column)) = (
+                registry.get_serializer(component_type),
+                registry.get_hooks(component_type),
+                archetype.get_column(component_type),
+            ) else {
+                continue;
             };
+            columns.push((serializer, hooks, column));
+        }
 
-            // Serialize each component in this archetype
-            for &component_type in archetype.signature() {
-                if let Some(serializer) = registry.get_serializer(component_type) {
-                    if let Some(_column) = archetype.get_column(component_type) {
-                        // Get component as Reflect trait object
-                        // Note: This requires components to implement Reflect
-                        // For now, we'll use a simplified approach with the serializer
-                        let _type_name = serializer.type_name();
-
-                        // We need to get the actual component data
-                        // This is a limitation - we need a way to get &dyn Reflect from ComponentColumn
-                        // For MVP, we'll document this limitation and provide a workaround
-
-                        // TODO: Implement proper component reflection
-                        // For now, skip components that can't be serialized
-                        continue;
-                    }
+        if columns.is_empty() && sparse.is_empty() {
+            continue;
+        }
+
+        for (row, &entity_id) in archetype.entities().iter().enumerate() {
+            let mut components = HashMap::with_capacity(columns.len());
+
+            for &(serializer, hooks, column) in &columns {
+                let component = (hooks.read_row)(column, row).ok_or_else(|| {
+                    EcsError::SerializationError(format!(
+                        "Missing {} data for entity {entity_id}",
+                        serializer.type_name()
+                    ))
+                })?;
+                components.insert(
+                    serializer.type_name().to_string(),
+                    serializer.serialize_json(component)?,
+                );
+            }
+            for &(serializer, hooks) in &sparse {
+                if let Some(component) = (hooks.read_entity)(world, entity_id) {
+                    components.insert(
+                        serializer.type_name().to_string(),
+                        serializer.serialize_json(component)?,
+                    );
                 }
             }
 
-            // Only add entities that have serializable components
-            if !entity_data.components.is_empty() {
-                scene.entities.push(entity_data);
+            if components.is_empty() {
+                continue;
             }
+            scene.entities.push(EntityData {
+                id: entity_id.data().as_ffi(),
+                components,
+            });
         }
     }
 
@@ -198,18 +535,362 @@ pub fn save_world(world: &World, registry: &SerializationRegistry) -> Result<Sce
 }
 
 /// Load world state from a scene
+///
+/// Spawns a fresh entity for every `EntityData` in the scene and returns a map
+/// from the saved entity IDs to the newly spawned ones. Once everything is
+/// spawned, components registered with
+/// [`SerializationRegistry::register_mapped`] (e.g. `Parent`, `Children`) have
+/// their entity IDs remapped; references to entities outside the scene are
+/// dropped, leaving a null `Parent`.
+///
+/// Every component is deserialized before anything is spawned, so a bad
+/// scene leaves `world` untouched. Entities sharing a component set are then
+/// spawned together, each with all of its components at once.
+///
+/// # Errors
+/// Returns `EcsError::DeserializationError` if the scene references a
+/// component type that is not registered in `registry`, or if a component
+/// fails to deserialize.
 pub fn load_world(
-    _world: &mut World,
-    _scene: &Scene,
-    _registry: &SerializationRegistry,
+    world: &mut World,
+    scene: &Scene,
+    registry: &SerializationRegistry,
+) -> Result<HashMap<u64, EntityId>> {
+    struct Group<'a> {
+        hooks: Vec<&'a ComponentHooks>,
+        ids: Vec<u64>,
+        components: Vec<Vec<Box<dyn Reflect>>>,
+    }
+
+    // Group entities by component set, in scene order
+    let mut group_index: HashMap<Vec<TypeId>, usize> = HashMap::new();
+    let mut groups: Vec<Group> = Vec::new();
+    for entity_data in &scene.entities {
+        let mut components = Vec::with_capacity(entity_data.components.len());
+        for (type_name, value) in &entity_data.components {
+            let type_id = registry.get_type_id(type_name).ok_or_else(|| {
+                EcsError::DeserializationError(format!("Unregistered component type: {type_name}"))
+            })?;
+            let (Some(serializer), Some(hooks)) = (
+                registry.get_serializer(type_id),
+                registry.get_hooks(type_id),
+            ) else {
+                return Err(EcsError::DeserializationError(format!(
+                    "Missing serialization hooks for {type_name}"
+                )));
+            };
+            components.push((type_id, hooks, serializer.deserialize_json(value)?));
+        }
+        components.sort_unstable_by_key(|&(type_id, ..)| type_id);
+
+        let type_ids: Vec<TypeId> = components.iter().map(|&(type_id, ..)| type_id).collect();
+        let index = *group_index.entry(type_ids).or_insert_with(|| {
+            groups.push(Group {
+                hooks: components.iter().map(|&(_, hooks, _)| hooks).collect(),
+                ids: Vec::new(),
+                components: components.iter().map(|_| Vec::new()).collect(),
+            });
+            groups.len() - 1
+        });
+        let group = &mut groups[index];
+        group.ids.push(entity_data.id);
+        for (column, (.., component)) in group.components.iter_mut().zip(components) {
+            column.push(component);
+        }
+    }
+
+    let mut batches = Vec::with_capacity(groups.len());
+    for group in groups {
+        let columns = group
+            .hooks
+            .iter()
+            .zip(group.components)
+            .map(|(hooks, components)| (hooks.collect_column)(components))
+            .collect::<Result<Vec<_>>>()?;
+        batches.push((group.ids, columns));
+    }
+
+    let mut id_map = HashMap::with_capacity(scene.entities.len());
+    for (ids, columns) in batches {
+        // SAFETY: A group's columns have distinct types, since scene
+        // components are keyed by type name, and one value per entity
+        let entities = unsafe { spawn_decoded(world, ids.len(), columns) };
+        id_map.extend(ids.into_iter().zip(entities));
+    }
+    registry.map_loaded_entities(world, &id_map);
+
+    Ok(id_map)
+}
+
+/// Move decoded columns into `count` freshly spawned entities
+///
+/// # Safety
+/// The columns must have distinct types and hold `count` values each.
+unsafe fn spawn_decoded(
+    world: &mut World,
+    count: usize,
+    mut columns: Vec<DecodedColumn>,
+) -> Vec<EntityId> {
+    let sources = columns.iter_mut().map(|column| column.0.source()).collect();
+    let entities = world.spawn_columns(count, sources);
+    // The values were moved out, so the columns must not drop them
+    for column in &mut columns {
+        column.0.forget();
+    }
+    entities
+}
+
+/// Magic bytes at the start of every binary scene
+pub const BINARY_SCENE_MAGIC: [u8; 4] = *b"AECS";
+
+/// Current binary scene format version
+pub const BINARY_SCENE_VERSION: u32 = 1;
+
+/// Header written before the binary scene payload
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Readable, Writable)]
+pub struct BinarySceneHeader {
+    pub magic: [u8; 4],
+    pub version: u32,
+}
+
+/// Compact binary snapshot of world state
+///
+/// Unlike the JSON `Scene`, component data is stored column-wise: entities
+/// sharing the same set of serializable components are grouped into a table,
+/// and component type names are written once in a shared table.
+#[derive(Debug, Clone, Default, Readable, Writable)]
+pub struct BinaryScene {
+    /// Component type names, referenced by index from each table
+    pub type_names: Vec<String>,
+    /// Entity groups sharing a component layout
+    pub tables: Vec<BinaryTable>,
+}
+
+/// Entities sharing the same serializable component layout
+#[derive(Debug, Clone, Default, Readable, Writable)]
+pub struct BinaryTable {
+    /// Indices into `BinaryScene::type_names`
+    pub types: Vec<u32>,
+    /// Original entity IDs (remapped on load)
+    pub entities: Vec<u64>,
+    /// Encoded component columns, parallel to `types`
+    pub columns: Vec<Vec<u8>>,
+}
+
+impl BinaryScene {
+    /// Get number of entities in scene
+    pub fn entity_count(&self) -> usize {
+        self.tables.iter().map(|t| t.entities.len()).sum()
+    }
+
+    /// Encode the scene with a versioned header
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let header = BinarySceneHeader {
+            magic: BINARY_SCENE_MAGIC,
+            version: BINARY_SCENE_VERSION,
+        };
+        let mut bytes = header
+            .write_to_vec()
+            .map_err(|e| EcsError::SerializationError(e.to_string()))?;
+        self.write_to_stream(&mut bytes)
+            .map_err(|e| EcsError::SerializationError(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Decode a scene, validating the header
+    ///
+    /// # Errors
+    /// Returns `EcsError::DeserializationError` if the magic bytes or format
+    /// version do not match, or the payload is malformed.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        // Magic + little-endian u32 version
+        let header_len = BINARY_SCENE_MAGIC.len() + std::mem::size_of::<u32>();
+
+        if bytes.len() < header_len {
+            return Err(EcsError::DeserializationError(
+                "Binary scene is truncated".to_string(),
+            ));
+        }
+
+        let header = BinarySceneHeader::read_from_buffer(&bytes[..header_len])
+            .map_err(|e| EcsError::DeserializationError(e.to_string()))?;
+        if header.magic != BINARY_SCENE_MAGIC {
+            return Err(EcsError::DeserializationError(
+                "Not a binary scene (bad magic)".to_string(),
+            ));
+        }
+        if header.version != BINARY_SCENE_VERSION {
+            return Err(EcsError::DeserializationError(format!(
+                "Unsupported binary scene version {} (expected {BINARY_SCENE_VERSION})",
+                header.version
+            )));
+        }
+
+        Self::read_from_buffer(&bytes[header_len..])
+            .map_err(|e| EcsError::DeserializationError(e.to_string()))
+    }
+}
+
+/// Save world state to a binary scene
+///
+/// Only components registered with `SerializationRegistry::register_binary`
+/// are written, including sparse-set ones. Rows of an archetype are split
+/// into one table per combination of sparse components they have.
+pub fn save_world_binary(world: &World, registry: &SerializationRegistry) -> Result<BinaryScene> {
+    let mut scene = BinaryScene::default();
+    let mut type_indices: HashMap<TypeId, u32> = HashMap::new();
+    let mut type_index = |type_id: TypeId, serializer: &dyn ComponentSerializer| {
+        *type_indices.entry(type_id).or_insert_with(|| {
+            scene.type_names.push(serializer.type_name().to_string());
+            (scene.type_names.len() - 1) as u32
+        })
+    };
+
+    let sparse: Vec<_> = registry
+        .sparse_types(world)
+        .into_iter()
+        .filter_map(|(_, type_id)| {
+            Some((
+                type_id,
+                registry.get_serializer(type_id)?,
+                registry.get_binary_hooks(type_id)?,
+            ))
+        })
+        .collect();
+
+    let mut tables = Vec::new();
+    for archetype in world.archetypes() {
+        if archetype.is_empty() {
+            continue;
+        }
+
+        let mut columns = Vec::new();
+        for &component_type in archetype.signature() {
+            let (Some(serializer), Some(hooks), Some(column)) = (
+                registry.get_serializer(component_type),
+                registry.get_binary_hooks(component_type),
+                archetype.get_column(component_type),
+            ) else {
+                continue;
+            };
+            columns.push((type_index(component_type, serializer), hooks, column));
+        }
+
+        // Group rows by the sparse components they have, in row order
+        let mut groups: BTreeMap<Vec<usize>, Vec<EntityId>> = BTreeMap::new();
+        for &entity in archetype.entities() {
+            let present = sparse
+                .iter()
+                .enumerate()
+                .filter(|(_, &(type_id, _, _))| world.sparse_storage().contains(type_id, entity))
+                .map(|(index, _)| index)
+                .collect();
+            groups.entry(present).or_default().push(entity);
+        }
+
+        for (present, entities) in groups {
+            if columns.is_empty() && present.is_empty() {
+                continue;
+            }
+
+            let mut table = BinaryTable::default();
+            // Whole archetypes encode straight from their columns
+            let whole = entities.len() == archetype.len();
+            for &(index, hooks, column) in &columns {
+                table.types.push(index);
+                table.columns.push(if whole {
+                    (hooks.write_column)(column)?
+                } else {
+                    (hooks.write_entities)(world, &entities)?
+                });
+            }
+            for &sparse_index in &present {
+                let (type_id, serializer, hooks) = sparse[sparse_index];
+                table.types.push(type_index(type_id, serializer));
+                table
+                    .columns
+                    .push((hooks.write_entities)(world, &entities)?);
+            }
+            table.entities = entities
+                .iter()
+                .map(|entity| entity.data().as_ffi())
+                .collect();
+            tables.push(table);
+        }
+    }
+
+    scene.tables = tables;
+    Ok(scene)
+}
+
+/// Load world state from a binary scene
+///
+/// Behaves like `load_world`: every saved entity is spawned fresh, stored
+/// entity IDs are remapped, and the returned map translates saved IDs to the
+/// new ones. Every table is decoded
+/// and checked before anything is spawned, so a bad scene leaves `world`
+/// untouched; each table's rows then land in their archetype in one batch.
+pub fn load_world_binary(
+    world: &mut World,
+    scene: &BinaryScene,
+    registry: &SerializationRegistry,
 ) -> Result<HashMap<u64, EntityId>> {
-    let id_map = HashMap::new();
+    // Resolve the type table once up front
+    let mut resolved = Vec::with_capacity(scene.type_names.len());
+    for type_name in &scene.type_names {
+        let type_id = registry.get_type_id(type_name).ok_or_else(|| {
+            EcsError::DeserializationError(format!("Unregistered component type: {type_name}"))
+        })?;
+        let binary = registry.get_binary_hooks(type_id).ok_or_else(|| {
+            EcsError::DeserializationError(format!(
+                "Missing binary serialization hooks for {type_name}"
+            ))
+        })?;
+        resolved.push((type_id, binary));
+    }
+
+    let mut tables = Vec::with_capacity(scene.tables.len());
+    for table in &scene.tables {
+        if table.types.len() != table.columns.len() {
+            return Err(EcsError::DeserializationError(
+                "Binary table type/column count mismatch".to_string(),
+            ));
+        }
+
+        let mut type_ids = Vec::with_capacity(table.types.len());
+        let mut columns = Vec::with_capacity(table.types.len());
+        for (&type_index, bytes) in table.types.iter().zip(&table.columns) {
+            let &(type_id, binary) = resolved.get(type_index as usize).ok_or_else(|| {
+                EcsError::DeserializationError(format!("Invalid type index {type_index}"))
+            })?;
+            if type_ids.contains(&type_id) {
+                return Err(EcsError::DeserializationError(format!(
+                    "Duplicate type index {type_index} in binary table"
+                )));
+            }
+
+            let column = (binary.decode_column)(bytes)?;
+            if column.len() != table.entities.len() {
+                return Err(EcsError::DeserializationError(format!(
+                    "Column length {} does not match entity count {}",
+                    column.len(),
+                    table.entities.len()
+                )));
+            }
+            type_ids.push(type_id);
+            columns.push(column);
+        }
+        tables.push((table, columns));
+    }
 
-    // TODO: Implement proper deserialization
-    // This requires:
-    // 1. A way to spawn entities with Box<dyn Reflect> components
-    // 2. Component type registration that includes spawn hooks
-    // For MVP, this is a known limitation that will be addressed in future iterations
+    let mut id_map = HashMap::with_capacity(scene.entity_count());
+    for (table, columns) in tables {
+        // SAFETY: Every column was checked to hold one value per entity, with
+        // distinct types
+        let entities = unsafe { spawn_decoded(world, table.entities.len(), columns) };
+        id_map.extend(table.entities.iter().copied().zip(entities));
+    }
+    registry.map_loaded_entities(world, &id_map);
 
     Ok(id_map)
 }
@@ -234,5 +915,330 @@ mod tests {
 
         assert!(registry.get_serializer(TypeId::of::<i32>()).is_some());
         assert!(registry.get_serializer(TypeId::of::<f32>()).is_some());
+        assert!(registry.get_hooks(TypeId::of::<i32>()).is_some());
+    }
+
+    #[test]
+    fn test_save_load_round_trip() {
+        let mut registry = SerializationRegistry::new();
+        registry.register::<i32>();
+        registry.register::<f32>();
+
+        let mut world = World::new();
+        let a = world.spawn_entity((1i32, 2.5f32));
+        let b = world.spawn_entity((7i32,));
+        world.spawn_entity((0u8,)); // Not registered, skipped
+
+        let scene = save_world(&world, &registry).unwrap();
+        assert_eq!(scene.entity_count(), 2);
+
+        // Round-trip through JSON text to exercise the full path
+        let json = serde_json::to_string(&scene).unwrap();
+        let scene: Scene = serde_json::from_str(&json).unwrap();
+
+        let mut loaded = World::new();
+        let id_map = load_world(&mut loaded, &scene, &registry).unwrap();
+        assert_eq!(id_map.len(), 2);
+
+        let new_a = id_map[&a.data().as_ffi()];
+        let new_b = id_map[&b.data().as_ffi()];
+        assert_eq!(loaded.get_component::<i32>(new_a), Some(&1));
+        assert_eq!(loaded.get_component::<f32>(new_a), Some(&2.5));
+        assert_eq!(loaded.get_component::<i32>(new_b), Some(&7));
+        assert!(!loaded.has_component::<f32>(new_b));
+    }
+
+    #[test]
+    fn test_load_unregistered_type_fails() {
+        let mut scene = Scene::new();
+        let mut components = HashMap::new();
+        components.insert("unknown::Type".to_string(), serde_json::Value::Null);
+        scene.entities.push(EntityData { id: 1, components });
+
+        let mut world = World::new();
+        let registry = SerializationRegistry::new();
+        assert!(load_world(&mut world, &scene, &registry).is_err());
+    }
+
+    #[test]
+    fn test_load_failure_leaves_world_untouched() {
+        let mut registry = SerializationRegistry::new();
+        registry.register::<i32>();
+        registry.register::<f32>();
+
+        let mut world = World::new();
+        for i in 0..3 {
+            world.spawn_entity((i, i as f32));
+        }
+        let mut scene = save_world(&world, &registry).unwrap();
+
+        let mut loaded = World::new();
+        loaded.spawn_entity((5i32,));
+        let id_map = load_world(&mut loaded, &scene, &registry).unwrap();
+        assert_eq!(loaded.entity_count(), 4);
+        for entity_data in &scene.entities {
+            let entity = id_map[&entity_data.id];
+            let value = *loaded.get_component::<i32>(entity).unwrap();
+            assert_eq!(loaded.get_component::<f32>(entity), Some(&(value as f32)));
+        }
+
+        // A bad last entity must not leave the earlier ones spawned
+        let last = scene.entities.last_mut().unwrap();
+        last.components
+            .insert(std::any::type_name::<f32>().to_string(), "nope".into());
+        let mut loaded = World::new();
+        assert!(load_world(&mut loaded, &scene, &registry).is_err());
+        assert_eq!(loaded.entity_count(), 0);
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Readable, Writable)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    crate::impl_reflect!(Position);
+
+    #[test]
+    fn test_binary_round_trip() {
+        let mut registry = SerializationRegistry::new();
+        registry.register_binary::<Position>();
+        registry.register_binary::<u32>();
+
+        let mut world = World::new();
+        let a = world.spawn_entity((Position { x: 1.0, y: 2.0 }, 5u32));
+        let b = world.spawn_entity((Position { x: 3.0, y: 4.0 },));
+
+        let bytes = world.save_binary(&registry).unwrap();
+        assert_eq!(&bytes[..4], &BINARY_SCENE_MAGIC);
+
+        let mut loaded = World::new();
+        let id_map = loaded.load_binary(&bytes, &registry).unwrap();
+        assert_eq!(id_map.len(), 2);
+
+        let new_a = id_map[&a.data().as_ffi()];
+        let new_b = id_map[&b.data().as_ffi()];
+        assert_eq!(
+            loaded.get_component::<Position>(new_a),
+            Some(&Position { x: 1.0, y: 2.0 })
+        );
+        assert_eq!(loaded.get_component::<u32>(new_a), Some(&5));
+        assert_eq!(
+            loaded.get_component::<Position>(new_b),
+            Some(&Position { x: 3.0, y: 4.0 })
+        );
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Readable, Writable)]
+    struct Label {
+        text: String,
+    }
+
+    crate::impl_reflect!(Label);
+
+    #[test]
+    fn test_binary_load_checks_every_table_first() {
+        let mut registry = SerializationRegistry::new();
+        registry.register_binary::<Position>();
+        registry.register_binary::<Label>();
+
+        let mut world = World::new();
+        for i in 0..3 {
+            world.spawn_entity((Label {
+                text: format!("label {i}"),
+            },));
+        }
+        let a = world.spawn_entity((
+            Position { x: 1.0, y: 2.0 },
+            Label {
+                text: "a".to_string(),
+            },
+        ));
+
+        let mut scene = save_world_binary(&world, &registry).unwrap();
+        let mut loaded = World::new();
+        let id_map = load_world_binary(&mut loaded, &scene, &registry).unwrap();
+        assert_eq!(id_map.len(), 4);
+        assert_eq!(
+            loaded.get_component::<Label>(id_map[&a.data().as_ffi()]),
+            Some(&Label {
+                text: "a".to_string()
+            })
+        );
+
+        // A bad last table must not leave the earlier ones spawned
+        scene.tables.last_mut().unwrap().entities.push(0);
+        let mut loaded = World::new();
+        assert!(load_world_binary(&mut loaded, &scene, &registry).is_err());
+        assert_eq!(loaded.entity_count(), 0);
+    }
+
+    #[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Readable, Writable)]
+    struct Stunned {
+        turns: u32,
+    }
+
+    crate::impl_reflect!(Stunned);
+
+    fn sparse_world() -> World {
+        let mut world = World::new();
+        world
+            .register_storage::<Stunned>(crate::StorageType::SparseSet)
+            .unwrap();
+        world
+    }
+
+    #[test]
+    fn test_sparse_components_round_trip() {
+        let mut registry = SerializationRegistry::new();
+        registry.register_binary::<Position>();
+        registry.register_binary::<Stunned>();
+
+        let mut world = sparse_world();
+        let a = world.spawn_entity((Position { x: 1.0, y: 0.0 }, Stunned { turns: 2 }));
+        let b = world.spawn_entity((Position { x: 2.0, y: 0.0 },));
+        let c = world.spawn_entity((Stunned { turns: 3 },));
+        let archetypes = world.archetype_count();
+
+        let scene = save_world(&world, &registry).unwrap();
+        let bytes = world.save_binary(&registry).unwrap();
+        assert_eq!(scene.entity_count(), 3);
+
+        let mut from_json = sparse_world();
+        let json_ids = load_world(&mut from_json, &scene, &registry).unwrap();
+        let mut from_binary = sparse_world();
+        let binary_ids = from_binary.load_binary(&bytes, &registry).unwrap();
+
+        for (loaded, ids) in [(&from_json, json_ids), (&from_binary, binary_ids)] {
+            let [a, b, c] = [a, b, c].map(|entity| ids[&entity.data().as_ffi()]);
+            assert_eq!(
+                loaded.get_component::<Stunned>(a),
+                Some(&Stunned { turns: 2 })
+            );
+            assert_eq!(
+                loaded.get_component::<Position>(a),
+                Some(&Position { x: 1.0, y: 0.0 })
+            );
+            assert!(!loaded.has_component::<Stunned>(b));
+            assert_eq!(
+                loaded.get_component::<Stunned>(c),
+                Some(&Stunned { turns: 3 })
+            );
+            assert_eq!(loaded.archetype_count(), archetypes);
+        }
+    }
+
+    #[test]
+    fn test_insert_reflect_keeps_sparse_storage() {
+        let mut world = sparse_world();
+        world.register_type::<Stunned>();
+        let entity = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+        let archetypes = world.archetype_count();
+
+        world
+            .insert_reflect(entity, Box::new(Stunned { turns: 4 }))
+            .unwrap();
+        assert_eq!(world.archetype_count(), archetypes);
+        assert_eq!(world.sparse_storage().len_of(TypeId::of::<Stunned>()), 1);
+
+        let stunned = world
+            .get_reflect_mut(entity, TypeId::of::<Stunned>())
+            .unwrap();
+        stunned
+            .as_any_mut()
+            .downcast_mut::<Stunned>()
+            .unwrap()
+            .turns = 1;
+        let stunned = world.get_reflect(entity, TypeId::of::<Stunned>()).unwrap();
+        assert_eq!(
+            stunned.as_any().downcast_ref::<Stunned>(),
+            Some(&Stunned { turns: 1 })
+        );
+    }
+
+    #[test]
+    fn test_binary_rejects_bad_header() {
+        let registry = SerializationRegistry::new();
+        let mut bytes = BinaryScene::default().to_bytes().unwrap();
+
+        let mut world = World::new();
+        assert!(world.load_binary(&bytes, &registry).is_ok());
+
+        bytes[0] = b'X';
+        assert!(world.load_binary(&bytes, &registry).is_err());
+        assert!(world.load_binary(&[], &registry).is_err());
+    }
+
+    #[test]
+    fn test_parent_child_remapped_on_load() {
+        use crate::hierarchy::{Children, Parent};
+
+        let mut registry = SerializationRegistry::new();
+        registry.register::<i32>();
+        registry.register_mapped::<Parent>();
+        registry.register_mapped::<Children>();
+
+        let mut world = World::new();
+        // Offset the saved IDs so stale references can't pass by accident
+        for i in 0..3 {
+            world.spawn_entity((i as u8,));
+        }
+        let parent = world.spawn_entity((1i32,));
+        let child = world.spawn_entity((2i32, Parent(parent)));
+        let mut children = Children::new();
+        children.add_child(child);
+        world.add_component(parent, children).unwrap();
+
+        let json = serde_json::to_string(&save_world(&world, &registry).unwrap()).unwrap();
+        let scene: Scene = serde_json::from_str(&json).unwrap();
+
+        let mut loaded = World::new();
+        let id_map = load_world(&mut loaded, &scene, &registry).unwrap();
+        let new_parent = id_map[&parent.data().as_ffi()];
+        let new_child = id_map[&child.data().as_ffi()];
+        assert_ne!(new_parent, parent);
+        assert_eq!(loaded.get_parent(new_child), Some(new_parent));
+        assert_eq!(loaded.get_children(new_parent), Some(vec![new_child]));
+        assert_eq!(loaded.get_component::<i32>(new_child), Some(&2));
+    }
+
+    #[test]
+    fn test_relations_remapped_on_load() {
+        struct Targets;
+
+        let mut registry = SerializationRegistry::new();
+        registry.register::<i32>();
+        registry.register_relation::<Targets>();
+
+        let mut world = World::new();
+        world.spawn_entity((0u8,));
+        let archer = world.spawn_entity((1i32,));
+        let goblin = world.spawn_entity((2i32,));
+        let outsider = world.spawn_entity((3i32,));
+        world.add_relation::<Targets>(archer, goblin).unwrap();
+        world.add_relation::<Targets>(archer, outsider).unwrap();
+
+        let mut scene = save_world(&world, &registry).unwrap();
+        scene
+            .entities
+            .retain(|entity_data| entity_data.id != outsider.data().as_ffi());
+        let mut loaded = World::new();
+        let id_map = load_world(&mut loaded, &scene, &registry).unwrap();
+        let new_archer = id_map[&archer.data().as_ffi()];
+        let new_goblin = id_map[&goblin.data().as_ffi()];
+
+        // The target missing from the scene is dropped
+        assert_eq!(
+            loaded.relation_targets::<Targets>(new_archer),
+            &[new_goblin]
+        );
+        assert_eq!(
+            loaded.relation_sources::<Targets>(new_goblin),
+            &[new_archer]
+        );
+
+        // Despawn cleanup works without any add_relation call
+        loaded.despawn(new_goblin).unwrap();
+        assert!(loaded.relation_targets::<Targets>(new_archer).is_empty());
     }
 }
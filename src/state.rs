@@ -0,0 +1,256 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! App-level state machines
+//!
+//! A state is any small enum kept in the [`State<S>`] resource. Systems
+//! request a change by setting [`NextState<S>`]; `App::update` applies it at
+//! the start of the next frame, running the `OnExit(old)`,
+//! `OnTransition { from, to }` and `OnEnter(new)` schedules in that order.
+//! The initial state's `OnEnter` schedule runs on the first update.
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//! use archetype_ecs::GameState;
+//!
+//! fn start_game(mut next: ResMut<NextState<GameState>>) {
+//!     next.set(GameState::Playing);
+//! }
+//! fn spawn_level() {}
+//!
+//! let mut app = App::new();
+//! app.insert_state(GameState::Menu)
+//!     .add_state_system(OnEnter(GameState::Playing), Box::new(spawn_level.into_system()));
+//! app.schedule
+//!     .add_system_with_condition(Box::new(start_game.into_system()), in_state(GameState::Menu));
+//!
+//! app.update().unwrap(); // enters Menu, `start_game` queues Playing
+//! app.update().unwrap(); // Menu -> Playing
+//! let state = app.world.resource::<State<GameState>>().unwrap();
+//! assert_eq!(state.get(), &GameState::Playing);
+//! ```
+//!
+//! Transitions of [`GameState`] also publish a `GameStateChanged` global event.
+
+use std::any::Any;
+use std::fmt::Debug;
+use std::hash::Hash;
+
+use rustc_hash::FxHashMap;
+
+use crate::condition::Condition;
+use crate::error::Result;
+use crate::event_types::{GameState, GameStateChanged};
+use crate::executor::Executor;
+use crate::schedule::Schedule;
+use crate::system::BoxedSystem;
+use crate::world::World;
+
+/// Types usable as app states
+pub trait States: Clone + Eq + Hash + Debug + Send + Sync + 'static {}
+
+impl<T: Clone + Eq + Hash + Debug + Send + Sync + 'static> States for T {}
+
+/// Current value of state `S`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct State<S: States>(S);
+
+impl<S: States> State<S> {
+    pub fn new(state: S) -> Self {
+        Self(state)
+    }
+
+    /// Get the current state
+    pub fn get(&self) -> &S {
+        &self.0
+    }
+}
+
+/// Pending change of state `S`, applied by the next `App::update`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NextState<S: States>(Option<S>);
+
+impl<S: States> Default for NextState<S> {
+    fn default() -> Self {
+        Self(None)
+    }
+}
+
+impl<S: States> NextState<S> {
+    /// Queue a transition to `state`
+    pub fn set(&mut self, state: S) {
+        self.0 = Some(state);
+    }
+
+    /// Get the queued state, if any
+    pub fn pending(&self) -> Option<&S> {
+        self.0.as_ref()
+    }
+
+    /// Take the queued state
+    pub fn take(&mut self) -> Option<S> {
+        self.0.take()
+    }
+}
+
+/// Schedule run when entering a state
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnEnter<S: States>(pub S);
+
+/// Schedule run when leaving a state
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnExit<S: States>(pub S);
+
+/// Schedule run between `OnExit(from)` and `OnEnter(to)`
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OnTransition<S: States> {
+    pub from: S,
+    pub to: S,
+}
+
+/// Which transition schedule a label refers to
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum StateScheduleKey<S: States> {
+    Enter(S),
+    Exit(S),
+    Transition(S, S),
+}
+
+/// Label of a state transition schedule
+pub trait StateScheduleLabel {
+    type State: States;
+
+    fn key(self) -> StateScheduleKey<Self::State>;
+}
+
+impl<S: States> StateScheduleLabel for OnEnter<S> {
+    type State = S;
+
+    fn key(self) -> StateScheduleKey<S> {
+        StateScheduleKey::Enter(self.0)
+    }
+}
+
+impl<S: States> StateScheduleLabel for OnExit<S> {
+    type State = S;
+
+    fn key(self) -> StateScheduleKey<S> {
+        StateScheduleKey::Exit(self.0)
+    }
+}
+
+impl<S: States> StateScheduleLabel for OnTransition<S> {
+    type State = S;
+
+    fn key(self) -> StateScheduleKey<S> {
+        StateScheduleKey::Transition(self.from, self.to)
+    }
+}
+
+/// Run while state `S` equals `state`
+pub fn in_state<S: States>(state: S) -> impl Condition {
+    move |world: &World| {
+        world
+            .resource::<State<S>>()
+            .is_some_and(|current| current.0 == state)
+    }
+}
+
+/// Type-erased transition schedules of one state type
+pub(crate) trait StateTransitions {
+    /// Apply a pending `NextState`, running the matching schedules
+    fn apply(&mut self, world: &mut World) -> Result<()>;
+
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+/// Transition schedules of state `S`
+pub(crate) struct StateSchedules<S: States> {
+    schedules: FxHashMap<StateScheduleKey<S>, Schedule>,
+    /// Whether the initial state's `OnEnter` schedule has run
+    entered: bool,
+}
+
+impl<S: States> Default for StateSchedules<S> {
+    fn default() -> Self {
+        Self {
+            schedules: FxHashMap::default(),
+            entered: false,
+        }
+    }
+}
+
+impl<S: States> StateSchedules<S> {
+    pub(crate) fn add_system(&mut self, key: StateScheduleKey<S>, system: BoxedSystem) {
+        self.schedules.entry(key).or_default().add_system(system);
+    }
+
+    fn run(&mut self, key: StateScheduleKey<S>, world: &mut World) -> Result<()> {
+        if let Some(schedule) = self.schedules.get_mut(&key) {
+            Executor::new(schedule).execute_frame(world)?;
+        }
+        Ok(())
+    }
+}
+
+impl<S: States> StateTransitions for StateSchedules<S> {
+    fn apply(&mut self, world: &mut World) -> Result<()> {
+        let Some(current) = world.resource::<State<S>>().map(|s| s.0.clone()) else {
+            return Ok(());
+        };
+        if !self.entered {
+            self.entered = true;
+            self.run(StateScheduleKey::Enter(current.clone()), world)?;
+        }
+
+        let Some(next) = world
+            .resource_mut::<NextState<S>>()
+            .and_then(NextState::take)
+        else {
+            return Ok(());
+        };
+        if next == current {
+            return Ok(());
+        }
+
+        self.run(StateScheduleKey::Exit(current.clone()), world)?;
+        self.run(
+            StateScheduleKey::Transition(current.clone(), next.clone()),
+            world,
+        )?;
+        world.insert_resource(State(next.clone()));
+        publish_game_state_changed(world, &current, &next)?;
+        self.run(StateScheduleKey::Enter(next), world)
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// Publish `GameStateChanged` when `S` is the built-in `GameState`
+fn publish_game_state_changed<S: States>(world: &mut World, old: &S, new: &S) -> Result<()> {
+    let old: &dyn Any = old;
+    let new: &dyn Any = new;
+    if let (Some(old), Some(new)) = (
+        old.downcast_ref::<GameState>(),
+        new.downcast_ref::<GameState>(),
+    ) {
+        world.publish_global_event(GameStateChanged {
+            old_state: old.clone(),
+            new_state: new.clone(),
+        })?;
+    }
+    Ok(())
+}
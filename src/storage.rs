@@ -0,0 +1,582 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Sparse-set component storage
+//!
+//! Components default to table storage in archetype columns. Types registered
+//! with [`StorageType::SparseSet`] live in a [`SparseSet`] keyed by entity
+//! instead, so adding and removing them never moves the entity between
+//! archetypes. This suits frequently toggled markers such as `Stunned`.
+//!
+//! Sparse-set components can be spawned in bundles or inserted with
+//! `World::add_component`. They work with `&T`, `&mut T`, `Option<&T>`,
+//! `Option<&mut T>`, `With<T>`, `Without<T>`, `Changed<T>` and `Added<T>`
+//! query terms. Those terms scan every archetype; see
+//! [`StorageType::SparseSet`] for the iteration cost.
+
+use std::alloc::Layout;
+use std::any::{Any, TypeId};
+use std::cell::UnsafeCell;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+use smallvec::SmallVec;
+
+use ahash::AHashMap;
+use slotmap::SecondaryMap;
+
+use crate::archetype::ArchetypeSignature;
+use crate::component::{Component, MAX_BUNDLE_COMPONENTS};
+use crate::entity::EntityId;
+use crate::world::rebase_tick;
+
+/// How a component type is stored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum StorageType {
+    /// Archetype columns (fast iteration, slow add/remove)
+    #[default]
+    Table,
+    /// Sparse set keyed by entity (fast add/remove, no archetype moves)
+    ///
+    /// Sparse terms match every archetype, so a query iterates all entities
+    /// and looks each one up: `Query<&Stunned>` costs O(entities in the
+    /// world), not O(stunned entities). Pair sparse terms with a table term
+    /// that narrows the archetypes, or walk [`SparseSet::iter`] directly.
+    SparseSet,
+}
+
+/// Densely packed components of one type, indexed by entity
+///
+/// Values and change ticks sit in cells, so parallel queries can hand out
+/// `&mut T` to different entities through a shared `&SparseSet<T>`; no
+/// `&mut SparseSet<T>` is ever created while a query runs.
+pub struct SparseSet<T> {
+    dense: Vec<UnsafeCell<T>>,
+    entities: Vec<EntityId>,
+    added_ticks: Vec<u32>,
+    changed_ticks: Vec<AtomicU32>,
+    sparse: SecondaryMap<EntityId, usize>,
+}
+
+// SAFETY: Shared access only reaches a value through `get` or
+// `get_unchecked_mut`, whose callers guarantee no conflicting borrow of the
+// same entity's value; ticks are atomics
+unsafe impl<T: Sync> Sync for SparseSet<T> {}
+
+impl<T: Component> SparseSet<T> {
+    pub fn new() -> Self {
+        Self {
+            dense: Vec::new(),
+            entities: Vec::new(),
+            added_ticks: Vec::new(),
+            changed_ticks: Vec::new(),
+            sparse: SecondaryMap::new(),
+        }
+    }
+
+    /// Insert or replace the component for `entity`
+    pub fn insert(&mut self, entity: EntityId, value: T, tick: u32) {
+        if let Some(&index) = self.sparse.get(entity) {
+            *self.dense[index].get_mut() = value;
+            *self.changed_ticks[index].get_mut() = tick;
+            return;
+        }
+
+        self.sparse.insert(entity, self.dense.len());
+        self.dense.push(UnsafeCell::new(value));
+        self.entities.push(entity);
+        self.added_ticks.push(tick);
+        self.changed_ticks.push(AtomicU32::new(tick));
+    }
+
+    /// Remove and return the component for `entity`
+    pub fn remove(&mut self, entity: EntityId) -> Option<T> {
+        let index = self.sparse.remove(entity)?;
+        let last = self.dense.len() - 1;
+        if index != last {
+            let moved = self.entities[last];
+            self.sparse[moved] = index;
+        }
+        self.entities.swap_remove(index);
+        self.added_ticks.swap_remove(index);
+        self.changed_ticks.swap_remove(index);
+        Some(self.dense.swap_remove(index).into_inner())
+    }
+
+    pub fn get(&self, entity: EntityId) -> Option<&T> {
+        let index = *self.sparse.get(entity)?;
+        // SAFETY: Mutable borrows only exist through `get_mut`, which needs
+        // `&mut self`, or `get_unchecked_mut`, whose callers rule this out
+        Some(unsafe { &*self.dense[index].get() })
+    }
+
+    /// Get mutable access, marking the component changed at `tick`
+    pub fn get_mut(&mut self, entity: EntityId, tick: u32) -> Option<&mut T> {
+        let index = *self.sparse.get(entity)?;
+        *self.changed_ticks[index].get_mut() = tick;
+        Some(self.dense[index].get_mut())
+    }
+
+    /// Mutable access through a shared reference, marking the component
+    /// changed at `tick`
+    ///
+    /// # Safety
+    /// No other reference to `entity`'s component may be live while the
+    /// returned one is.
+    #[allow(clippy::mut_from_ref)]
+    pub(crate) unsafe fn get_unchecked_mut(&self, entity: EntityId, tick: u32) -> Option<&mut T> {
+        let index = *self.sparse.get(entity)?;
+        self.changed_ticks[index].store(tick, Ordering::Relaxed);
+        Some(&mut *self.dense[index].get())
+    }
+
+    pub fn contains(&self, entity: EntityId) -> bool {
+        self.sparse.contains_key(entity)
+    }
+
+    pub fn added_tick(&self, entity: EntityId) -> Option<u32> {
+        self.sparse
+            .get(entity)
+            .map(|&index| self.added_ticks[index])
+    }
+
+    pub fn changed_tick(&self, entity: EntityId) -> Option<u32> {
+        self.sparse
+            .get(entity)
+            .map(|&index| self.changed_ticks[index].load(Ordering::Relaxed))
+    }
+
+    /// Iterate `(entity, component)` pairs in dense order
+    pub fn iter(&self) -> impl Iterator<Item = (EntityId, &T)> + '_ {
+        // SAFETY: As in `get`
+        self.entities
+            .iter()
+            .copied()
+            .zip(self.dense.iter().map(|cell| unsafe { &*cell.get() }))
+    }
+
+    pub fn len(&self) -> usize {
+        self.dense.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.dense.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.dense.clear();
+        self.entities.clear();
+        self.added_ticks.clear();
+        self.changed_ticks.clear();
+        self.sparse.clear();
+    }
+}
+
+impl<T: Component> Default for SparseSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Type-erased sparse set operations needed by the world
+trait AnySparseSet: Send + Sync {
+    fn remove_entity(&mut self, entity: EntityId) -> bool;
+    fn contains(&self, entity: EntityId) -> bool;
+    fn clear(&mut self);
+    fn len(&self) -> usize;
+    fn rebase_ticks(&mut self);
+    fn item_layout(&self) -> Layout;
+    /// Move the `T` at `ptr` into the set
+    ///
+    /// # Safety
+    /// `ptr` must point to an initialized `T`, which the caller must not use
+    /// or drop afterwards.
+    unsafe fn insert_raw(&mut self, entity: EntityId, ptr: *mut u8, tick: u32);
+    fn get_raw(&self, entity: EntityId) -> Option<*const u8>;
+    fn get_raw_mut(&mut self, entity: EntityId, tick: u32) -> Option<*mut u8>;
+    fn as_any(&self) -> &dyn Any;
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+}
+
+impl<T: Component> AnySparseSet for SparseSet<T> {
+    fn remove_entity(&mut self, entity: EntityId) -> bool {
+        self.remove(entity).is_some()
+    }
+
+    fn contains(&self, entity: EntityId) -> bool {
+        SparseSet::contains(self, entity)
+    }
+
+    fn clear(&mut self) {
+        SparseSet::clear(self)
+    }
+
+    fn len(&self) -> usize {
+        SparseSet::len(self)
+    }
+
+    fn rebase_ticks(&mut self) {
+        let changed = self.changed_ticks.iter_mut().map(AtomicU32::get_mut);
+        for tick in self.added_ticks.iter_mut().chain(changed) {
+            *tick = rebase_tick(*tick);
+        }
+    }
+
+    fn item_layout(&self) -> Layout {
+        Layout::new::<T>()
+    }
+
+    unsafe fn insert_raw(&mut self, entity: EntityId, ptr: *mut u8, tick: u32) {
+        self.insert(entity, ptr.cast::<T>().read(), tick);
+    }
+
+    fn get_raw(&self, entity: EntityId) -> Option<*const u8> {
+        self.get(entity).map(|value| (value as *const T).cast())
+    }
+
+    fn get_raw_mut(&mut self, entity: EntityId, tick: u32) -> Option<*mut u8> {
+        self.get_mut(entity, tick)
+            .map(|value| (value as *mut T).cast())
+    }
+
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn Any {
+        self
+    }
+}
+
+/// All sparse sets of a world, keyed by component type
+#[derive(Default)]
+pub struct SparseStorage {
+    sets: AHashMap<TypeId, Box<dyn AnySparseSet>>,
+}
+
+impl SparseStorage {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Create the sparse set for `T` if it doesn't exist
+    pub fn register<T: Component>(&mut self) {
+        self.sets
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| Box::new(SparseSet::<T>::new()));
+    }
+
+    /// Drop the sparse set for `type_id`, returning whether it existed
+    pub fn unregister(&mut self, type_id: TypeId) -> bool {
+        self.sets.remove(&type_id).is_some()
+    }
+
+    /// Check if `type_id` uses sparse-set storage
+    pub fn is_sparse(&self, type_id: TypeId) -> bool {
+        self.sets.contains_key(&type_id)
+    }
+
+    /// Number of components stored for `type_id`
+    pub fn len_of(&self, type_id: TypeId) -> usize {
+        self.sets.get(&type_id).map_or(0, |set| set.len())
+    }
+
+    pub fn get<T: Component>(&self) -> Option<&SparseSet<T>> {
+        self.sets
+            .get(&TypeId::of::<T>())
+            .and_then(|set| set.as_any().downcast_ref())
+    }
+
+    pub fn get_mut<T: Component>(&mut self) -> Option<&mut SparseSet<T>> {
+        self.sets
+            .get_mut(&TypeId::of::<T>())
+            .and_then(|set| set.as_any_mut().downcast_mut())
+    }
+
+    /// Check if `entity` has a sparse component of type `type_id`
+    pub fn contains(&self, type_id: TypeId, entity: EntityId) -> bool {
+        self.sets
+            .get(&type_id)
+            .is_some_and(|set| set.contains(entity))
+    }
+
+    /// Remove a sparse component by type, returning whether it was present
+    pub fn remove_by_type_id(&mut self, type_id: TypeId, entity: EntityId) -> bool {
+        self.sets
+            .get_mut(&type_id)
+            .is_some_and(|set| set.remove_entity(entity))
+    }
+
+    /// Type-erased pointer to `entity`'s `type_id` component
+    pub(crate) fn get_raw(&self, type_id: TypeId, entity: EntityId) -> Option<*const u8> {
+        self.sets.get(&type_id)?.get_raw(entity)
+    }
+
+    /// Mutable counterpart of [`Self::get_raw`], marking the component changed
+    pub(crate) fn get_raw_mut(
+        &mut self,
+        type_id: TypeId,
+        entity: EntityId,
+        tick: u32,
+    ) -> Option<*mut u8> {
+        self.sets.get_mut(&type_id)?.get_raw_mut(entity, tick)
+    }
+
+    /// Insert a component written by `write` into the set of `type_id`
+    ///
+    /// `write` returns whether it initialized the value. Returns whether
+    /// `entity` didn't have the component yet, or `None` if nothing was
+    /// inserted.
+    ///
+    /// # Safety
+    /// When `write` returns true, it must have written a value of type
+    /// `type_id` to the pointer it was given.
+    pub(crate) unsafe fn insert_with(
+        &mut self,
+        type_id: TypeId,
+        entity: EntityId,
+        tick: u32,
+        write: impl FnOnce(*mut u8) -> bool,
+    ) -> Option<bool> {
+        let staging = SparseStaging::new(self, &[type_id]);
+        let buffer = staging.members.first()?.buffer;
+        if !write(buffer.as_ptr()) {
+            return None;
+        }
+        let added = !self.contains(type_id, entity);
+        staging.flush(self, entity, tick);
+        Some(added)
+    }
+
+    /// Move the value at `ptr` into the set of `type_id`
+    ///
+    /// Returns false, leaving the value in place, if `type_id` isn't sparse.
+    ///
+    /// # Safety
+    /// `ptr` must point to an initialized value of type `type_id`, which the
+    /// caller must not use or drop again when this returns true.
+    pub(crate) unsafe fn insert_raw(
+        &mut self,
+        type_id: TypeId,
+        entity: EntityId,
+        ptr: *mut u8,
+        tick: u32,
+    ) -> bool {
+        let Some(set) = self.sets.get_mut(&type_id) else {
+            return false;
+        };
+        set.insert_raw(entity, ptr, tick);
+        true
+    }
+
+    /// Types of the sparse components `entity` has
+    pub fn type_ids_of(&self, entity: EntityId) -> impl Iterator<Item = TypeId> + '_ {
+        self.sets
+            .iter()
+            .filter(move |(_, set)| set.contains(entity))
+            .map(|(&type_id, _)| type_id)
+    }
+
+    /// Remove every sparse component of `entity`
+    pub fn remove_entity(&mut self, entity: EntityId) {
+        for set in self.sets.values_mut() {
+            set.remove_entity(entity);
+        }
+    }
+
+    /// Shift every tick down for a world tick rebase
+    pub(crate) fn rebase_ticks(&mut self) {
+        for set in self.sets.values_mut() {
+            set.rebase_ticks();
+        }
+    }
+
+    /// Remove all components, keeping registrations
+    pub fn clear(&mut self) {
+        for set in self.sets.values_mut() {
+            set.clear();
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sets.is_empty()
+    }
+}
+
+type BundlePointers = SmallVec<[*mut u8; MAX_BUNDLE_COMPONENTS]>;
+
+/// Staging buffers for the sparse-set members of a bundle
+///
+/// Bundles write every member through a pointer. Sparse members are written
+/// to a buffer here, then moved into their sets with [`Self::flush`].
+pub(crate) struct SparseStaging {
+    members: SmallVec<[StagedMember; 2]>,
+}
+
+/// One sparse member of a bundle and its staging buffer
+struct StagedMember {
+    /// Position in the bundle
+    index: usize,
+    type_id: TypeId,
+    buffer: NonNull<u8>,
+    layout: Layout,
+}
+
+impl SparseStaging {
+    /// Stage the members of `type_ids` that use sparse-set storage
+    pub(crate) fn new(storage: &SparseStorage, type_ids: &[TypeId]) -> Self {
+        let mut members = SmallVec::new();
+        if !storage.is_empty() {
+            for (index, type_id) in type_ids.iter().enumerate() {
+                if let Some(set) = storage.sets.get(type_id) {
+                    let layout = set.item_layout();
+                    let buffer = if layout.size() == 0 {
+                        // Dangling but aligned, as for any zero-sized value
+                        NonNull::new(layout.align() as *mut u8).unwrap()
+                    } else {
+                        // SAFETY: Layout has a non-zero size
+                        let ptr = unsafe { std::alloc::alloc(layout) };
+                        NonNull::new(ptr).unwrap_or_else(|| std::alloc::handle_alloc_error(layout))
+                    };
+                    members.push(StagedMember {
+                        index,
+                        type_id: *type_id,
+                        buffer,
+                        layout,
+                    });
+                }
+            }
+        }
+        Self { members }
+    }
+
+    /// The members of `type_ids` stored in archetype columns
+    pub(crate) fn table_ids(&self, type_ids: &[TypeId]) -> ArchetypeSignature {
+        let mut members = self.members.iter().peekable();
+        type_ids
+            .iter()
+            .enumerate()
+            .filter(|&(index, _)| members.next_if(|member| member.index == index).is_none())
+            .map(|(_, &type_id)| type_id)
+            .collect()
+    }
+
+    /// Bundle write pointers: `columns` for table members, in order, with
+    /// the staging buffers spliced in
+    pub(crate) fn pointers(&self, columns: BundlePointers) -> BundlePointers {
+        if self.members.is_empty() {
+            return columns;
+        }
+        let len = columns.len() + self.members.len();
+        let mut columns = columns.into_iter();
+        let mut members = self.members.iter().peekable();
+        (0..len)
+            .filter_map(
+                |index| match members.next_if(|member| member.index == index) {
+                    Some(member) => Some(member.buffer.as_ptr()),
+                    None => columns.next(),
+                },
+            )
+            .collect()
+    }
+
+    /// Move the staged members into their sparse sets
+    ///
+    /// # Safety
+    /// Every member must have been written since the last flush.
+    pub(crate) unsafe fn flush(&self, storage: &mut SparseStorage, entity: EntityId, tick: u32) {
+        for member in &self.members {
+            if let Some(set) = storage.sets.get_mut(&member.type_id) {
+                set.insert_raw(entity, member.buffer.as_ptr(), tick);
+            }
+        }
+    }
+}
+
+impl Drop for SparseStaging {
+    fn drop(&mut self) {
+        for member in &self.members {
+            if member.layout.size() != 0 {
+                // SAFETY: Allocated in `new` with this layout
+                unsafe { std::alloc::dealloc(member.buffer.as_ptr(), member.layout) };
+            }
+        }
+    }
+}
+
+/// Heap-pinned `SparseStorage` owned by a `World`
+///
+/// Archetypes keep a raw pointer to the storage so query fetches, which only
+/// see an `Archetype`, can reach sparse components. The allocation never moves
+/// while the world is alive.
+pub(crate) struct SparseStorageBox(NonNull<SparseStorage>);
+
+// SAFETY: SparseStorage is Send + Sync; the box uniquely owns it.
+unsafe impl Send for SparseStorageBox {}
+unsafe impl Sync for SparseStorageBox {}
+
+impl SparseStorageBox {
+    pub(crate) fn new() -> Self {
+        Self(NonNull::from(Box::leak(Box::new(SparseStorage::new()))))
+    }
+
+    pub(crate) fn get(&self) -> &SparseStorage {
+        // SAFETY: Pointer comes from a live Box owned by self
+        unsafe { self.0.as_ref() }
+    }
+
+    pub(crate) fn get_mut(&mut self) -> &mut SparseStorage {
+        // SAFETY: Pointer comes from a live Box owned by self
+        unsafe { self.0.as_mut() }
+    }
+
+    pub(crate) fn as_ptr(&self) -> NonNull<SparseStorage> {
+        self.0
+    }
+}
+
+impl Drop for SparseStorageBox {
+    fn drop(&mut self) {
+        // SAFETY: Pointer was created by Box::leak in new()
+        unsafe { drop(Box::from_raw(self.0.as_ptr())) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use slotmap::SlotMap;
+
+    #[test]
+    fn test_sparse_set_swap_remove() {
+        let mut ids: SlotMap<EntityId, ()> = SlotMap::with_key();
+        let (a, b, c) = (ids.insert(()), ids.insert(()), ids.insert(()));
+
+        let mut set = SparseSet::<u32>::new();
+        set.insert(a, 1, 1);
+        set.insert(b, 2, 1);
+        set.insert(c, 3, 1);
+
+        assert_eq!(set.remove(a), Some(1));
+        assert_eq!(set.get(c), Some(&3));
+        assert_eq!(set.get(b), Some(&2));
+        assert!(!set.contains(a));
+        assert_eq!(set.len(), 2);
+
+        set.insert(b, 20, 5);
+        assert_eq!(set.get(b), Some(&20));
+        assert_eq!(set.added_tick(b), Some(1));
+        assert_eq!(set.changed_tick(b), Some(5));
+    }
+}
@@ -1,6 +1,7 @@
 //! System trait and access metadata
 
 use crate::error::Result;
+use crate::event_channel::Events;
 use crate::world::{UnsafeWorldCell, World};
 use std::any::TypeId;
 
@@ -29,6 +30,10 @@ impl From<TypeId> for ComponentId {
 pub struct SystemAccess {
     pub reads: Vec<ComponentId>,
     pub writes: Vec<ComponentId>,
+    /// `Events<T>` channels the system sends to, also listed in `writes`
+    ///
+    /// The schedule runs these systems before the channel's readers.
+    pub event_writes: Vec<ComponentId>,
 }
 
 impl Default for SystemAccess {
@@ -43,6 +48,7 @@ impl SystemAccess {
         Self {
             reads: Vec::new(),
             writes: Vec::new(),
+            event_writes: Vec::new(),
         }
     }
 
@@ -87,7 +93,18 @@ impl SystemAccess {
             }
         }
 
-        SystemAccess { reads, writes }
+        let mut event_writes = self.event_writes.clone();
+        for write in &other.event_writes {
+            if !event_writes.contains(write) {
+                event_writes.push(*write);
+            }
+        }
+
+        SystemAccess {
+            reads,
+            writes,
+            event_writes,
+        }
     }
 
     /// Check if this access conflicts with another
@@ -128,6 +145,17 @@ impl SystemAccess {
         self.writes.push(ComponentId::of::<R>());
         self
     }
+
+    /// Declare reading the `Events<T>` channel
+    pub fn event_reader<T: 'static>(self) -> Self {
+        self.resource::<Events<T>>()
+    }
+
+    /// Declare sending to the `Events<T>` channel
+    pub fn event_writer<T: 'static>(mut self) -> Self {
+        self.event_writes.push(ComponentId::of::<Events<T>>());
+        self.resource_mut::<Events<T>>()
+    }
 }
 
 /// System trait
@@ -145,6 +173,15 @@ pub trait System: Send + Sync {
         commands: &mut crate::command::CommandBuffer,
     ) -> Result<()>;
 
+    /// Whether `run` may queue commands
+    ///
+    /// The schedule applies commands between a system returning `true` and
+    /// the systems ordered after it. Every system gets a `CommandBuffer`, so
+    /// this defaults to `true`.
+    fn has_deferred(&self) -> bool {
+        true
+    }
+
     /// Run system in parallel using UnsafeWorldCell
     ///
     /// # Safety
@@ -0,0 +1,431 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Function system parameters
+//!
+//! Each parameter type knows which components and resources it touches, so
+//! a function system's `SystemAccess` is derived from its signature.
+
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+
+use crate::command::CommandBuffer;
+use crate::component::{Bundle, Component};
+use crate::entity::EntityId;
+use crate::error::Result;
+use crate::event_channel::{EventCursor, EventReader, EventWriter, Events};
+use crate::hierarchy_system::HierarchyBuilder;
+use crate::query::{Query, QueryData};
+use crate::removed_components::{RemovedComponents, RemovedCursor};
+use crate::resource::{Res, ResMut};
+use crate::system::SystemAccess;
+use crate::world::{UnsafeWorldCell, World};
+
+/// A value a function system can take as a parameter
+pub trait SystemParam {
+    /// The parameter borrowing from the world for `'w`
+    type Item<'w>;
+
+    /// Data kept by the system between runs, such as a read cursor
+    type State: Default + Send + Sync + 'static;
+
+    /// Add the components and resources this parameter touches to `access`
+    fn access(access: SystemAccess) -> SystemAccess;
+
+    /// Whether this parameter can queue commands
+    fn has_deferred() -> bool {
+        false
+    }
+
+    /// Fetch the parameter for one system run
+    ///
+    /// `last_run_tick` is the world tick the system last ran at, 0 on its
+    /// first run. The executor advances the tick after every system group,
+    /// so later changes in the same frame are newer.
+    ///
+    /// # Safety
+    /// The caller must hold the access declared by `access` for `'w`, and
+    /// `commands` must stay valid for `'w`.
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        commands: NonNull<CommandBuffer>,
+        last_run_tick: u32,
+        state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>>;
+}
+
+/// The item type of a system parameter for `'w`
+pub type SystemParamItem<'w, P> = <P as SystemParam>::Item<'w>;
+
+impl<Q: QueryData> SystemParam for Query<'_, Q> {
+    type Item<'w> = Query<'w, Q::Fetch<'w>>;
+    type State = ();
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        Q::access(access)
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        _commands: NonNull<CommandBuffer>,
+        last_run_tick: u32,
+        _state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        Ok(Query::from_world_cell(world, last_run_tick))
+    }
+}
+
+impl<R: Send + Sync + 'static> SystemParam for Res<'_, R> {
+    type Item<'w> = Res<'w, R>;
+    type State = ();
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.resource::<R>()
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        _commands: NonNull<CommandBuffer>,
+        _last_run_tick: u32,
+        _state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        world.get_resource::<R>()
+    }
+}
+
+impl<R: Send + Sync + 'static> SystemParam for ResMut<'_, R> {
+    type Item<'w> = ResMut<'w, R>;
+    type State = ();
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.resource_mut::<R>()
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        _commands: NonNull<CommandBuffer>,
+        _last_run_tick: u32,
+        _state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        world.get_resource_mut::<R>()
+    }
+}
+
+impl<T: Send + Sync + 'static> SystemParam for EventReader<'_, T> {
+    type Item<'w> = EventReader<'w, T>;
+    type State = EventCursor<T>;
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.event_reader::<T>()
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        _commands: NonNull<CommandBuffer>,
+        _last_run_tick: u32,
+        state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        Ok(EventReader {
+            events: world.get_resource::<Events<T>>()?,
+            cursor: state,
+        })
+    }
+}
+
+impl<T: Send + Sync + 'static> SystemParam for EventWriter<'_, T> {
+    type Item<'w> = EventWriter<'w, T>;
+    type State = ();
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access.event_writer::<T>()
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        _commands: NonNull<CommandBuffer>,
+        _last_run_tick: u32,
+        _state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        Ok(EventWriter {
+            events: world.get_resource_mut::<Events<T>>()?,
+        })
+    }
+}
+
+impl<T: Component> SystemParam for RemovedComponents<T> {
+    type Item<'w> = RemovedComponents<T>;
+    type State = RemovedCursor<T>;
+
+    /// Removal logs are synchronized internally, so this adds no access
+    fn access(access: SystemAccess) -> SystemAccess {
+        access
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        _commands: NonNull<CommandBuffer>,
+        _last_run_tick: u32,
+        state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        Ok(RemovedComponents {
+            entities: world.read_removed(state),
+            _marker: PhantomData,
+        })
+    }
+}
+
+/// Deferred world mutations, applied after the system's stage
+///
+/// Writes to the system's `CommandBuffer`. Commands touch no components
+/// while the system runs, so they add nothing to its access.
+///
+/// Spawned entities get their ID immediately, reserved through
+/// [`World::reserve_entity`], so later commands can refer to them:
+///
+/// ```
+/// use archetype_ecs::prelude::*;
+///
+/// struct Name(&'static str);
+///
+/// fn spawn_ship(mut commands: Commands) {
+///     commands
+///         .spawn((Name("ship"),))
+///         .with_children(|ship| {
+///             ship.spawn((Name("turret"),));
+///         });
+/// }
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::new();
+/// schedule.add_system(Box::new(spawn_ship.into_system()));
+/// Executor::new(&mut schedule).execute_frame(&mut world).unwrap();
+/// assert_eq!(world.query::<&Name>().iter().count(), 2);
+/// ```
+pub struct Commands<'w> {
+    buffer: NonNull<CommandBuffer>,
+    world: UnsafeWorldCell<'w>,
+    _marker: PhantomData<&'w mut CommandBuffer>,
+}
+
+impl<'w> Commands<'w> {
+    fn buffer(&mut self) -> &mut CommandBuffer {
+        // SAFETY: Buffer outlives 'w; borrows never escape a single call
+        unsafe { self.buffer.as_mut() }
+    }
+
+    /// Queue spawning an entity with `bundle`
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) -> EntityCommands<'_, 'w> {
+        let entity = self.world.reserve_entity();
+        self.buffer()
+            .spawn(move |world| world.spawn_reserved(entity, bundle));
+        self.entity(entity)
+    }
+
+    /// Queue spawning an entity with no components
+    pub fn spawn_empty(&mut self) -> EntityCommands<'_, 'w> {
+        let entity = self.world.reserve_entity();
+        self.entity(entity)
+    }
+
+    /// Queue commands for an existing entity
+    pub fn entity(&mut self, entity: EntityId) -> EntityCommands<'_, 'w> {
+        EntityCommands {
+            entity,
+            commands: self,
+        }
+    }
+
+    /// Queue despawn command
+    pub fn despawn(&mut self, entity: EntityId) {
+        self.buffer().despawn(entity);
+    }
+
+    /// Queue a custom world mutation
+    pub fn add<F>(&mut self, f: F)
+    where
+        F: FnOnce(&mut World) -> Result<()> + Send + 'static,
+    {
+        self.buffer().add(f);
+    }
+
+    /// Queue add component command
+    pub fn add_component<T: Component>(&mut self, entity: EntityId, component: T) {
+        self.buffer().add_component(entity, component);
+    }
+
+    /// Queue remove component command
+    pub fn remove_component<T: Component>(&mut self, entity: EntityId) {
+        self.buffer().remove_component::<T>(entity);
+    }
+
+    /// Queue triggering `event` for global observers
+    pub fn trigger<E: Send + Sync + 'static>(&mut self, event: E) {
+        self.add(move |world| {
+            world.trigger(event);
+            Ok(())
+        });
+    }
+
+    /// Queue triggering `event` on `entity`, see [`World::trigger_entity`]
+    pub fn trigger_entity<E: Send + Sync + 'static>(&mut self, event: E, entity: EntityId) {
+        self.add(move |world| {
+            world.trigger_entity(event, entity);
+            Ok(())
+        });
+    }
+}
+
+/// Commands for one entity, from [`Commands::spawn`] or [`Commands::entity`]
+pub struct EntityCommands<'a, 'w> {
+    entity: EntityId,
+    commands: &'a mut Commands<'w>,
+}
+
+impl<'w> EntityCommands<'_, 'w> {
+    /// The entity's ID, valid once the spawn is applied
+    pub fn id(&self) -> EntityId {
+        self.entity
+    }
+
+    /// Queue adding `component`
+    pub fn insert<T: Component>(&mut self, component: T) -> &mut Self {
+        self.commands.add_component(self.entity, component);
+        self
+    }
+
+    /// Queue removing component `T`
+    pub fn remove<T: Component>(&mut self) -> &mut Self {
+        self.commands.remove_component::<T>(self.entity);
+        self
+    }
+
+    /// Queue triggering `event` on the entity
+    pub fn trigger<E: Send + Sync + 'static>(&mut self, event: E) -> &mut Self {
+        self.commands.trigger_entity(event, self.entity);
+        self
+    }
+
+    /// Queue despawning the entity
+    pub fn despawn(&mut self) {
+        self.commands.despawn(self.entity);
+    }
+
+    /// Spawn children of this entity
+    pub fn with_children(&mut self, build: impl FnOnce(&mut ChildBuilder<'_, 'w>)) -> &mut Self {
+        build(&mut ChildBuilder {
+            parent: self.entity,
+            commands: self.commands,
+        });
+        self
+    }
+}
+
+/// Spawns children of an entity, see [`EntityCommands::with_children`]
+pub struct ChildBuilder<'a, 'w> {
+    parent: EntityId,
+    commands: &'a mut Commands<'w>,
+}
+
+impl<'w> ChildBuilder<'_, 'w> {
+    /// The parent's ID
+    pub fn parent_entity(&self) -> EntityId {
+        self.parent
+    }
+
+    /// Queue spawning a child with `bundle`
+    pub fn spawn<B: Bundle>(&mut self, bundle: B) -> EntityCommands<'_, 'w> {
+        let parent = self.parent;
+        let child = self.commands.spawn(bundle).id();
+        self.commands
+            .add(move |world| HierarchyBuilder::attach(world, parent, child));
+        self.commands.entity(child)
+    }
+}
+
+impl SystemParam for Commands<'_> {
+    type Item<'w> = Commands<'w>;
+    type State = ();
+
+    fn access(access: SystemAccess) -> SystemAccess {
+        access
+    }
+
+    fn has_deferred() -> bool {
+        true
+    }
+
+    unsafe fn fetch<'w>(
+        world: UnsafeWorldCell<'w>,
+        commands: NonNull<CommandBuffer>,
+        _last_run_tick: u32,
+        _state: &'w mut Self::State,
+    ) -> Result<Self::Item<'w>> {
+        Ok(Commands {
+            buffer: commands,
+            world,
+            _marker: PhantomData,
+        })
+    }
+}
+
+macro_rules! impl_system_param_tuple {
+    ($($P:ident),*) => {
+        #[allow(non_snake_case, unused_variables)]
+        impl<$($P: SystemParam),*> SystemParam for ($($P,)*) {
+            type Item<'w> = ($($P::Item<'w>,)*);
+            type State = ($($P::State,)*);
+
+            /// # Panics
+            /// Panics if two parameters conflict, e.g. `Query<&mut A>` and
+            /// `Query<&A>`, since the system would alias its own borrows.
+            fn access(access: SystemAccess) -> SystemAccess {
+                $(
+                    let param = $P::access(SystemAccess::new());
+                    assert!(
+                        !param.conflicts_with(&access),
+                        "system parameter `{}` conflicts with an earlier parameter",
+                        std::any::type_name::<$P>()
+                    );
+                    let access = access.merge(&param);
+                )*
+                access
+            }
+
+            fn has_deferred() -> bool {
+                false $(|| $P::has_deferred())*
+            }
+
+            unsafe fn fetch<'w>(
+                world: UnsafeWorldCell<'w>,
+                commands: NonNull<CommandBuffer>,
+                last_run_tick: u32,
+                state: &'w mut Self::State,
+            ) -> Result<Self::Item<'w>> {
+                let ($($P,)*) = state;
+                $(let $P = $P::fetch(world, commands, last_run_tick, $P)?;)*
+                Ok(($($P,)*))
+            }
+        }
+    };
+}
+
+impl_system_param_tuple!();
+impl_system_param_tuple!(A);
+impl_system_param_tuple!(A, B);
+impl_system_param_tuple!(A, B, C);
+impl_system_param_tuple!(A, B, C, D);
+impl_system_param_tuple!(A, B, C, D, E);
+impl_system_param_tuple!(A, B, C, D, E, F);
+impl_system_param_tuple!(A, B, C, D, E, F, G);
+impl_system_param_tuple!(A, B, C, D, E, F, G, H);
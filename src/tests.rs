@@ -21,7 +21,7 @@ mod tests {
     use crate::{
         CommandBuffer, Executor, Query, QueryState, Schedule, System, SystemAccess, World,
     };
-    use crate::{EcsError, Event, Result};
+    use crate::{EcsError, Event, Result, SpawnError};
     use std::any::{Any, TypeId};
 
     #[test]
@@ -586,11 +586,23 @@ mod tests {
         let mut world = World::new();
 
         // Use simple tuple components directly
+        // Both tuple types are the same, so the bundle repeats a component type
+        let result = world.spawn_batch(vec![
+            ((0.0f32, 0.0f32, 0.0f32), (1.0f32, 0.0f32, 0.0f32)),
+            ((1.0f32, 0.0f32, 0.0f32), (0.0f32, 1.0f32, 0.0f32)),
+        ]);
+        assert!(matches!(
+            result,
+            Err(EcsError::SpawnError(SpawnError::DuplicateComponent { .. }))
+        ));
+        assert_eq!(world.entity_count(), 0);
+        assert!(world.component_tracker.is_empty());
+
         // Batch spawn entities
         let entities = world
             .spawn_batch(vec![
-                ((0.0f32, 0.0f32, 0.0f32), (1.0f32, 0.0f32, 0.0f32)),
-                ((1.0f32, 0.0f32, 0.0f32), (0.0f32, 1.0f32, 0.0f32)),
+                ((0.0f32, 0.0f32, 0.0f32), [1.0f32, 0.0f32, 0.0f32]),
+                ((1.0f32, 0.0f32, 0.0f32), [0.0f32, 1.0f32, 0.0f32]),
             ])
             .unwrap();
 
@@ -598,22 +610,7 @@ mod tests {
         for entity in entities {
             let tracked = world.component_tracker.get(&entity);
             assert!(tracked.is_some(), "Entity should be in component tracker");
-
-            // Debug: print what we actually track
-            let tracked_set = tracked.unwrap();
-            println!(
-                "Entity {:?} tracks {} components: {:?}",
-                entity,
-                tracked_set.len(),
-                tracked_set
-            );
-
-            // Both tuple types are the same, so we only track 1 component type
-            assert_eq!(
-                tracked_set.len(),
-                1,
-                "Should track 1 component type (both tuples are same type)"
-            );
+            assert_eq!(tracked.unwrap().len(), 2, "Should track 2 component types");
         }
     }
 
@@ -61,6 +61,16 @@ impl Time {
         self.frame_count += 1;
     }
 
+    /// Advance by an explicit delta instead of measuring the wall clock
+    ///
+    /// Use in place of [`Time::update`] for deterministic stepping.
+    pub fn advance_by(&mut self, delta: Duration) {
+        self.delta = delta;
+        self.elapsed += delta;
+        self.last_update = std::time::Instant::now();
+        self.frame_count += 1;
+    }
+
     /// Get delta time (time since last frame)
     pub fn delta(&self) -> Duration {
         self.delta
@@ -110,6 +120,22 @@ impl Time {
     pub fn is_paused(&self) -> bool {
         self.time_scale == 0.0
     }
+
+    /// Report `timestep` as an unscaled delta while fixed systems run
+    ///
+    /// Returns the frame's delta and scale for [`Time::end_fixed_steps`].
+    pub(crate) fn begin_fixed_steps(&mut self, timestep: Duration) -> (Duration, f32) {
+        let frame = (self.delta, self.time_scale);
+        self.delta = timestep;
+        self.time_scale = 1.0;
+        frame
+    }
+
+    /// Restore the frame's delta and scale after the fixed steps
+    pub(crate) fn end_fixed_steps(&mut self, (delta, time_scale): (Duration, f32)) {
+        self.delta = delta;
+        self.time_scale = time_scale;
+    }
 }
 
 impl Default for Time {
@@ -127,40 +153,68 @@ pub struct FixedTime {
     accumulator: Duration,
     /// Overstep from last frame (for interpolation)
     overstep: Duration,
+    /// Most steps run by one [`FixedTime::tick`]
+    max_steps: usize,
 }
 
+/// Default cap on fixed steps per frame, see [`FixedTime::set_max_steps`]
+pub const DEFAULT_MAX_FIXED_STEPS: usize = 8;
+
 impl FixedTime {
     /// Create new FixedTime with given frequency (Hz)
+    ///
+    /// # Panics
+    ///
+    /// Panics if `hz` is zero.
     pub fn new(hz: u32) -> Self {
-        let timestep = Duration::from_secs_f32(1.0 / hz as f32);
-        Self {
-            timestep,
-            accumulator: Duration::ZERO,
-            overstep: Duration::ZERO,
-        }
+        assert!(hz > 0, "fixed timestep frequency must be non-zero");
+        Self::from_duration(Duration::from_secs_f32(1.0 / hz as f32))
     }
 
     /// Create with explicit timestep duration
+    ///
+    /// # Panics
+    ///
+    /// Panics if `timestep` is zero.
     pub fn from_duration(timestep: Duration) -> Self {
+        assert!(!timestep.is_zero(), "fixed timestep must be non-zero");
         Self {
             timestep,
             accumulator: Duration::ZERO,
             overstep: Duration::ZERO,
+            max_steps: DEFAULT_MAX_FIXED_STEPS,
         }
     }
 
+    /// Cap the steps one [`FixedTime::tick`] returns
+    ///
+    /// Time beyond the cap is dropped, so a long stall slows the simulation
+    /// down instead of stalling the next frame too.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_steps` is zero, which would stop fixed updates.
+    pub fn set_max_steps(&mut self, max_steps: usize) {
+        assert!(max_steps > 0, "max fixed steps must be non-zero");
+        self.max_steps = max_steps;
+    }
+
+    /// Get the cap on steps per tick
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
     /// Update accumulator and return number of fixed steps to run
     pub fn tick(&mut self, delta: Duration) -> usize {
         self.accumulator += delta;
 
-        let mut steps = 0;
-        while self.accumulator >= self.timestep {
-            self.accumulator -= self.timestep;
-            steps += 1;
-        }
+        let timestep = self.timestep.as_nanos();
+        let accumulated = self.accumulator.as_nanos();
+        let steps = accumulated / timestep;
+        self.accumulator = Duration::from_nanos((accumulated % timestep) as u64);
 
         self.overstep = self.accumulator;
-        steps
+        steps.min(self.max_steps as u128) as usize
     }
 
     /// Get fixed timestep duration
@@ -214,6 +268,16 @@ mod tests {
         assert!(!time.is_paused());
     }
 
+    #[test]
+    fn test_time_advance_by() {
+        let mut time = Time::new();
+        time.advance_by(Duration::from_millis(10));
+        time.advance_by(Duration::from_millis(15));
+        assert_eq!(time.delta(), Duration::from_millis(15));
+        assert_eq!(time.elapsed(), Duration::from_millis(25));
+        assert_eq!(time.frame_count(), 2);
+    }
+
     #[test]
     fn test_fixed_time_60hz() {
         let mut fixed = FixedTime::new(60);
@@ -236,6 +300,28 @@ mod tests {
         assert_eq!(steps, 1); // First step
     }
 
+    #[test]
+    fn test_fixed_time_caps_steps() {
+        let mut fixed = FixedTime::from_duration(Duration::from_millis(10));
+        fixed.set_max_steps(4);
+
+        assert_eq!(fixed.tick(Duration::from_millis(5005)), 4);
+        assert_eq!(fixed.overstep(), Duration::from_millis(5));
+        assert_eq!(fixed.tick(Duration::from_millis(5)), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "max fixed steps must be non-zero")]
+    fn test_fixed_time_rejects_zero_max_steps() {
+        FixedTime::default().set_max_steps(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "fixed timestep must be non-zero")]
+    fn test_fixed_time_rejects_zero_timestep() {
+        FixedTime::from_duration(Duration::ZERO);
+    }
+
     #[test]
     fn test_overstep_fraction() {
         let mut fixed = FixedTime::new(60);
@@ -1,10 +1,11 @@
+use crate::reflection::Reflect;
 use serde::{Deserialize, Serialize};
 
 // Re-export glam types for standardization and ease of use
 pub use glam::{Mat4, Quat, Vec3};
 
 /// Local transform (relative to parent)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Reflect)]
 pub struct LocalTransform {
     pub position: Vec3,
     pub rotation: Quat,
@@ -57,7 +58,7 @@ impl Default for LocalTransform {
 }
 
 /// Global transform (world space)
-#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, Reflect)]
 pub struct GlobalTransform {
     pub position: Vec3,
     pub rotation: Quat,
@@ -0,0 +1,221 @@
+// Copyright 2024 Saptak Santra
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Typed triggers and the observers reacting to them
+//!
+//! Any `Send + Sync + 'static` type can be a trigger. Observers subscribe
+//! to one trigger type, either globally or scoped to one entity, and run
+//! immediately when it's triggered:
+//!
+//! ```
+//! use archetype_ecs::prelude::*;
+//!
+//! struct OnDamage {
+//!     amount: u32,
+//! }
+//! struct Health(u32);
+//!
+//! let mut world = World::new();
+//! let ship = world.spawn_entity((Health(10),));
+//! let turret = world.spawn_entity((Parent(ship),));
+//!
+//! world.observe_entity(ship, |trigger: &mut Trigger<OnDamage>, world| {
+//!     let ship = trigger.target().unwrap();
+//!     world.get_component_mut::<Health>(ship).unwrap().0 -= trigger.event().amount;
+//! });
+//!
+//! // Damage to the turret bubbles up to the ship
+//! world.trigger_entity(OnDamage { amount: 3 }, turret);
+//! assert_eq!(world.get_component::<Health>(ship).unwrap().0, 7);
+//! ```
+//!
+//! Observers are indexed by trigger type and target, so triggering only
+//! visits the observers that match. Entity triggers propagate up the
+//! `Parent` chain until an observer calls [`Trigger::stop_propagation`].
+
+use std::any::{Any, TypeId};
+use std::sync::Arc;
+
+use ahash::AHashMap;
+use smallvec::SmallVec;
+
+use crate::entity::EntityId;
+use crate::world::World;
+
+/// A triggered event, as seen by an observer
+pub struct Trigger<E> {
+    event: E,
+    target: Option<EntityId>,
+    original_target: Option<EntityId>,
+    propagate: bool,
+}
+
+impl<E> Trigger<E> {
+    pub(crate) fn new(event: E, target: Option<EntityId>) -> Self {
+        Self {
+            event,
+            target,
+            original_target: target,
+            propagate: true,
+        }
+    }
+
+    pub fn event(&self) -> &E {
+        &self.event
+    }
+
+    /// Mutable event data, seen by the observers that run next
+    pub fn event_mut(&mut self) -> &mut E {
+        &mut self.event
+    }
+
+    /// Entity whose observers are running, `None` for global triggers
+    pub fn target(&self) -> Option<EntityId> {
+        self.target
+    }
+
+    /// Entity the event was triggered on, before any propagation
+    pub fn original_target(&self) -> Option<EntityId> {
+        self.original_target
+    }
+
+    /// Don't propagate to the target's parent
+    ///
+    /// The remaining observers of the current target still run.
+    pub fn stop_propagation(&mut self) {
+        self.propagate = false;
+    }
+
+    pub(crate) fn retarget(&mut self, target: EntityId) {
+        self.target = Some(target);
+    }
+
+    pub(crate) fn is_propagating(&self) -> bool {
+        self.propagate
+    }
+}
+
+/// Handle of a registered observer, used to remove it
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ObserverId(u64);
+
+type ObserverFn<E> = Box<dyn Fn(&mut Trigger<E>, &mut World) + Send + Sync>;
+
+/// Type-erased `ObserverFn<E>`
+type ErasedObserver = Arc<dyn Any + Send + Sync>;
+
+/// Trigger type and target an observer listens to
+type ObserverKey = (TypeId, Option<EntityId>);
+
+/// Observers indexed by trigger type and target entity
+#[derive(Default)]
+pub(crate) struct TriggerObservers {
+    observers: AHashMap<ObserverKey, Vec<(ObserverId, ErasedObserver)>>,
+    keys: AHashMap<ObserverId, ObserverKey>,
+    /// Observers scoped to each entity, dropped when it's despawned
+    scoped: AHashMap<EntityId, SmallVec<[ObserverId; 2]>>,
+    next_id: u64,
+}
+
+impl TriggerObservers {
+    pub(crate) fn add<E, F>(&mut self, target: Option<EntityId>, observer: F) -> ObserverId
+    where
+        E: Send + Sync + 'static,
+        F: Fn(&mut Trigger<E>, &mut World) + Send + Sync + 'static,
+    {
+        let id = ObserverId(self.next_id);
+        self.next_id += 1;
+        let key = (TypeId::of::<E>(), target);
+        let observer: ObserverFn<E> = Box::new(observer);
+        self.observers
+            .entry(key)
+            .or_default()
+            .push((id, Arc::new(observer)));
+        self.keys.insert(id, key);
+        if let Some(entity) = target {
+            self.scoped.entry(entity).or_default().push(id);
+        }
+        id
+    }
+
+    pub(crate) fn remove(&mut self, id: ObserverId) -> bool {
+        let Some(key) = self.keys.remove(&id) else {
+            return false;
+        };
+        if let Some(observers) = self.observers.get_mut(&key) {
+            observers.retain(|(other, _)| *other != id);
+            if observers.is_empty() {
+                self.observers.remove(&key);
+            }
+        }
+        if let Some(entity) = key.1 {
+            if let Some(ids) = self.scoped.get_mut(&entity) {
+                ids.retain(|other| *other != id);
+                if ids.is_empty() {
+                    self.scoped.remove(&entity);
+                }
+            }
+        }
+        true
+    }
+
+    /// Drop every observer scoped to `entity`
+    pub(crate) fn remove_entity(&mut self, entity: EntityId) {
+        if let Some(ids) = self.scoped.remove(&entity) {
+            for id in ids {
+                self.remove(id);
+            }
+        }
+    }
+
+    /// Drop every entity-scoped observer, keeping global ones
+    pub(crate) fn clear_scoped(&mut self) {
+        let entities: Vec<EntityId> = self.scoped.keys().copied().collect();
+        for entity in entities {
+            self.remove_entity(entity);
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    /// Observers of `E` on `target`, cloned so they can run with `&mut World`
+    pub(crate) fn matching<E: 'static>(
+        &self,
+        target: Option<EntityId>,
+    ) -> SmallVec<[ErasedObserver; 4]> {
+        self.observers
+            .get(&(TypeId::of::<E>(), target))
+            .map(|observers| observers.iter().map(|(_, f)| f.clone()).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Run `observers`, which must all be observers of `E`
+pub(crate) fn run_observers<E: 'static>(
+    observers: &[ErasedObserver],
+    trigger: &mut Trigger<E>,
+    world: &mut World,
+) {
+    for observer in observers {
+        if let Some(observer) = observer.downcast_ref::<ObserverFn<E>>() {
+            observer(trigger, world);
+        }
+    }
+}
@@ -14,9 +14,8 @@
 
 //! World: central entity and archetype storage
 
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use parking_lot::RwLock;
-use slotmap::SlotMap;
 use smallvec::SmallVec;
 use std::any::TypeId;
 use std::marker::PhantomData;
@@ -27,16 +26,81 @@ use tracing::info_span;
 
 use crate::archetype::{Archetype, ArchetypeSignature, ComponentColumn};
 use crate::command::CommandBuffer;
-use crate::component::{Bundle, Component, MAX_BUNDLE_COMPONENTS};
-use crate::entity::{EntityId, EntityLocation};
-use crate::error::{EcsError, Result};
+use crate::component::{Bundle, BundleTypeIds, Component, MAX_BUNDLE_COMPONENTS};
+use crate::component_hooks::{ComponentHooks, ComponentHooksBuilder, HookKind};
+use crate::entity::{Entities, EntityId, EntityLocation};
+use crate::error::{EcsError, Result, SpawnError};
 use crate::event::{EntityEvent, EventQueue};
 use crate::observer::{Observer, ObserverRegistry};
 use crate::query::{Query, QueryFetch, QueryFetchMut, QueryFilter, QueryMut};
+use crate::reflection::{Reflect, TypeRegistry};
+use crate::relation::{Relation, RelationHook, RelationKind, RelationSources};
+use crate::removed_components::{RemovalLogs, RemovedCursor};
+use crate::resource::{Res, ResMut, Resources};
+use crate::storage::{SparseStaging, SparseStorage, SparseStorageBox, StorageType};
+use crate::system::SystemAccess;
+use crate::trigger::{run_observers, ObserverId, Trigger, TriggerObservers};
+
+/// Tick at which the world shifts every stored tick down
+///
+/// Ticks would otherwise run out after `u32::MAX` increments.
+pub const TICK_REBASE_THRESHOLD: u32 = 3 << 30;
+
+/// How many ticks back change detection reaches
+///
+/// A rebase clamps older ticks to this age, so a system that hasn't run
+/// for longer sees them as changed.
+pub const MAX_CHANGE_AGE: u32 = 1 << 30;
+
+/// Amount every tick drops by on a rebase
+const TICK_REBASE_SHIFT: u32 = TICK_REBASE_THRESHOLD - MAX_CHANGE_AGE;
+
+/// Shift one stored tick for a rebase, keeping 0 as "never"
+pub(crate) fn rebase_tick(tick: u32) -> u32 {
+    if tick == 0 {
+        0
+    } else {
+        tick.saturating_sub(TICK_REBASE_SHIFT).max(1)
+    }
+}
+
+/// A world tick kept across frames, such as when a system last ran
+///
+/// Stays comparable with fresh ticks through rebases, see
+/// [`World::saved_tick`]. The default is tick 0, before anything changed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct SavedTick {
+    tick: u32,
+    rebases: u32,
+}
+
+/// Type IDs of bundle `B`, which must all be distinct
+///
+/// A repeated type would have two values written to one column slot.
+fn bundle_type_ids<B: Bundle>() -> Result<BundleTypeIds> {
+    let type_ids = B::type_ids();
+    for (index, type_id) in type_ids.iter().enumerate() {
+        if type_ids[index + 1..].contains(type_id) {
+            return Err(EcsError::SpawnError(SpawnError::DuplicateComponent {
+                bundle: std::any::type_name::<B>(),
+            }));
+        }
+    }
+    Ok(type_ids)
+}
+
+/// One component type's values for [`World::spawn_columns`]
+pub(crate) struct ColumnSource {
+    pub(crate) type_id: TypeId,
+    /// Empty column of the type, used if the archetype doesn't exist yet
+    pub(crate) column: ComponentColumn,
+    /// Packed values, one per spawned entity
+    pub(crate) items: *mut u8,
+}
 
 /// Central ECS world
 pub struct World {
-    entity_locations: SlotMap<EntityId, EntityLocation>,
+    entity_locations: Entities,
 
     recycled_entities: usize,
 
@@ -57,18 +121,38 @@ pub struct World {
 
     pub tick: u32,
 
+    /// Number of tick rebases so far
+    tick_rebases: u32,
+
     removal_queue: Vec<EntityId>,
 
-    resources: AHashMap<TypeId, Box<dyn std::any::Any + Send + Sync>>,
+    resources: Resources,
 
     query_cache: RwLock<AHashMap<crate::query::QuerySignature, crate::query::CachedQueryResult>>,
+
+    type_registry: TypeRegistry,
+
+    /// Despawn cleanup hooks, one per relation kind in use
+    relation_hooks: AHashMap<TypeId, RelationHook>,
+
+    /// Components registered with `StorageType::SparseSet`
+    sparse_storage: SparseStorageBox,
+
+    /// Lifecycle hooks, keyed by component type
+    component_hooks: AHashMap<TypeId, ComponentHooks>,
+
+    /// Observers of typed triggers
+    triggers: TriggerObservers,
+
+    /// Removed components, for the types systems read with `RemovedComponents`
+    removed_components: RemovalLogs,
 }
 
 impl World {
     /// Create a new, empty world.
     pub fn new() -> Self {
         let mut world = Self {
-            entity_locations: SlotMap::with_key(),
+            entity_locations: Entities::new(),
             recycled_entities: 0,
 
             // Start with reasonable defaults to avoid resize spikes
@@ -84,10 +168,17 @@ impl World {
             global_event_bus: crate::event_bus::EventBus::new(),
 
             tick: 1, // Tick 0 is reserved/unused to ensure change detection checks always pass for new things
+            tick_rebases: 0,
             removal_queue: Vec::new(),
-            resources: AHashMap::new(),
+            resources: Resources::default(),
             // Pre-allocate query cache - trades memory for speed (most apps have <100 unique queries)
             query_cache: RwLock::new(AHashMap::with_capacity(32)),
+            type_registry: TypeRegistry::new(),
+            relation_hooks: AHashMap::new(),
+            sparse_storage: SparseStorageBox::new(),
+            component_hooks: AHashMap::new(),
+            triggers: TriggerObservers::default(),
+            removed_components: RemovalLogs::default(),
         };
 
         // Bootstrap the empty archetype (entities with no components)
@@ -102,15 +193,50 @@ impl World {
         self.tick
     }
 
+    /// Advance the tick, rebasing every stored tick at the threshold
+    ///
+    /// A rebase subtracts the same amount from all component and resource
+    /// ticks, so comparisons between ticks younger than [`MAX_CHANGE_AGE`]
+    /// are unaffected. Ticks kept outside the world should be saved with
+    /// [`World::save_tick`] to survive it.
     pub fn increment_tick(&mut self) {
-        // Saturating add: prefer capped growth over panic
-        self.tick = self.tick.saturating_add(1);
-        // Reserve 0 for "never changed" state
-        if self.tick == 0 {
-            self.tick = 1;
+        self.tick += 1;
+        if self.tick >= TICK_REBASE_THRESHOLD {
+            self.rebase_ticks();
+        }
+    }
+
+    fn rebase_ticks(&mut self) {
+        for archetype in &mut self.archetypes {
+            archetype.rebase_ticks();
+        }
+        self.sparse_storage.get_mut().rebase_ticks();
+        self.resources.rebase_ticks();
+        self.tick = rebase_tick(self.tick);
+        self.tick_rebases = self.tick_rebases.wrapping_add(1);
+    }
+
+    /// Save the current tick
+    pub fn save_tick(&self) -> SavedTick {
+        SavedTick {
+            tick: self.tick,
+            rebases: self.tick_rebases,
         }
     }
 
+    /// A saved tick, shifted by the rebases since it was saved
+    pub fn saved_tick(&self, saved: SavedTick) -> u32 {
+        let mut tick = saved.tick;
+        for _ in 0..self.tick_rebases.wrapping_sub(saved.rebases) {
+            // Ticks bottom out at 1, or stay 0
+            if tick <= 1 {
+                break;
+            }
+            tick = rebase_tick(tick);
+        }
+        tick
+    }
+
     /// Spawn entity with components
     #[deprecated(
         since = "1.2.0",
@@ -124,9 +250,12 @@ impl World {
     /// Spawn a new entity with the given bundle of components.
     ///
     /// # Panics
-    /// Panics if the Entity ID generator overflows (which is practically impossible).
+    /// Panics if the bundle repeats a component type, or if the Entity ID
+    /// generator overflows (which is practically impossible). Use
+    /// [`World::try_spawn_entity`] to handle these as errors.
     pub fn spawn_entity<B: Bundle>(&mut self, bundle: B) -> EntityId {
-        self.try_spawn_entity(bundle).unwrap()
+        self.try_spawn_entity(bundle)
+            .unwrap_or_else(|err| panic!("{err}"))
     }
 
     /// Try to spawn entity with components, returning detailed error information
@@ -145,7 +274,11 @@ impl World {
     /// - Entity capacity is exhausted
     /// - Component registration fails  
     /// - Archetype creation fails
+    /// - The bundle repeats a component type
     pub fn try_spawn_entity<B: Bundle>(&mut self, bundle: B) -> crate::error::Result<EntityId> {
+        let type_ids = bundle_type_ids::<B>()?;
+        self.flush_entities();
+
         // Ensure capacity before insertion
         self.ensure_entity_capacity()?;
 
@@ -159,18 +292,28 @@ impl World {
         if self.recycled_entities > 0 {
             self.recycled_entities -= 1;
         }
-        let type_ids = B::type_ids();
         #[cfg(feature = "profiling")]
         let span = info_span!(
             "world.spawn",
-            bundle_components = type_ids.len(),
+            bundle_components = B::component_count(),
             archetype_count = self.archetypes.len()
         );
         #[cfg(feature = "profiling")]
         let _span_guard = span.enter();
 
-        let arch_id = self.get_or_create_archetype_with(&type_ids, |arch| {
+        self.place_bundle(id, bundle, &type_ids);
+        Ok(id)
+    }
+
+    /// Write `bundle` into its archetype and point `id` at the new row
+    ///
+    /// Sparse-set members go to their sparse sets instead.
+    fn place_bundle<B: Bundle>(&mut self, id: EntityId, bundle: B, type_ids: &[TypeId]) {
+        let staging = SparseStaging::new(self.sparse_storage.get(), type_ids);
+        let table_ids = staging.table_ids(type_ids);
+        let arch_id = self.get_or_create_archetype_with(&table_ids, |arch| {
             B::register_components(arch);
+            arch.drop_sparse_columns();
             arch.mark_columns_initialized();
         });
         let archetype = &mut self.archetypes[arch_id];
@@ -179,27 +322,24 @@ impl World {
         let row = archetype.allocate_row(id, self.tick);
 
         // Pre-calculate column indices to avoid hash lookups in the hot path
-        let mut column_indices = [usize::MAX; MAX_BUNDLE_COMPONENTS];
-        let mut column_count = 0;
-        for &type_id in type_ids.iter() {
-            if let Some(idx) = archetype.column_index(type_id) {
-                column_indices[column_count] = idx;
-                column_count += 1;
-            }
-        }
+        let column_indices: SmallVec<[usize; MAX_BUNDLE_COMPONENTS]> = type_ids
+            .iter()
+            .filter_map(|&tid| archetype.column_index(tid))
+            .collect();
 
         // Write component data using pre-calculated indices
         // SAFETY: We have exclusive access to these rows because we just allocated them
-        let mut ptrs = [std::ptr::null_mut(); MAX_BUNDLE_COMPONENTS];
-        for i in 0..column_count {
-            let col_idx = column_indices[i];
+        let mut ptrs: SmallVec<[*mut u8; MAX_BUNDLE_COMPONENTS]> = SmallVec::new();
+        for &col_idx in &column_indices {
             if let Some(column) = archetype.get_column_mut_by_index(col_idx) {
-                ptrs[i] = column.get_ptr_mut(row);
+                ptrs.push(column.get_ptr_mut(row));
             }
         }
 
+        let ptrs = staging.pointers(ptrs);
         unsafe {
-            bundle.write_components(&ptrs[..column_count]);
+            bundle.write_components(&ptrs);
+            staging.flush(self.sparse_storage.get_mut(), id, self.tick);
         }
 
         // Update entity location
@@ -212,14 +352,71 @@ impl World {
         }
 
         // Track components
-        let mut component_set = std::collections::HashSet::with_capacity(type_ids.len());
-        for &type_id in type_ids.iter() {
+        let mut component_set = std::collections::HashSet::with_capacity(table_ids.len());
+        for &type_id in table_ids.iter() {
             component_set.insert(type_id);
         }
         self.component_tracker.insert(id, component_set);
+        self.run_added_hooks(id, type_ids);
+    }
 
-        // Return entity ID
-        Ok(id)
+    /// Reserve an entity ID without spawning it
+    ///
+    /// Safe to call through a shared reference, including from systems
+    /// running in parallel. The entity becomes alive, with no components, at
+    /// the next [`World::flush_entities`]; spawning and applying commands
+    /// flush automatically.
+    pub fn reserve_entity(&self) -> EntityId {
+        self.entity_locations.reserve()
+    }
+
+    /// Make every reserved entity alive, with no components
+    pub fn flush_entities(&mut self) {
+        if !self.entity_locations.needs_flush() {
+            return;
+        }
+        let empty = self.get_or_create_archetype_with(&ArchetypeSignature::new(), |arch| {
+            arch.mark_columns_initialized();
+        });
+        let archetype = &mut self.archetypes[empty];
+        let tick = self.tick;
+        let tracker = &mut self.component_tracker;
+        self.entity_locations.flush(|id| {
+            tracker.insert(id, std::collections::HashSet::new());
+            EntityLocation {
+                archetype_id: empty,
+                archetype_row: archetype.allocate_row(id, tick),
+            }
+        });
+    }
+
+    /// Give a component-less entity, usually a reserved one, its bundle
+    pub(crate) fn spawn_reserved<B: Bundle>(&mut self, entity: EntityId, bundle: B) -> Result<()> {
+        let type_ids = bundle_type_ids::<B>()?;
+        self.flush_entities();
+        let location = *self
+            .entity_locations
+            .get(entity)
+            .ok_or(EcsError::EntityNotFound)?;
+        if !self.archetypes[location.archetype_id]
+            .signature()
+            .is_empty()
+        {
+            return Err(EcsError::CommandError(format!(
+                "entity {entity} already has components"
+            )));
+        }
+
+        // SAFETY: The row belongs to `entity`, whose location is updated below
+        let swapped =
+            unsafe { self.archetypes[location.archetype_id].remove_row(location.archetype_row) };
+        if let Some(swapped) = swapped {
+            if let Some(swapped_loc) = self.entity_locations.get_mut(swapped) {
+                swapped_loc.archetype_row = location.archetype_row;
+            }
+        }
+        self.place_bundle(entity, bundle, &type_ids);
+        Ok(())
     }
 
     /// Check if an entity is alive
@@ -252,10 +449,32 @@ impl World {
         if !self.entity_locations.contains_key(entity) {
             return Err(EcsError::EntityNotFound);
         }
+        self.flush_entities();
+        self.run_despawn_hooks(entity);
+        self.record_despawn(entity);
+
+        // Detach from relations while the entity's components are still readable
+        if !self.relation_hooks.is_empty() {
+            let hooks: SmallVec<[RelationHook; 8]> =
+                self.relation_hooks.values().copied().collect();
+            for hook in hooks {
+                hook(self, entity);
+            }
+        }
+
+        let sparse = self.sparse_storage.get_mut();
+        if !sparse.is_empty() {
+            sparse.remove_entity(entity);
+        }
+        if !self.triggers.is_empty() {
+            self.triggers.remove_entity(entity);
+        }
 
         let location = self.entity_locations.remove(entity).unwrap();
         let archetype = &mut self.archetypes[location.archetype_id];
+        // SAFETY: The row belongs to `entity`, which is removed right after
         unsafe {
+            archetype.drop_row(location.archetype_row);
             if let Some(swapped_entity) = archetype.remove_row(location.archetype_row) {
                 if let Some(swapped_loc) = self.entity_locations.get_mut(swapped_entity) {
                     swapped_loc.archetype_row = location.archetype_row;
@@ -271,7 +490,7 @@ impl World {
     /// # Safety
     /// This provides unrestricted raw pointer access to world data.
     /// Internal tools use this for parallel execution after validating disjointness.
-    pub unsafe fn as_unsafe_world_cell(&mut self) -> UnsafeWorldCell {
+    pub unsafe fn as_unsafe_world_cell(&mut self) -> UnsafeWorldCell<'_> {
         UnsafeWorldCell::new(self)
     }
 
@@ -315,8 +534,10 @@ impl World {
         // Returns None for invalid entity - simpler API, caller decides error handling
         let location = self.entity_locations.get(entity)?;
         let archetype = self.archetypes.get(location.archetype_id)?;
-        let column = archetype.get_column(TypeId::of::<T>())?;
-        column.get::<T>(location.archetype_row)
+        match archetype.get_column(TypeId::of::<T>()) {
+            Some(column) => column.get::<T>(location.archetype_row),
+            None => self.sparse_storage.get().get::<T>()?.get(entity),
+        }
     }
 
     /// Get mutable reference to a component on an entity
@@ -325,7 +546,13 @@ impl World {
         let location = self.entity_locations.get(entity)?;
         let tick = self.tick;
         let archetype = self.archetypes.get_mut(location.archetype_id)?;
-        let column = archetype.get_column_mut(TypeId::of::<T>())?;
+        let Some(column) = archetype.get_column_mut(TypeId::of::<T>()) else {
+            return self
+                .sparse_storage
+                .get_mut()
+                .get_mut::<T>()?
+                .get_mut(entity, tick);
+        };
 
         // Mark component as changed for change detection
         column.mark_changed(location.archetype_row, tick);
@@ -337,7 +564,9 @@ impl World {
     pub fn has_component<T: Component>(&self, entity: EntityId) -> bool {
         if let Some(location) = self.entity_locations.get(entity) {
             if let Some(archetype) = self.archetypes.get(location.archetype_id) {
-                return archetype.has_column(TypeId::of::<T>());
+                let type_id = TypeId::of::<T>();
+                return archetype.has_column(type_id)
+                    || self.sparse_storage.get().contains(type_id, entity);
             }
         }
         false
@@ -345,110 +574,476 @@ impl World {
 
     /// Add a component to an entity
     ///
-    /// This is an expensive operation as it moves the entity to a new archetype.
+    /// This is an expensive operation as it moves the entity to a new archetype,
+    /// unless `T` uses sparse-set storage.
     pub fn add_component<T: Component>(&mut self, entity: EntityId, component: T) -> Result<()> {
         let location = *self
             .entity_locations
             .get(entity)
             .ok_or(EcsError::EntityNotFound)?;
-        let old_archetype = &mut self.archetypes[location.archetype_id];
 
-        // If component already exists, overwrite it
-        if let Some(col) = old_archetype.get_column_mut(TypeId::of::<T>()) {
-            let ptr = col.get_ptr_mut(location.archetype_row) as *mut T;
-            unsafe {
-                std::ptr::write(ptr, component);
+        let tick = self.tick;
+        let type_id = TypeId::of::<T>();
+        let sparse = self.sparse_storage.get();
+        if sparse.is_sparse(type_id) {
+            let added = !sparse.contains(type_id, entity);
+            if !added {
+                self.run_hooks(HookKind::Replace, entity, &[type_id]);
+            }
+            if let Some(set) = self.sparse_storage.get_mut().get_mut::<T>() {
+                set.insert(entity, component, tick);
             }
+            self.run_inserted_hooks(entity, type_id, added);
             return Ok(());
         }
 
-        // Check cache first (fast path)
-        let component_type = TypeId::of::<T>();
-
-        if let Some(new_archetype_id) = old_archetype.get_add_edge(component_type) {
-            // Fast path: archetype already exists in cache
-            self.move_entity(entity, location, new_archetype_id, |archetype, row| {
-                // Initialize new component
-                if let Some(col) = archetype.get_column_mut(component_type) {
-                    let ptr = col.get_ptr_mut(row) as *mut T;
-                    unsafe {
-                        std::ptr::write(ptr, component);
-                    }
+        // If component already exists, overwrite it
+        if self.archetypes[location.archetype_id].has_column(type_id) {
+            self.run_hooks(HookKind::Replace, entity, &[type_id]);
+            if let Some(col) = self.archetypes[location.archetype_id].get_column_mut(type_id) {
+                // SAFETY: The row holds an initialized `T`, which is dropped and
+                // immediately overwritten with the new value.
+                unsafe {
+                    col.drop_at(location.archetype_row);
+                    std::ptr::write(col.get_ptr_mut(location.archetype_row) as *mut T, component);
                 }
-            })?;
-            return Ok(());
-        }
-
-        // Slow path: compute new archetype and cache it
-        let mut new_signature = old_archetype.signature().clone();
-        new_signature.push(component_type);
-
-        // Capture existing columns to replicate them in new archetype
-        // We need to do this before calling get_or_create_archetype as that requires mutable self access,
-        // which would conflict with holding a reference to old_archetype.
-        let mut columns_to_add = Vec::with_capacity(new_signature.len());
-        for &type_id in old_archetype.signature() {
-            if let Some(col) = old_archetype.get_column(type_id) {
-                columns_to_add.push((type_id, col.clone_empty()));
+                col.mark_changed(location.archetype_row, tick);
             }
+            self.run_inserted_hooks(entity, type_id, false);
+            return Ok(());
         }
 
-        let new_archetype_id = self.get_or_create_archetype_with(&new_signature, |archetype| {
-            for (type_id, col) in columns_to_add {
-                archetype.add_column_raw(type_id, col);
-            }
-            archetype.register_component::<T>();
-            archetype.mark_columns_initialized();
-        });
-
-        // Cache the transition for future use
-        self.archetypes[location.archetype_id].set_add_edge(component_type, new_archetype_id);
+        let new_archetype_id = self.archetype_with_added(
+            location.archetype_id,
+            TypeId::of::<T>(),
+            ComponentColumn::new::<T>,
+        );
 
         // Move entity
         self.move_entity(entity, location, new_archetype_id, |archetype, row| {
             // Initialize new component
-            if let Some(col) = archetype.get_column_mut(TypeId::of::<T>()) {
+            if let Some(col) = archetype.get_column_mut(type_id) {
                 let ptr = col.get_ptr_mut(row) as *mut T;
                 unsafe {
                     std::ptr::write(ptr, component);
                 }
             }
-        })
+        })?;
+        self.run_inserted_hooks(entity, type_id, true);
+        Ok(())
     }
 
     /// Remove a component from an entity
     ///
-    /// This is an expensive operation as it moves the entity to a new archetype.
+    /// This is an expensive operation as it moves the entity to a new archetype,
+    /// unless `T` uses sparse-set storage.
     pub fn remove_component<T: Component>(&mut self, entity: EntityId) -> Result<()> {
+        self.remove_by_type_id(entity, TypeId::of::<T>())
+    }
+
+    /// Remove a component from an entity by its `TypeId`
+    ///
+    /// The removed component is dropped. Unlike `insert_reflect`, this does
+    /// not require the type to be registered.
+    pub fn remove_by_type_id(&mut self, entity: EntityId, component_type_id: TypeId) -> Result<()> {
         let old_location = self
             .entity_locations
             .get(entity)
             .copied()
             .ok_or(EcsError::EntityNotFound)?;
-        let old_archetype = &self.archetypes[old_location.archetype_id];
+
+        let sparse = self.sparse_storage.get();
+        if sparse.is_sparse(component_type_id) {
+            if !sparse.contains(component_type_id, entity) {
+                return Err(EcsError::ComponentNotFound);
+            }
+            self.run_hooks(HookKind::Remove, entity, &[component_type_id]);
+            self.sparse_storage
+                .get_mut()
+                .remove_by_type_id(component_type_id, entity);
+            self.removed_components.record(component_type_id, entity);
+            return Ok(());
+        }
 
         // PRE-CONDITION: Verify component exists on entity
-        let component_type_id = TypeId::of::<T>();
-        if !old_archetype.has_column(component_type_id) {
+        if !self.archetypes[old_location.archetype_id].has_column(component_type_id) {
             return Err(EcsError::ComponentNotFound);
         }
+        self.run_hooks(HookKind::Remove, entity, &[component_type_id]);
 
-        // Check cache first (fast path)
-        if let Some(new_archetype_id) = old_archetype.get_remove_edge(component_type_id) {
-            self.move_entity(entity, old_location, new_archetype_id, |_, _| {})?;
+        let new_archetype_id =
+            self.archetype_with_removed(old_location.archetype_id, component_type_id);
+
+        // POST-CONDITION: Verify destination archetype is ready
+        #[cfg(debug_assertions)]
+        {
+            let arch = &self.archetypes[new_archetype_id];
+            debug_assert!(
+                arch.columns_initialized(),
+                "BUG: Destination archetype columns not initialized"
+            );
+            for &tid in arch.signature() {
+                debug_assert!(
+                    arch.has_column(tid),
+                    "BUG: Destination archetype missing column for type {tid:?}"
+                );
+            }
+        }
+
+        // Safe migration: move entity and drop the removed component
+        self.move_entity(entity, old_location, new_archetype_id, |_, _| {})?;
+        self.removed_components.record(component_type_id, entity);
+        Ok(())
+    }
+
+    /// Choose how components of type `T` are stored
+    ///
+    /// Must be called before any `T` is stored in the other representation.
+    /// Sparse-set components are added and removed without moving the entity
+    /// between archetypes, and are inserted with `add_component` rather than
+    /// spawned in bundles.
+    ///
+    /// # Errors
+    /// Returns `EcsError::ValidationError` if existing `T` components would be
+    /// stranded by the switch.
+    pub fn register_storage<T: Component>(&mut self, storage: StorageType) -> Result<()> {
+        let type_id = TypeId::of::<T>();
+        match storage {
+            StorageType::SparseSet => {
+                if self.archetypes.iter().any(|arch| arch.has_column(type_id)) {
+                    return Err(EcsError::ValidationError(format!(
+                        "{} is already stored in archetype tables",
+                        std::any::type_name::<T>()
+                    )));
+                }
+                self.sparse_storage.get_mut().register::<T>();
+            }
+            StorageType::Table => {
+                if self.sparse_storage.get().len_of(type_id) > 0 {
+                    return Err(EcsError::ValidationError(format!(
+                        "{} still has sparse-set components",
+                        std::any::type_name::<T>()
+                    )));
+                }
+                self.sparse_storage.get_mut().unregister(type_id);
+            }
+        }
+
+        // Cached matches depend on which types are sparse
+        self.query_cache.write().clear();
+        Ok(())
+    }
+
+    /// Storage used for components of type `T`
+    pub fn storage_type<T: Component>(&self) -> StorageType {
+        if self.sparse_storage.get().is_sparse(TypeId::of::<T>()) {
+            StorageType::SparseSet
+        } else {
+            StorageType::Table
+        }
+    }
+
+    /// Sparse-set component storage
+    pub fn sparse_storage(&self) -> &SparseStorage {
+        self.sparse_storage.get()
+    }
+
+    /// Register lifecycle hooks for components of type `T`
+    ///
+    /// Hooks run synchronously, in registration order, from every mutation
+    /// that adds, replaces or removes a `T`:
+    ///
+    /// ```
+    /// # use archetype_ecs::prelude::*;
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// world
+    ///     .register_component_hooks::<Health>()
+    ///     .on_remove(|_, entity, health| println!("{entity} died at {}", health.0));
+    /// ```
+    pub fn register_component_hooks<T: Component>(&mut self) -> ComponentHooksBuilder<'_, T> {
+        ComponentHooksBuilder::new(self.component_hooks.entry(TypeId::of::<T>()).or_default())
+    }
+
+    /// Run the `kind` hooks of each of `type_ids` for `entity`
+    fn run_hooks(&self, kind: HookKind, entity: EntityId, type_ids: &[TypeId]) {
+        if self.component_hooks.is_empty() {
+            return;
+        }
+        for type_id in type_ids {
+            if let Some(hooks) = self.component_hooks.get(type_id) {
+                for hook in hooks.get(kind) {
+                    hook(self, entity);
+                }
+            }
+        }
+    }
+
+    /// Run the hooks for `entity` gaining every one of `type_ids`
+    fn run_added_hooks(&self, entity: EntityId, type_ids: &[TypeId]) {
+        self.run_hooks(HookKind::Add, entity, type_ids);
+        self.run_hooks(HookKind::Insert, entity, type_ids);
+    }
+
+    /// Run the hooks for a component inserted into `entity`
+    fn run_inserted_hooks(&self, entity: EntityId, type_id: TypeId, added: bool) {
+        if added {
+            self.run_hooks(HookKind::Add, entity, &[type_id]);
+        }
+        self.run_hooks(HookKind::Insert, entity, &[type_id]);
+    }
+
+    /// Types of every component of `entity`, archetype and sparse
+    fn component_type_ids(&self, entity: EntityId) -> SmallVec<[TypeId; MAX_BUNDLE_COMPONENTS]> {
+        let Some(location) = self.entity_locations.get(entity) else {
+            return SmallVec::new();
+        };
+        let mut type_ids: SmallVec<[TypeId; MAX_BUNDLE_COMPONENTS]> = self.archetypes
+            [location.archetype_id]
+            .signature()
+            .iter()
+            .copied()
+            .collect();
+        type_ids.extend(self.sparse_storage.get().type_ids_of(entity));
+        type_ids
+    }
+
+    /// Run the remove hooks of every component of `entity`
+    fn run_despawn_hooks(&self, entity: EntityId) {
+        if self.component_hooks.is_empty() {
+            return;
+        }
+        let type_ids = self.component_type_ids(entity);
+        self.run_hooks(HookKind::Remove, entity, &type_ids);
+    }
+
+    /// Log the removal of every component of `entity`
+    fn record_despawn(&mut self, entity: EntityId) {
+        if !self.removed_components.is_tracking() {
+            return;
+        }
+        for type_id in self.component_type_ids(entity) {
+            self.removed_components.record(type_id, entity);
+        }
+    }
+
+    pub(crate) fn resources(&self) -> &Resources {
+        &self.resources
+    }
+
+    /// Insert a component whose concrete type is only known at runtime
+    ///
+    /// The component's type must be registered in the world's `TypeRegistry`
+    /// (see `register_type`). The value is cloned into storage via the
+    /// registration's clone hook; an existing component of the same type is
+    /// dropped and replaced.
+    ///
+    /// # Errors
+    /// - `EcsError::EntityNotFound` if the entity does not exist
+    /// - `EcsError::ComponentRegistrationFailed` if the type is not registered
+    pub fn insert_reflect(&mut self, entity: EntityId, component: Box<dyn Reflect>) -> Result<()> {
+        let location = *self
+            .entity_locations
+            .get(entity)
+            .ok_or(EcsError::EntityNotFound)?;
+        let type_id = component.as_any().type_id();
+        let registration = self
+            .type_registry
+            .get(type_id)
+            .ok_or(EcsError::ComponentRegistrationFailed(type_id))?;
+        let (layout, drop_fn) = (registration.layout, registration.drop_fn);
+        let tick = self.tick;
+
+        let sparse = self.sparse_storage.get();
+        if sparse.is_sparse(type_id) {
+            if sparse.contains(type_id, entity) {
+                self.run_hooks(HookKind::Replace, entity, &[type_id]);
+            }
+            // SAFETY: The registration clones a value of its own type
+            let added = unsafe {
+                self.sparse_storage
+                    .get_mut()
+                    .insert_with(type_id, entity, tick, |dst| {
+                        registration.clone_reflect_into(component.as_ref(), dst)
+                    })
+            };
+            let added = added.ok_or(EcsError::ComponentRegistrationFailed(type_id))?;
+            self.run_inserted_hooks(entity, type_id, added);
+            return Ok(());
+        }
+
+        // Replace in place if the component already exists
+        if self.archetypes[location.archetype_id].has_column(type_id) {
+            self.run_hooks(HookKind::Replace, entity, &[type_id]);
+        }
+        if let Some(col) = self.archetypes[location.archetype_id].get_column_mut(type_id) {
+            // SAFETY: The row holds an initialized component of this type, which
+            // is dropped and immediately overwritten with a clone of the same type.
+            unsafe {
+                col.drop_at(location.archetype_row);
+                let dst = col.get_ptr_mut(location.archetype_row);
+                registration.clone_reflect_into(component.as_ref(), dst);
+            }
+            col.mark_changed(location.archetype_row, tick);
+            self.run_inserted_hooks(entity, type_id, false);
             return Ok(());
         }
 
-        // Build new signature (excluding component T)
+        let new_archetype_id = self.archetype_with_added(location.archetype_id, type_id, || {
+            ComponentColumn::from_layout(layout, drop_fn)
+        });
+
+        let registry = &self.type_registry;
+        let archetypes = &mut self.archetypes;
+        let entity_locations = &mut self.entity_locations;
+        Self::move_entity_in(
+            archetypes,
+            entity_locations,
+            tick,
+            entity,
+            location,
+            new_archetype_id,
+            |archetype, row| {
+                if let (Some(col), Some(registration)) =
+                    (archetype.get_column_mut(type_id), registry.get(type_id))
+                {
+                    // SAFETY: Fresh row in a column created from this registration's layout
+                    unsafe {
+                        registration.clone_reflect_into(component.as_ref(), col.get_ptr_mut(row));
+                    }
+                }
+            },
+        )?;
+        self.run_inserted_hooks(entity, type_id, true);
+        Ok(())
+    }
+
+    /// Get a component as `&dyn Reflect` by its `TypeId`
+    ///
+    /// Returns `None` if the entity does not exist, does not have the
+    /// component, or the type is not registered.
+    pub fn get_reflect(&self, entity: EntityId, type_id: TypeId) -> Option<&dyn Reflect> {
+        let location = self.entity_locations.get(entity)?;
+        let registration = self.type_registry.get(type_id)?;
+        let sparse = self.sparse_storage.get();
+        let ptr = if sparse.is_sparse(type_id) {
+            sparse.get_raw(type_id, entity)?
+        } else {
+            let column = self
+                .archetypes
+                .get(location.archetype_id)?
+                .get_column(type_id)?;
+            column.get_ptr(location.archetype_row)?
+        };
+        // SAFETY: The storage holds initialized values of the registered type
+        Some(unsafe { &*(registration.as_reflect_fn)(ptr) })
+    }
+
+    /// Get a component as `&mut dyn Reflect` by its `TypeId`
+    ///
+    /// Marks the component as changed, like `get_component_mut`.
+    pub fn get_reflect_mut(
+        &mut self,
+        entity: EntityId,
+        type_id: TypeId,
+    ) -> Option<&mut dyn Reflect> {
+        let location = self.entity_locations.get(entity)?;
+        let registration = self.type_registry.get(type_id)?;
+        let sparse = self.sparse_storage.get_mut();
+        let ptr = if sparse.is_sparse(type_id) {
+            sparse.get_raw_mut(type_id, entity, self.tick)?
+        } else {
+            let column = self
+                .archetypes
+                .get_mut(location.archetype_id)?
+                .get_column_mut(type_id)?;
+            column.get_ptr(location.archetype_row)?;
+            column.mark_changed(location.archetype_row, self.tick);
+            column.get_ptr_mut(location.archetype_row)
+        };
+        // SAFETY: The storage holds initialized values of the registered type
+        Some(unsafe { &mut *(registration.as_reflect_mut_fn)(ptr) })
+    }
+
+    /// Register a type for dynamic (reflection-based) component access
+    pub fn register_type<T: Reflect + Default + Clone>(&mut self) {
+        self.type_registry.register::<T>();
+    }
+
+    /// Get the world's type registry
+    pub fn type_registry(&self) -> &TypeRegistry {
+        &self.type_registry
+    }
+
+    /// Get the world's type registry mutably
+    pub fn type_registry_mut(&mut self) -> &mut TypeRegistry {
+        &mut self.type_registry
+    }
+
+    /// Resolve (and cache) the archetype reached by adding `type_id`
+    fn archetype_with_added<F>(
+        &mut self,
+        archetype_id: usize,
+        type_id: TypeId,
+        new_column: F,
+    ) -> usize
+    where
+        F: FnOnce() -> ComponentColumn,
+    {
+        let old_archetype = &self.archetypes[archetype_id];
+
+        // Check cache first (fast path)
+        if let Some(new_archetype_id) = old_archetype.get_add_edge(type_id) {
+            return new_archetype_id;
+        }
+
+        // Slow path: compute new archetype and cache it
+        let mut new_signature = old_archetype.signature().clone();
+        new_signature.push(type_id);
+
+        // Capture existing columns to replicate them in new archetype
+        // We need to do this before calling get_or_create_archetype as that requires mutable self access,
+        // which would conflict with holding a reference to old_archetype.
+        let mut columns_to_add = Vec::with_capacity(new_signature.len());
+        for &existing in old_archetype.signature() {
+            if let Some(col) = old_archetype.get_column(existing) {
+                columns_to_add.push((existing, col.clone_empty()));
+            }
+        }
+        columns_to_add.push((type_id, new_column()));
+
+        let new_archetype_id = self.get_or_create_archetype_with(&new_signature, |archetype| {
+            for (type_id, col) in columns_to_add {
+                archetype.add_column_raw(type_id, col);
+            }
+            archetype.mark_columns_initialized();
+        });
+
+        // Cache the transition for future use
+        self.archetypes[archetype_id].set_add_edge(type_id, new_archetype_id);
+        new_archetype_id
+    }
+
+    /// Resolve (and cache) the archetype reached by removing `type_id`
+    fn archetype_with_removed(&mut self, archetype_id: usize, type_id: TypeId) -> usize {
+        let old_archetype = &self.archetypes[archetype_id];
+
+        // Check cache first (fast path)
+        if let Some(new_archetype_id) = old_archetype.get_remove_edge(type_id) {
+            return new_archetype_id;
+        }
+
+        // Build new signature (excluding the removed component)
         let mut new_signature = old_archetype.signature().clone();
-        new_signature.retain(|tid| *tid != component_type_id);
+        new_signature.retain(|tid| *tid != type_id);
 
         // Capture existing columns to replicate them in new archetype.
         // This must be done before we potentially push to self.archetypes.
         let mut columns_to_add = Vec::with_capacity(new_signature.len());
-        for &type_id in &new_signature {
-            if let Some(col) = old_archetype.get_column(type_id) {
-                columns_to_add.push((type_id, col.clone_empty()));
+        for &existing in &new_signature {
+            if let Some(col) = old_archetype.get_column(existing) {
+                columns_to_add.push((existing, col.clone_empty()));
             }
         }
 
@@ -460,27 +1055,8 @@ impl World {
         });
 
         // Cache the transition for future use
-        self.archetypes[old_location.archetype_id]
-            .set_remove_edge(component_type_id, new_archetype_id);
-
-        // POST-CONDITION: Verify destination archetype is ready
-        #[cfg(debug_assertions)]
-        {
-            let arch = &self.archetypes[new_archetype_id];
-            debug_assert!(
-                arch.columns_initialized(),
-                "BUG: Destination archetype columns not initialized"
-            );
-            for &tid in arch.signature() {
-                debug_assert!(
-                    arch.has_column(tid),
-                    "BUG: Destination archetype missing column for type {tid:?}"
-                );
-            }
-        }
-
-        // Safe migration: move entity and drop the removed component implicitly
-        self.move_entity(entity, old_location, new_archetype_id, |_, _| {})
+        self.archetypes[archetype_id].set_remove_edge(type_id, new_archetype_id);
+        new_archetype_id
     }
 
     /// Get multiple immutable components at once using QueryFetch
@@ -549,6 +1125,34 @@ impl World {
         new_archetype_id: usize,
         on_new_location: F,
     ) -> Result<()>
+    where
+        F: FnOnce(&mut Archetype, usize),
+    {
+        let tick = self.tick;
+        Self::move_entity_in(
+            &mut self.archetypes,
+            &mut self.entity_locations,
+            tick,
+            entity,
+            old_loc,
+            new_archetype_id,
+            on_new_location,
+        )
+    }
+
+    /// Move implementation over the storage fields only, so callers can keep
+    /// other parts of the world (e.g. the type registry) borrowed.
+    ///
+    /// Components present in the old archetype but not the new one are dropped.
+    fn move_entity_in<F>(
+        archetypes: &mut [Archetype],
+        entity_locations: &mut Entities,
+        tick: u32,
+        entity: EntityId,
+        old_loc: EntityLocation,
+        new_archetype_id: usize,
+        on_new_location: F,
+    ) -> Result<()>
     where
         F: FnOnce(&mut Archetype, usize),
     {
@@ -556,17 +1160,13 @@ impl World {
             return Ok(());
         }
 
-        let tick = self.tick;
-        // We need to ensure new archetype has space (it does via allocate_row logic usually, but let's be safe if reserve needed)
-        // actually allocate_row just pushes.
-
         // Access both archetypes safely using split_at_mut
         // We need this to copy components from old to new.
         let (old_arch, new_arch) = if old_loc.archetype_id < new_archetype_id {
-            let (left, right) = self.archetypes.split_at_mut(new_archetype_id);
+            let (left, right) = archetypes.split_at_mut(new_archetype_id);
             (&mut left[old_loc.archetype_id], &mut right[0])
         } else {
-            let (left, right) = self.archetypes.split_at_mut(old_loc.archetype_id);
+            let (left, right) = archetypes.split_at_mut(old_loc.archetype_id);
             (&mut right[0], &mut left[new_archetype_id])
         };
 
@@ -586,6 +1186,15 @@ impl World {
                     }
                 }
             }
+
+            // Components left behind were not moved anywhere, so drop them
+            // before their row is overwritten by remove_row
+            let old_sig = old_arch.signature().to_vec();
+            for &type_id in old_sig.iter().filter(|tid| !new_sig.contains(tid)) {
+                if let Some(old_col) = old_arch.get_column_mut(type_id) {
+                    old_col.drop_at(old_loc.archetype_row);
+                }
+            }
         }
 
         on_new_location(new_arch, new_row);
@@ -593,14 +1202,14 @@ impl World {
         // Remove from old archetype
         unsafe {
             if let Some(swapped_entity) = old_arch.remove_row(old_loc.archetype_row) {
-                if let Some(swapped_loc_ptr) = self.entity_locations.get_mut(swapped_entity) {
+                if let Some(swapped_loc_ptr) = entity_locations.get_mut(swapped_entity) {
                     swapped_loc_ptr.archetype_row = old_loc.archetype_row;
                 }
             }
         }
 
         // Update location of moved entity
-        if let Some(loc) = self.entity_locations.get_mut(entity) {
+        if let Some(loc) = entity_locations.get_mut(entity) {
             loc.archetype_id = new_archetype_id;
             loc.archetype_row = new_row;
         }
@@ -697,11 +1306,21 @@ impl World {
 
     /// Clear all entities
     pub fn clear(&mut self) {
+        self.flush_entities();
+        if !self.component_hooks.is_empty() || self.removed_components.is_tracking() {
+            let entities: Vec<EntityId> = self.entity_locations.iter().map(|(id, _)| id).collect();
+            for entity in entities {
+                self.run_despawn_hooks(entity);
+                self.record_despawn(entity);
+            }
+        }
         self.entity_locations.clear();
+        self.triggers.clear_scoped();
         self.recycled_entities = 0;
         self.archetypes.clear();
         self.arch_idx.clear();
         self.query_cache.write().clear();
+        self.sparse_storage.get_mut().clear();
 
         // Recreate empty archetype
         self.get_or_create_archetype(&[]); // FIXED
@@ -728,36 +1347,35 @@ impl World {
 
     /// Insert a resource (singleton) into the world
     pub fn insert_resource<R: Send + Sync + 'static>(&mut self, resource: R) {
-        self.resources.insert(TypeId::of::<R>(), Box::new(resource));
+        self.resources.insert(resource, self.tick);
     }
 
     /// Get an immutable reference to a resource
     pub fn resource<R: 'static>(&self) -> Option<&R> {
-        self.resources
-            .get(&TypeId::of::<R>())
-            .and_then(|r| r.downcast_ref())
+        self.resources.get()
     }
 
     /// Get a mutable reference to a resource
     ///
-    /// Returns `None` if the resource doesn't exist.
+    /// Returns `None` if the resource doesn't exist. Marks the resource
+    /// changed at the current tick.
     pub fn resource_mut<R: 'static>(&mut self) -> Option<&mut R> {
-        self.resources
-            .get_mut(&TypeId::of::<R>())
-            .and_then(|r| r.downcast_mut())
+        self.resources.get_mut(self.tick)
+    }
+
+    /// Tick at which a resource was last inserted or mutably accessed
+    pub fn resource_changed_tick<R: 'static>(&self) -> Option<u32> {
+        self.resources.changed_tick(TypeId::of::<R>())
     }
 
     /// Check if a resource exists
     pub fn has_resource<R: 'static>(&self) -> bool {
-        self.resources.contains_key(&TypeId::of::<R>())
+        self.resources.contains(TypeId::of::<R>())
     }
 
     /// Remove a resource and return it
     pub fn remove_resource<R: 'static>(&mut self) -> Option<R> {
-        self.resources
-            .remove(&TypeId::of::<R>())
-            .and_then(|r| r.downcast().ok())
-            .map(|boxed| *boxed)
+        self.resources.remove()
     }
 
     /// Get a mutable reference to a resource, inserting it if it doesn't exist
@@ -765,16 +1383,13 @@ impl World {
         &mut self,
         f: impl FnOnce() -> R,
     ) -> &mut R {
-        let type_id = TypeId::of::<R>();
-
-        if !self.resources.contains_key(&type_id) {
-            self.resources.insert(type_id, Box::new(f()));
+        if !self.resources.contains(TypeId::of::<R>()) {
+            self.resources.insert(f(), self.tick);
         }
 
         // Internal helper - panic indicates programming error
         self.resources
-            .get_mut(&type_id)
-            .and_then(|r| r.downcast_mut())
+            .get_mut(self.tick)
             .expect("Resource should exist after init")
     }
 
@@ -787,11 +1402,11 @@ impl World {
     pub fn init_resource<R: Send + Sync + 'static>(&mut self, resource: R) -> Result<()> {
         let type_id = TypeId::of::<R>();
 
-        if self.resources.contains_key(&type_id) {
+        if self.resources.contains(type_id) {
             return Err(EcsError::ResourceAlreadyExists(type_id));
         }
 
-        self.resources.insert(type_id, Box::new(resource));
+        self.resources.insert(resource, self.tick);
         Ok(())
     }
 
@@ -828,6 +1443,7 @@ impl World {
 
         // Create new archetype with the sorted signature
         let mut archetype = Archetype::new(sorted_signature.clone());
+        archetype.set_sparse_storage(self.sparse_storage.as_ptr());
         on_create(&mut archetype);
 
         // Push archetype FIRST to ensure it exists
@@ -852,6 +1468,7 @@ impl World {
     {
         let bundles = bundles.into_iter();
         let count = bundles.len();
+        self.flush_entities();
 
         // Limit batch size to prevent OOM and overflow (10M is generous but prevents DoS)
         if count > 10_000_000 {
@@ -868,15 +1485,19 @@ impl World {
 
         if current + count > self.entity_locations.capacity() {
             let additional = new_capacity - current;
-            self.entity_locations.reserve(additional);
+            self.entity_locations.reserve_capacity(additional);
         }
 
         // Get or create archetype first
-        let type_ids = B::type_ids();
-        let archetype_id = self.get_or_create_archetype_with(&type_ids, |archetype| {
+        let type_ids = bundle_type_ids::<B>()?;
+        let staging = SparseStaging::new(self.sparse_storage.get(), &type_ids);
+        let table_ids = staging.table_ids(&type_ids);
+        let archetype_id = self.get_or_create_archetype_with(&table_ids, |archetype| {
             B::register_components(archetype);
+            archetype.drop_sparse_columns();
             archetype.mark_columns_initialized();
         });
+        let sparse_storage = self.sparse_storage.get_mut();
 
         // Get mutable reference to archetype after all lookups are done
         let archetype = &mut self.archetypes[archetype_id];
@@ -886,14 +1507,10 @@ impl World {
         archetype.reserve_rows(count);
 
         // OPTIMIZATION: Pre-calculate column indices to avoid hash lookups in the hot loop
-        let mut column_indices = [usize::MAX; MAX_BUNDLE_COMPONENTS];
-        let mut col_count = 0;
-        for &tid in type_ids.iter() {
-            if let Some(idx) = archetype.column_index(tid) {
-                column_indices[col_count] = idx;
-                col_count += 1;
-            }
-        }
+        let column_indices: SmallVec<[usize; MAX_BUNDLE_COMPONENTS]> = type_ids
+            .iter()
+            .filter_map(|&tid| archetype.column_index(tid))
+            .collect();
 
         // Process each bundle
         for bundle in bundles {
@@ -911,21 +1528,22 @@ impl World {
             }
 
             // Write component data using pre-calculated indices
-            let mut ptrs = [std::ptr::null_mut(); MAX_BUNDLE_COMPONENTS];
-            for i in 0..col_count {
-                let col_idx = column_indices[i];
+            let mut ptrs: SmallVec<[*mut u8; MAX_BUNDLE_COMPONENTS]> = SmallVec::new();
+            for &col_idx in &column_indices {
                 if let Some(column) = archetype.get_column_mut_by_index(col_idx) {
-                    ptrs[i] = column.get_ptr_mut(row);
+                    ptrs.push(column.get_ptr_mut(row));
                 }
             }
 
+            let ptrs = staging.pointers(ptrs);
             unsafe {
-                bundle.write_components(&ptrs[..col_count]);
+                bundle.write_components(&ptrs);
+                staging.flush(sparse_storage, entity, self.tick);
             }
 
             // Track components for change detection
             let mut component_set = std::collections::HashSet::new();
-            for &tid in type_ids.iter() {
+            for &tid in table_ids.iter() {
                 component_set.insert(tid);
             }
             self.component_tracker.insert(entity, component_set);
@@ -933,9 +1551,104 @@ impl World {
             entity_ids.push(entity);
         }
 
+        for &entity in &entity_ids {
+            self.run_added_hooks(entity, &type_ids);
+        }
         Ok(entity_ids)
     }
 
+    /// Spawn `count` entities from type-erased component columns
+    ///
+    /// Each source's values are moved in row order, so the whole batch lands
+    /// in its archetype with one copy per column. Sparse types go to their
+    /// sparse sets instead.
+    ///
+    /// # Safety
+    /// Each source's `items` must point to `count` initialized values of its
+    /// type, with distinct types across sources. The values are moved out,
+    /// so the caller must not drop them again.
+    pub(crate) unsafe fn spawn_columns(
+        &mut self,
+        count: usize,
+        sources: Vec<ColumnSource>,
+    ) -> Vec<EntityId> {
+        self.flush_entities();
+        if count == 0 {
+            return Vec::new();
+        }
+        if self.entity_locations.len() + count > self.entity_locations.capacity() {
+            self.entity_locations.reserve_capacity(count);
+        }
+
+        let type_ids: SmallVec<[TypeId; MAX_BUNDLE_COMPONENTS]> =
+            sources.iter().map(|source| source.type_id).collect();
+        let (sparse, table): (Vec<_>, Vec<_>) = sources
+            .into_iter()
+            .partition(|source| self.sparse_storage.get().is_sparse(source.type_id));
+        let table_ids: ArchetypeSignature = table.iter().map(|source| source.type_id).collect();
+        let (columns, items): (Vec<_>, Vec<_>) = table
+            .into_iter()
+            .map(|source| {
+                (
+                    (source.type_id, source.column),
+                    (source.type_id, source.items),
+                )
+            })
+            .unzip();
+        let archetype_id = self.get_or_create_archetype_with(&table_ids, |archetype| {
+            for (type_id, column) in columns {
+                archetype.add_column_raw(type_id, column);
+            }
+            archetype.mark_columns_initialized();
+        });
+        let sparse_storage = self.sparse_storage.get_mut();
+        let archetype = &mut self.archetypes[archetype_id];
+        archetype.reserve_rows(count);
+
+        let first_row = archetype.len();
+        let mut entity_ids = Vec::with_capacity(count);
+        for index in 0..count {
+            let entity = self.entity_locations.insert(EntityLocation {
+                archetype_id,
+                archetype_row: 0,
+            });
+            let row = archetype.allocate_row(entity, self.tick);
+            if let Some(loc) = self.entity_locations.get_mut(entity) {
+                loc.archetype_row = row;
+            }
+
+            for source in &sparse {
+                let size = source.column.get_item_size();
+                sparse_storage.insert_raw(
+                    source.type_id,
+                    entity,
+                    source.items.add(index * size),
+                    self.tick,
+                );
+            }
+
+            self.component_tracker
+                .insert(entity, table_ids.iter().copied().collect());
+            entity_ids.push(entity);
+        }
+
+        // Rows are contiguous, so each column is a single copy
+        for (type_id, items) in items {
+            let Some(column) = archetype.get_column_mut(type_id) else {
+                continue;
+            };
+            let size = column.get_item_size();
+            // Grows the column to cover every new row first
+            column.get_ptr_mut(first_row + count - 1);
+            std::ptr::copy_nonoverlapping(items, column.get_ptr_mut(first_row), count * size);
+        }
+
+        for &entity in &entity_ids {
+            self.run_added_hooks(entity, &type_ids);
+        }
+        entity_ids
+    }
+
     /// Ensure we have enough capacity for new entities with an aggressive growth strategy
     fn ensure_entity_capacity(&mut self) -> crate::error::Result<()> {
         let len = self.entity_locations.len();
@@ -955,7 +1668,7 @@ impl World {
         if len >= cap {
             // Aggressive growth to reduce reallocations
             let growth = (cap / 2).max(64);
-            self.entity_locations.reserve(growth);
+            self.entity_locations.reserve_capacity(growth);
         }
         Ok(())
     }
@@ -1061,6 +1774,69 @@ impl World {
         &mut self.observers
     }
 
+    /// Observe every trigger of type `E`
+    pub fn observe<E, F>(&mut self, observer: F) -> ObserverId
+    where
+        E: Send + Sync + 'static,
+        F: Fn(&mut Trigger<E>, &mut World) + Send + Sync + 'static,
+    {
+        self.triggers.add(None, observer)
+    }
+
+    /// Observe triggers of type `E` targeting `entity` or its descendants
+    ///
+    /// The observer is removed when `entity` is despawned.
+    pub fn observe_entity<E, F>(&mut self, entity: EntityId, observer: F) -> ObserverId
+    where
+        E: Send + Sync + 'static,
+        F: Fn(&mut Trigger<E>, &mut World) + Send + Sync + 'static,
+    {
+        self.triggers.add(Some(entity), observer)
+    }
+
+    /// Remove an observer, returning whether it existed
+    pub fn unobserve(&mut self, observer: ObserverId) -> bool {
+        self.triggers.remove(observer)
+    }
+
+    /// Number of registered trigger observers
+    pub fn trigger_observer_count(&self) -> usize {
+        self.triggers.len()
+    }
+
+    /// Run the global observers of `E`
+    pub fn trigger<E: Send + Sync + 'static>(&mut self, event: E) {
+        let observers = self.triggers.matching::<E>(None);
+        run_observers(&observers, &mut Trigger::new(event, None), self);
+    }
+
+    /// Trigger `E` on `entity`, propagating up its `Parent` chain
+    ///
+    /// Global observers of `E` run first, once, then `entity`'s scoped
+    /// observers. The trigger then moves up to each ancestor in turn until an
+    /// observer calls [`Trigger::stop_propagation`]. Each entity is visited
+    /// once, so a `Parent` cycle ends the walk.
+    pub fn trigger_entity<E: Send + Sync + 'static>(&mut self, event: E, entity: EntityId) {
+        let mut trigger = Trigger::new(event, Some(entity));
+        let observers = self.triggers.matching::<E>(None);
+        run_observers(&observers, &mut trigger, self);
+
+        let mut visited = AHashSet::new();
+        let mut current = Some(entity);
+        while let Some(entity) = current {
+            if !visited.insert(entity) {
+                break;
+            }
+            trigger.retarget(entity);
+            let observers = self.triggers.matching::<E>(Some(entity));
+            run_observers(&observers, &mut trigger, self);
+            if !trigger.is_propagating() {
+                break;
+            }
+            current = self.get_parent(entity);
+        }
+    }
+
     /// Get event queue (for inspection)
     pub fn event_queue(&self) -> &EventQueue {
         &self.event_queue
@@ -1130,6 +1906,143 @@ impl World {
         Ok(())
     }
 
+    // ========== Relation Methods ==========
+
+    /// Relate `source` to `target` through relation kind `R`
+    ///
+    /// Adding an existing relation is a no-op. Both sides are cleaned up
+    /// automatically when either entity is despawned.
+    pub fn add_relation<R: RelationKind>(
+        &mut self,
+        source: EntityId,
+        target: EntityId,
+    ) -> Result<()> {
+        if !self.is_alive(source) || !self.is_alive(target) {
+            return Err(EcsError::EntityNotFound);
+        }
+
+        self.register_relation_kind::<R>();
+
+        if !self.has_component::<Relation<R>>(source) {
+            self.add_component(source, Relation::<R>::new())?;
+        }
+        if !self.has_component::<RelationSources<R>>(target) {
+            self.add_component(target, RelationSources::<R>::new())?;
+        }
+
+        if let Some(relation) = self.get_component_mut::<Relation<R>>(source) {
+            relation.add(target);
+        }
+        if let Some(sources) = self.get_component_mut::<RelationSources<R>>(target) {
+            sources.add(source);
+        }
+
+        Ok(())
+    }
+
+    /// Clean up `R` relations when their entities are despawned
+    pub(crate) fn register_relation_kind<R: RelationKind>(&mut self) {
+        self.relation_hooks
+            .entry(TypeId::of::<R>())
+            .or_insert(crate::relation::cleanup_relations::<R>);
+    }
+
+    /// Remove the `R` relation from `source` to `target`
+    ///
+    /// The `Relation<R>` component is removed from `source` once it has no targets left.
+    pub fn remove_relation<R: RelationKind>(
+        &mut self,
+        source: EntityId,
+        target: EntityId,
+    ) -> Result<()> {
+        let removed = self
+            .get_component_mut::<Relation<R>>(source)
+            .map(|relation| (relation.remove(target), relation.is_empty()));
+        let now_empty = match removed {
+            Some((true, now_empty)) => now_empty,
+            _ => {
+                return Err(EcsError::RelationError(format!(
+                    "Entity {source:?} is not related to {target:?}"
+                )))
+            }
+        };
+
+        if let Some(sources) = self.get_component_mut::<RelationSources<R>>(target) {
+            sources.remove(source);
+        }
+        if now_empty {
+            self.remove_component::<Relation<R>>(source)?;
+        }
+
+        Ok(())
+    }
+
+    /// Check whether `source` is related to `target` through `R`
+    pub fn has_relation<R: RelationKind>(&self, source: EntityId, target: EntityId) -> bool {
+        self.get_component::<Relation<R>>(source)
+            .is_some_and(|r| r.contains(target))
+    }
+
+    /// Targets of `source`'s `R` relations
+    pub fn relation_targets<R: RelationKind>(&self, source: EntityId) -> &[EntityId] {
+        self.get_component::<Relation<R>>(source)
+            .map(|r| r.targets())
+            .unwrap_or(&[])
+    }
+
+    /// Entities that have an `R` relation to `target`
+    pub fn relation_sources<R: RelationKind>(&self, target: EntityId) -> &[EntityId] {
+        self.get_component::<RelationSources<R>>(target)
+            .map(|s| s.sources())
+            .unwrap_or(&[])
+    }
+
+    /// Query the sources of `target`'s incoming `R` relations
+    ///
+    /// Yields `Q` for every entity that relates to `target` and matches `Q`,
+    /// e.g. `world.query_related::<Targets, (Entity, &Health)>(goblin)`.
+    pub fn query_related<'w, R, Q>(
+        &'w self,
+        target: EntityId,
+    ) -> impl Iterator<Item = <Q as QueryFetch<'w>>::Item> + 'w
+    where
+        R: RelationKind,
+        Q: QueryFetch<'w> + 'w,
+    {
+        self.relation_sources::<R>(target)
+            .iter()
+            .filter_map(move |&source| self.get_components::<Q>(source))
+    }
+
+    /// Despawn `target` and, recursively, every entity related to it through `R`
+    ///
+    /// Each entity is visited once, so relation cycles are fine.
+    pub fn despawn_related<R: RelationKind>(&mut self, target: EntityId) -> Result<()> {
+        if !self.is_alive(target) {
+            return Err(EcsError::EntityNotFound);
+        }
+
+        let mut visited = AHashSet::from_iter([target]);
+        let mut order = vec![target];
+        let mut next = 0;
+        while let Some(&entity) = order.get(next) {
+            next += 1;
+            for &source in self.relation_sources::<R>(entity) {
+                if visited.insert(source) {
+                    order.push(source);
+                }
+            }
+        }
+
+        // Sources first, so each despawn only unlinks entities still alive
+        for entity in order.into_iter().rev() {
+            if self.is_alive(entity) {
+                self.despawn(entity)?;
+            }
+        }
+        Ok(())
+    }
+
     // ========== Global Event Bus Methods (Phase 6) ==========
 
     /// Get mutable reference to global event bus
@@ -1155,6 +2068,28 @@ impl World {
         self.global_event_bus.process_events()
     }
 
+    // ========== Serialization ==========
+
+    /// Save all binary-registered components to a versioned binary snapshot
+    pub fn save_binary(
+        &self,
+        registry: &crate::serialization::SerializationRegistry,
+    ) -> Result<Vec<u8>> {
+        crate::serialization::save_world_binary(self, registry)?.to_bytes()
+    }
+
+    /// Load a binary snapshot produced by `save_binary` into this world
+    ///
+    /// Returns a map from saved entity IDs to the newly spawned entities.
+    pub fn load_binary(
+        &mut self,
+        bytes: &[u8],
+        registry: &crate::serialization::SerializationRegistry,
+    ) -> Result<std::collections::HashMap<u64, EntityId>> {
+        let scene = crate::serialization::BinaryScene::from_bytes(bytes)?;
+        crate::serialization::load_world_binary(self, &scene, registry)
+    }
+
     // ========== Query Cache Management (Phase 2) ==========
 
     /// Get or update cached query results for a signature
@@ -1207,7 +2142,7 @@ impl World {
     /// # Example
     /// ```
     /// # let mut world = archetype_ecs::World::new();
-    /// # let entity_id = world.spawn((1.0f32, 0.1f32));
+    /// # let entity_id = world.spawn((1.0f32, 1u32));
     /// world.debug_print_entity(entity_id);
     /// // Output shows entity with its components
     /// ```
@@ -1234,8 +2169,8 @@ impl World {
     /// # Example
     /// ```
     /// # let mut world = archetype_ecs::World::new();
-    /// # world.spawn((1.0f32, 0.1f32));
-    /// # world.spawn((2.0f32, 0.2f32));
+    /// # world.spawn((1.0f32, 1u32));
+    /// # world.spawn((2.0f32, 2u32));
     /// world.debug_print_entities_with::<f32>();
     /// // Output shows all entities with f32 components
     /// ```
@@ -1375,6 +2310,74 @@ mod tests {
         // Should create 4 archetypes (+ empty one)
         assert!(world.archetype_count() >= 4);
     }
+
+    #[derive(Debug, Clone, Default, PartialEq)]
+    struct Tagged {
+        name: String,
+        value: i32,
+    }
+    crate::impl_reflect!(Tagged);
+
+    #[test]
+    fn test_insert_reflect_round_trip() -> Result<()> {
+        let mut world = World::new();
+        world.register_type::<Tagged>();
+
+        let entity = world.spawn_entity((1u32,));
+        let tagged = Tagged {
+            name: "player".to_string(),
+            value: 7,
+        };
+        world.insert_reflect(entity, Box::new(tagged.clone()))?;
+
+        assert_eq!(world.get_component::<Tagged>(entity), Some(&tagged));
+        assert_eq!(world.get_component::<u32>(entity), Some(&1));
+
+        let reflected = world
+            .get_reflect(entity, TypeId::of::<Tagged>())
+            .expect("component should be reflectable");
+        assert_eq!(reflected.as_any().downcast_ref::<Tagged>(), Some(&tagged));
+
+        // Inserting again replaces the existing value in place
+        world.insert_reflect(
+            entity,
+            Box::new(Tagged {
+                name: "enemy".to_string(),
+                value: 3,
+            }),
+        )?;
+        assert_eq!(world.get_component::<Tagged>(entity).unwrap().value, 3);
+
+        world.remove_by_type_id(entity, TypeId::of::<Tagged>())?;
+        assert!(world.get_reflect(entity, TypeId::of::<Tagged>()).is_none());
+        assert_eq!(world.get_component::<u32>(entity), Some(&1));
+        Ok(())
+    }
+
+    #[test]
+    fn test_insert_reflect_unregistered_fails() {
+        let mut world = World::new();
+        let entity = world.spawn_entity((1u32,));
+
+        let result = world.insert_reflect(entity, Box::new(Tagged::default()));
+        assert!(matches!(
+            result,
+            Err(EcsError::ComponentRegistrationFailed(id)) if id == TypeId::of::<Tagged>()
+        ));
+    }
+
+    #[test]
+    fn test_remove_component_drops_value() -> Result<()> {
+        let mut world = World::new();
+        let shared = std::sync::Arc::new(());
+
+        let entity = world.spawn_entity((1u32, shared.clone()));
+        assert_eq!(std::sync::Arc::strong_count(&shared), 2);
+
+        world.remove_component::<std::sync::Arc<()>>(entity)?;
+        assert_eq!(std::sync::Arc::strong_count(&shared), 1);
+        Ok(())
+    }
 }
 
 /// A pointer to the world that can be used to bypass standard borrow checking.
@@ -1385,6 +2388,9 @@ mod tests {
 #[derive(Copy, Clone)]
 pub struct UnsafeWorldCell<'a> {
     world: NonNull<World>,
+    /// Access declared by the running system, if restricted
+    #[cfg_attr(not(debug_assertions), allow(dead_code))]
+    access: Option<&'a SystemAccess>,
     _marker: PhantomData<&'a mut World>,
 }
 
@@ -1395,6 +2401,7 @@ impl<'a> UnsafeWorldCell<'a> {
     pub(crate) unsafe fn new(world: &mut World) -> Self {
         Self {
             world: NonNull::from(world),
+            access: None,
             _marker: PhantomData,
         }
     }
@@ -1412,6 +2419,16 @@ impl<'a> UnsafeWorldCell<'a> {
         unsafe { self.world.as_ref().tick }
     }
 
+    /// Save the current tick, see [`World::save_tick`]
+    pub fn save_tick(&self) -> SavedTick {
+        unsafe { self.world.as_ref().save_tick() }
+    }
+
+    /// Resolve a saved tick, see [`World::saved_tick`]
+    pub fn saved_tick(&self, saved: SavedTick) -> u32 {
+        unsafe { self.world.as_ref().saved_tick(saved) }
+    }
+
     /// Get raw pointer to a component column
     ///
     /// # Safety
@@ -1454,4 +2471,76 @@ impl<'a> UnsafeWorldCell<'a> {
     pub fn get_cached_query_indices<Q: crate::query::QueryFilter>(&self) -> Vec<usize> {
         unsafe { (&*self.world.as_ptr()).get_cached_query_indices::<Q>() }
     }
+
+    /// Restrict this cell to the resources `access` declares
+    ///
+    /// Debug builds then reject borrows of undeclared resources with
+    /// `EcsError::ResourceAccessError`.
+    pub fn with_access(self, access: &'a SystemAccess) -> Self {
+        Self {
+            access: Some(access),
+            ..self
+        }
+    }
+
+    /// Borrow a resource
+    ///
+    /// # Safety
+    /// Caller must have declared read access to `R`, and no one may hold
+    /// mutable access to it. Debug builds check both and return
+    /// `EcsError::ResourceAccessError` instead.
+    pub unsafe fn get_resource<R: 'static>(&self) -> Result<Res<'a, R>> {
+        #[cfg(debug_assertions)]
+        self.check_declared::<R>(false)?;
+        (*self.world.as_ptr()).resources.borrow()
+    }
+
+    /// Reserve an entity ID, see [`World::reserve_entity`]
+    pub fn reserve_entity(&self) -> EntityId {
+        unsafe { self.world.as_ref().reserve_entity() }
+    }
+
+    /// Entities that lost a `T` since `cursor` last read
+    ///
+    /// The removal logs are internally synchronized, and only change while
+    /// the world is exclusively borrowed.
+    pub fn read_removed<T: Component>(&self, cursor: &mut RemovedCursor<T>) -> Vec<EntityId> {
+        unsafe {
+            self.world
+                .as_ref()
+                .removed_components
+                .read(TypeId::of::<T>(), &mut cursor.position)
+        }
+    }
+
+    /// Mutably borrow a resource
+    ///
+    /// # Safety
+    /// Caller must have declared write access to `R` and guarantee exclusive
+    /// access to it across all threads using this cell. Debug builds check
+    /// both and return `EcsError::ResourceAccessError` instead.
+    pub unsafe fn get_resource_mut<R: 'static>(&self) -> Result<ResMut<'a, R>> {
+        #[cfg(debug_assertions)]
+        self.check_declared::<R>(true)?;
+        let world = &*self.world.as_ptr();
+        world.resources.borrow_mut(world.tick)
+    }
+
+    #[cfg(debug_assertions)]
+    fn check_declared<R: 'static>(&self, write: bool) -> Result<()> {
+        let Some(access) = self.access else {
+            return Ok(());
+        };
+        let id = crate::system::ComponentId::of::<R>();
+        let declared = access.writes.contains(&id) || (!write && access.reads.contains(&id));
+        if declared {
+            Ok(())
+        } else {
+            Err(EcsError::ResourceAccessError(format!(
+                "`{}` was not declared {} by the running system",
+                std::any::type_name::<R>(),
+                if write { "for writing" } else { "for reading" }
+            )))
+        }
+    }
 }
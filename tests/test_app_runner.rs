@@ -0,0 +1,86 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::runner::{run_frames, run_headless, run_loop, run_once};
+use std::time::{Duration, Instant};
+
+#[derive(Default)]
+struct Frames(u32);
+
+struct ExitAfter(u32);
+
+fn count_frames(mut frames: ResMut<Frames>) {
+    frames.0 += 1;
+}
+
+fn exit_after(frames: Res<Frames>, limit: Res<ExitAfter>, mut commands: Commands) {
+    if frames.0 >= limit.0 {
+        commands.add(|world| {
+            world.insert_resource(AppExit::Error(3));
+            Ok(())
+        });
+    }
+}
+
+fn app(limit: u32) -> App {
+    let mut app = App::new();
+    app.world.insert_resource(Frames::default());
+    app.world.insert_resource(ExitAfter(limit));
+    app.schedule.add_stage("count").unwrap();
+    app.schedule.add_stage("exit").unwrap();
+    app.schedule.add_stage_dependency("exit", "count").unwrap();
+    app.schedule
+        .add_system_to_stage("count", Box::new(count_frames.into_system()))
+        .unwrap();
+    app.schedule
+        .add_system_to_stage("exit", Box::new(exit_after.into_system()))
+        .unwrap();
+    app
+}
+
+fn frames(app: &App) -> u32 {
+    app.world.resource::<Frames>().unwrap().0
+}
+
+#[test]
+fn test_run_once() {
+    let mut app = app(10);
+    app.set_runner(Box::new(run_once));
+    assert_eq!(app.run().unwrap(), AppExit::Success);
+    assert_eq!(frames(&app), 1);
+    assert_eq!(app.world.resource::<Time>().unwrap().frame_count(), 1);
+}
+
+#[test]
+fn test_run_headless_until_exit() {
+    let mut app = app(5);
+    app.set_runner(Box::new(run_headless));
+    let exit = app.run().unwrap();
+    assert_eq!(exit, AppExit::Error(3));
+    assert_eq!(exit.code(), 3);
+    assert_eq!(frames(&app), 5);
+    // The request is consumed
+    assert!(app.take_exit().is_none());
+}
+
+#[test]
+fn test_run_frames_budget() {
+    let mut app = app(u32::MAX);
+    app.set_runner(run_frames(7));
+    assert_eq!(app.run().unwrap(), AppExit::Success);
+    assert_eq!(frames(&app), 7);
+
+    // The runner is kept for the next run
+    app.run().unwrap();
+    assert_eq!(frames(&app), 14);
+}
+
+#[test]
+fn test_run_loop_paces_frames() {
+    let mut app = app(4);
+    app.set_runner(run_loop(100));
+
+    let start = Instant::now();
+    assert_eq!(app.run().unwrap(), AppExit::Error(3));
+    assert_eq!(frames(&app), 4);
+    // Three sleeps between four frames at 10ms each
+    assert!(start.elapsed() >= Duration::from_millis(30));
+}
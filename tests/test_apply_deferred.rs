@@ -0,0 +1,150 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::EcsError;
+use std::any::type_name_of_val;
+
+#[derive(Debug, PartialEq)]
+struct Position(f32);
+
+#[derive(Default)]
+struct Seen(Vec<usize>);
+
+fn spawner(mut commands: Commands) {
+    commands.spawn((Position(0.0),));
+}
+
+fn counter(query: Query<&Position>, mut seen: ResMut<Seen>) {
+    seen.0.push(query.iter().count());
+}
+
+fn seen(world: &World) -> Vec<usize> {
+    world.resource::<Seen>().unwrap().0.clone()
+}
+
+fn world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Seen::default());
+    world
+}
+
+#[test]
+fn test_commands_deferred_to_end_of_stage() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(spawner.into_system()));
+    schedule.add_system(Box::new(counter.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(seen(&world), [0, 1]);
+}
+
+#[test]
+fn test_explicit_apply_deferred() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(spawner.into_system()));
+    schedule.add_apply_deferred();
+    schedule.add_system(Box::new(counter.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(seen(&world), [1, 2]);
+}
+
+#[test]
+fn test_apply_deferred_in_stage() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_stage("update").unwrap();
+    schedule
+        .add_system_to_stage("update", Box::new(spawner.into_system()))
+        .unwrap();
+    schedule.add_apply_deferred_to_stage("update").unwrap();
+    schedule
+        .add_system_to_stage("update", Box::new(counter.into_system()))
+        .unwrap();
+    assert!(matches!(
+        schedule.add_apply_deferred_to_stage("missing"),
+        Err(EcsError::ScheduleError(_))
+    ));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(seen(&world), [1]);
+}
+
+#[test]
+fn test_after_ordering_inserts_sync_point() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    // Added first, but ordered after the spawner
+    schedule.add_system_after(Box::new(counter.into_system()), type_name_of_val(&spawner));
+    schedule.add_system(Box::new(spawner.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(seen(&world), [1]);
+}
+
+#[test]
+fn test_sync_point_in_parallel_execution() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(spawner.into_system()));
+    schedule.add_system_after(Box::new(counter.into_system()), type_name_of_val(&spawner));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_parallel(&mut world).unwrap();
+    assert_eq!(seen(&world), [1]);
+}
+
+#[test]
+fn test_before_ordering_is_honored() {
+    #[derive(Default)]
+    struct Log(Vec<&'static str>);
+
+    fn first(mut log: ResMut<Log>) {
+        log.0.push("first");
+    }
+
+    fn second(mut log: ResMut<Log>) {
+        log.0.push("second");
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(second.into_system()));
+    schedule.add_system_before(Box::new(first.into_system()), type_name_of_val(&second));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(world.resource::<Log>().unwrap().0, ["first", "second"]);
+}
+
+#[test]
+fn test_ordering_cycle_detected() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system_after(Box::new(spawner.into_system()), type_name_of_val(&counter));
+    schedule.add_system_after(Box::new(counter.into_system()), type_name_of_val(&spawner));
+
+    let mut executor = Executor::new(&mut schedule);
+    let result = executor.execute_frame(&mut world);
+    assert!(matches!(result, Err(EcsError::SystemCycleDetected)));
+}
+
+#[test]
+fn test_event_executors_honor_plan() {
+    let mut world = world();
+    let mut schedule = Schedule::new();
+    schedule.add_system_after(Box::new(counter.into_system()), type_name_of_val(&spawner));
+    schedule.add_system(Box::new(spawner.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_with_events(&mut world).unwrap();
+    executor.execute_full(&mut world).unwrap();
+    assert_eq!(seen(&world), [1, 2]);
+}
@@ -0,0 +1,189 @@
+use archetype_ecs::{Bundle, EcsError, SpawnError, World};
+use std::any::TypeId;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position(f32, f32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Velocity(f32, f32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Health(u32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Mana(u32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stamina(u32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Armor(u32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Level(u32);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Experience(u64);
+#[derive(Debug, Clone, PartialEq)]
+struct Name(String);
+#[derive(Debug, Clone, PartialEq)]
+struct Inventory(Vec<u32>);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Gold(u64);
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Team(u8);
+
+#[derive(Bundle)]
+struct Motion {
+    position: Position,
+    velocity: Velocity,
+}
+
+#[derive(Bundle)]
+struct Vitals(Health, Mana, Stamina);
+
+#[derive(Bundle)]
+struct PlayerBundle {
+    #[bundle]
+    motion: Motion,
+    #[bundle]
+    vitals: Vitals,
+    armor: Armor,
+    level: Level,
+    experience: Experience,
+    name: Name,
+    inventory: Inventory,
+    gold: Gold,
+    team: Team,
+}
+
+fn player(name: &str, gold: u64) -> PlayerBundle {
+    PlayerBundle {
+        motion: Motion {
+            position: Position(1.0, 2.0),
+            velocity: Velocity(0.5, 0.0),
+        },
+        vitals: Vitals(Health(100), Mana(50), Stamina(75)),
+        armor: Armor(10),
+        level: Level(3),
+        experience: Experience(1200),
+        name: Name(name.to_string()),
+        inventory: Inventory(vec![1, 2, 3]),
+        gold: Gold(gold),
+        team: Team(1),
+    }
+}
+
+#[test]
+fn test_nested_bundle_is_flattened() {
+    let ids = PlayerBundle::type_ids();
+    assert_eq!(ids.len(), 12);
+    assert_eq!(PlayerBundle::component_count(), 12);
+    assert_eq!(ids[0], TypeId::of::<Position>());
+    assert_eq!(ids[2], TypeId::of::<Health>());
+    assert_eq!(ids[11], TypeId::of::<Team>());
+}
+
+#[test]
+fn test_spawn_large_bundle() {
+    let mut world = World::new();
+    let entity = world.spawn_entity(player("hero", 42));
+
+    assert_eq!(world.archetype_count(), 2); // empty archetype + player
+    assert_eq!(
+        world.get_component::<Position>(entity),
+        Some(&Position(1.0, 2.0))
+    );
+    assert_eq!(world.get_component::<Stamina>(entity), Some(&Stamina(75)));
+    assert_eq!(
+        world.get_component::<Name>(entity),
+        Some(&Name("hero".to_string()))
+    );
+    assert_eq!(
+        world.get_component::<Inventory>(entity),
+        Some(&Inventory(vec![1, 2, 3]))
+    );
+    assert_eq!(world.get_component::<Gold>(entity), Some(&Gold(42)));
+    assert_eq!(world.get_component::<Team>(entity), Some(&Team(1)));
+}
+
+#[test]
+fn test_spawn_batch_large_bundle() {
+    let mut world = World::new();
+    let entities = world
+        .spawn_batch((0..16usize).map(|i| player(&format!("p{i}"), i as u64)))
+        .unwrap();
+
+    assert_eq!(entities.len(), 16);
+    for (i, &entity) in entities.iter().enumerate() {
+        assert_eq!(world.get_component::<Gold>(entity), Some(&Gold(i as u64)));
+        assert_eq!(
+            world.get_component::<Name>(entity),
+            Some(&Name(format!("p{i}")))
+        );
+    }
+
+    world.despawn(entities[0]).unwrap();
+    assert_eq!(
+        world.get_component::<Name>(entities[1]),
+        Some(&Name("p1".to_string()))
+    );
+}
+
+#[derive(Bundle)]
+struct ManaPool {
+    mana: Mana,
+    #[bundle]
+    vitals: Vitals,
+}
+
+#[test]
+#[should_panic(expected = "contains the same component type twice")]
+fn test_nested_duplicate_type_panics() {
+    let mut world = World::new();
+    world.spawn_entity(ManaPool {
+        mana: Mana(1),
+        vitals: Vitals(Health(1), Mana(2), Stamina(3)),
+    });
+}
+
+#[test]
+#[should_panic(expected = "contains the same component type twice")]
+fn test_tuple_duplicate_type_panics() {
+    let mut world = World::new();
+    world.spawn_entity((Health(1), Health(2)));
+}
+
+#[test]
+fn test_try_spawn_duplicate_type_errors() {
+    let mut world = World::new();
+    let result = world.try_spawn_entity(ManaPool {
+        mana: Mana(1),
+        vitals: Vitals(Health(1), Mana(2), Stamina(3)),
+    });
+    assert!(matches!(
+        result,
+        Err(EcsError::SpawnError(SpawnError::DuplicateComponent { .. }))
+    ));
+
+    let result = world.spawn_batch(vec![(Health(1), Health(2))]);
+    assert!(matches!(
+        result,
+        Err(EcsError::SpawnError(SpawnError::DuplicateComponent { .. }))
+    ));
+    assert_eq!(world.entity_count(), 0);
+}
+
+type Hp = Health;
+
+#[derive(Bundle)]
+struct Aliased {
+    health: Health,
+    hp: Hp,
+}
+
+#[test]
+fn test_aliased_duplicate_type_errors_at_spawn() {
+    let mut world = World::new();
+    let result = world.try_spawn_entity(Aliased {
+        health: Health(1),
+        hp: Health(2),
+    });
+    assert!(matches!(
+        result,
+        Err(EcsError::SpawnError(SpawnError::DuplicateComponent { .. }))
+    ));
+}
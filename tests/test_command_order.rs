@@ -0,0 +1,140 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::system::SystemAccess;
+use archetype_ecs::ParallelExecutor;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Tag(usize);
+
+#[derive(Default)]
+struct Log(Vec<usize>);
+
+/// Queues a spawn and a log entry; later systems finish first
+struct Queue {
+    index: usize,
+    delay: Duration,
+}
+
+impl System for Queue {
+    fn run(&mut self, _world: &mut World, commands: &mut CommandBuffer) -> Result<()> {
+        std::thread::sleep(self.delay);
+        let index = self.index;
+        commands.spawn(move |world| {
+            world.spawn_entity((Tag(index),));
+            Ok(())
+        });
+        commands.add(move |world| {
+            world.resource_mut::<Log>().unwrap().0.push(index);
+            Ok(())
+        });
+        Ok(())
+    }
+
+    fn name(&self) -> &'static str {
+        "Queue"
+    }
+
+    fn accesses(&self) -> SystemAccess {
+        SystemAccess::new()
+    }
+}
+
+const SYSTEMS: usize = 8;
+
+fn queue_systems() -> Vec<Box<dyn System>> {
+    (0..SYSTEMS)
+        .map(|index| {
+            Box::new(Queue {
+                index,
+                delay: Duration::from_millis((SYSTEMS - index) as u64),
+            }) as Box<dyn System>
+        })
+        .collect()
+}
+
+fn new_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    world
+}
+
+fn spawned(world: &World) -> Vec<(EntityId, usize)> {
+    let mut spawned: Vec<_> = world
+        .query::<(Entity, &Tag)>()
+        .iter()
+        .map(|(entity, tag)| (entity, tag.0))
+        .collect();
+    spawned.sort_by_key(|&(_, tag)| tag);
+    spawned
+}
+
+fn run(parallel: bool, frames: usize) -> World {
+    let mut world = new_world();
+    let mut schedule = Schedule::new();
+    for system in queue_systems() {
+        schedule.add_system(system);
+    }
+    let mut executor = Executor::new(&mut schedule);
+    for _ in 0..frames {
+        if parallel {
+            executor.execute_frame_parallel(&mut world).unwrap();
+        } else {
+            executor.execute_frame(&mut world).unwrap();
+        }
+    }
+    world
+}
+
+#[test]
+fn test_parallel_commands_apply_in_schedule_order() {
+    let world = run(true, 1);
+    assert_eq!(
+        world.resource::<Log>().unwrap().0,
+        (0..SYSTEMS).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_parallel_and_sequential_worlds_match() {
+    let sequential = run(false, 5);
+    let parallel = run(true, 5);
+
+    assert_eq!(
+        sequential.resource::<Log>().unwrap().0,
+        parallel.resource::<Log>().unwrap().0
+    );
+    assert_eq!(spawned(&sequential), spawned(&parallel));
+}
+
+#[test]
+fn test_parallel_executor_commands_apply_in_stage_order() {
+    let mut world = new_world();
+    let mut executor = ParallelExecutor::new(queue_systems());
+    executor.execute_parallel(&mut world).unwrap();
+
+    assert_eq!(
+        world.resource::<Log>().unwrap().0,
+        (0..SYSTEMS).collect::<Vec<_>>()
+    );
+    assert_eq!(spawned(&world), spawned(&run(false, 1)));
+}
+
+#[test]
+fn test_event_executor_world_matches_sequential() {
+    let mut world = new_world();
+    let mut schedule = Schedule::new();
+    for system in queue_systems() {
+        schedule.add_system(system);
+    }
+    let mut executor = Executor::new(&mut schedule);
+    for _ in 0..5 {
+        executor.execute_frame_with_events(&mut world).unwrap();
+    }
+
+    let sequential = run(false, 5);
+    assert_eq!(
+        world.resource::<Log>().unwrap().0,
+        sequential.resource::<Log>().unwrap().0
+    );
+    assert_eq!(spawned(&world), spawned(&sequential));
+}
@@ -0,0 +1,148 @@
+use archetype_ecs::prelude::*;
+use std::collections::HashSet;
+
+#[derive(Debug, PartialEq)]
+struct Name(&'static str);
+
+#[derive(Debug, PartialEq)]
+struct Health(u32);
+
+#[derive(Default)]
+struct Spawned(Vec<EntityId>);
+
+fn run_once<M, S>(world: &mut World, system: S)
+where
+    S: IntoSystem<M>,
+    S::System: 'static,
+{
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(system.into_system()));
+    Executor::new(&mut schedule).execute_frame(world).unwrap();
+}
+
+#[test]
+fn test_spawn_returns_id_usable_in_same_frame() {
+    fn spawn(mut commands: Commands, mut spawned: ResMut<Spawned>) {
+        let entity = commands.spawn((Name("player"),)).id();
+        commands.entity(entity).insert(Health(10));
+        spawned.0.push(entity);
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Spawned::default());
+    run_once(&mut world, spawn);
+
+    let entity = world.resource::<Spawned>().unwrap().0[0];
+    assert!(world.is_alive(entity));
+    assert_eq!(world.get_component::<Name>(entity), Some(&Name("player")));
+    assert_eq!(world.get_component::<Health>(entity), Some(&Health(10)));
+}
+
+#[test]
+fn test_with_children_attaches_hierarchy() {
+    fn spawn(mut commands: Commands, mut spawned: ResMut<Spawned>) {
+        let mut ship = commands.spawn((Name("ship"),));
+        let ship_id = ship.id();
+        ship.with_children(|children| {
+            assert_eq!(children.parent_entity(), ship_id);
+            let left = children.spawn((Name("left"),)).id();
+            let right = children.spawn((Name("right"),)).id();
+            spawned.0.extend([left, right]);
+        });
+        spawned.0.insert(0, ship_id);
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Spawned::default());
+    run_once(&mut world, spawn);
+
+    let ids = world.resource::<Spawned>().unwrap().0.clone();
+    let (ship, left, right) = (ids[0], ids[1], ids[2]);
+    assert_eq!(world.get_component::<Parent>(left), Some(&Parent(ship)));
+    assert_eq!(world.get_component::<Parent>(right), Some(&Parent(ship)));
+    let children = world.get_component::<Children>(ship).unwrap();
+    assert_eq!(children.get_children(), vec![left, right]);
+}
+
+#[test]
+fn test_entity_commands_remove_and_despawn() {
+    let mut world = World::new();
+    let keep = world.spawn_entity((Name("keep"), Health(3)));
+    let gone = world.spawn_entity((Name("gone"),));
+    world.insert_resource(Spawned(vec![keep, gone]));
+
+    fn edit(mut commands: Commands, spawned: Res<Spawned>) {
+        commands.entity(spawned.0[0]).remove::<Health>();
+        commands.entity(spawned.0[1]).despawn();
+    }
+    run_once(&mut world, edit);
+
+    assert!(world.get_component::<Health>(keep).is_none());
+    assert_eq!(world.get_component::<Name>(keep), Some(&Name("keep")));
+    assert!(!world.is_alive(gone));
+}
+
+#[test]
+fn test_spawn_empty_then_insert() {
+    fn spawn(mut commands: Commands, mut spawned: ResMut<Spawned>) {
+        let entity = commands.spawn_empty().insert(Health(1)).id();
+        spawned.0.push(entity);
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Spawned::default());
+    run_once(&mut world, spawn);
+
+    let entity = world.resource::<Spawned>().unwrap().0[0];
+    assert_eq!(world.get_component::<Health>(entity), Some(&Health(1)));
+}
+
+#[test]
+fn test_reserved_entities_alive_after_flush() {
+    let mut world = World::new();
+    let old = world.spawn_entity((Health(1),));
+    world.despawn(old).unwrap();
+
+    let reserved = world.reserve_entity();
+    assert_ne!(reserved, old);
+    assert!(!world.is_alive(reserved));
+
+    world.flush_entities();
+    assert!(world.is_alive(reserved));
+    assert!(!world.is_alive(old));
+
+    world.add_component(reserved, Health(2)).unwrap();
+    assert_eq!(world.get_component::<Health>(reserved), Some(&Health(2)));
+}
+
+#[test]
+fn test_reserve_entity_from_many_threads() {
+    let mut world = World::new();
+    for _ in 0..10 {
+        let entity = world.spawn_entity((Health(0),));
+        world.despawn(entity).unwrap();
+    }
+
+    let reserved: Vec<EntityId> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let world = &world;
+                scope.spawn(move || (0..50).map(|_| world.reserve_entity()).collect::<Vec<_>>())
+            })
+            .collect();
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    });
+
+    let unique: HashSet<_> = reserved.iter().copied().collect();
+    assert_eq!(unique.len(), 200);
+
+    world.flush_entities();
+    assert!(reserved.iter().all(|&entity| world.is_alive(entity)));
+    assert_eq!(world.entity_count(), 200);
+
+    let spawned = world.spawn_entity((Health(1),));
+    assert!(!unique.contains(&spawned));
+}
@@ -0,0 +1,222 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{Changed, HierarchyBuilder, HookWorld, StorageType, View};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Cell(i32, i32);
+
+#[derive(Debug, PartialEq)]
+struct Health(u32);
+
+#[derive(Default)]
+struct Log(Vec<String>);
+
+/// Counts how many times it was dropped
+struct Tracked(Arc<AtomicUsize>);
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        self.0.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+/// Spatial index kept in sync with `Cell` components by hooks
+#[derive(Default)]
+struct Grid(HashMap<(i32, i32), Vec<EntityId>>);
+
+fn log(world: &mut HookWorld, entry: String) {
+    world.resource_mut::<Log>().unwrap().0.push(entry);
+}
+
+fn logged(world: &World) -> Vec<String> {
+    world.resource::<Log>().unwrap().0.clone()
+}
+
+/// World logging every `Health` hook with the value it saw
+fn logging_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    world
+        .register_component_hooks::<Health>()
+        .on_add(|world, _, health| log(world, format!("add {}", health.0)))
+        .on_insert(|world, _, health| log(world, format!("insert {}", health.0)))
+        .on_replace(|world, _, health| log(world, format!("replace {}", health.0)))
+        .on_remove(|world, _, health| log(world, format!("remove {}", health.0)));
+    world
+}
+
+fn unindex(world: &mut HookWorld, entity: EntityId, cell: &Cell) {
+    let mut grid = world.resource_mut::<Grid>().unwrap();
+    grid.0
+        .get_mut(&(cell.0, cell.1))
+        .unwrap()
+        .retain(|&e| e != entity);
+}
+
+fn grid_world() -> World {
+    let mut world = World::new();
+    world.insert_resource(Grid::default());
+    world
+        .register_component_hooks::<Cell>()
+        .on_insert(|world, entity, cell| {
+            let mut grid = world.resource_mut::<Grid>().unwrap();
+            grid.0.entry((cell.0, cell.1)).or_default().push(entity);
+        })
+        .on_replace(unindex)
+        .on_remove(unindex);
+    world
+}
+
+fn at(world: &World, x: i32, y: i32) -> Vec<EntityId> {
+    let grid = world.resource::<Grid>().unwrap();
+    grid.0.get(&(x, y)).cloned().unwrap_or_default()
+}
+
+#[test]
+fn test_spawn_runs_add_then_insert() {
+    let mut world = logging_world();
+    world.spawn_entity((Health(5),));
+    assert_eq!(logged(&world), ["add 5", "insert 5"]);
+}
+
+#[test]
+fn test_spawn_batch_runs_hooks_for_each_entity() {
+    let mut world = logging_world();
+    world.spawn_batch((1..4).map(|hp| (Health(hp),))).unwrap();
+    assert_eq!(
+        logged(&world),
+        ["add 1", "insert 1", "add 2", "insert 2", "add 3", "insert 3"]
+    );
+}
+
+#[test]
+fn test_overwrite_runs_replace_then_insert() {
+    let mut world = logging_world();
+    let entity = world.spawn_entity((Cell(0, 0),));
+    world.add_component(entity, Health(1)).unwrap();
+    world.add_component(entity, Health(2)).unwrap();
+    assert_eq!(
+        logged(&world),
+        ["add 1", "insert 1", "replace 1", "insert 2"]
+    );
+}
+
+#[test]
+fn test_overwrite_drops_old_value_and_marks_changed() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+    let entity = world.spawn_entity((Tracked(drops.clone()),));
+    let tick = world.tick();
+    world.increment_tick();
+
+    world.add_component(entity, Tracked(drops.clone())).unwrap();
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    let changed = View::<(&Tracked, Changed<Tracked>)>::new(&world, tick);
+    assert_eq!(changed.iter().count(), 1);
+
+    drop(world);
+    assert_eq!(drops.load(Ordering::SeqCst), 2);
+}
+
+#[test]
+fn test_despawn_drops_table_components() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new();
+    let a = world.spawn_entity((Tracked(drops.clone()), Cell(0, 0)));
+    let b = world.spawn_entity((Tracked(drops.clone()), Cell(1, 1)));
+    let c = world.spawn_entity((Tracked(drops.clone()),));
+    HierarchyBuilder::attach(&mut world, c, a).unwrap();
+
+    world.despawn(b).unwrap();
+    assert_eq!(drops.load(Ordering::SeqCst), 1);
+    world.despawn_recursive(c).unwrap();
+    assert_eq!(drops.load(Ordering::SeqCst), 3);
+
+    let d = world.spawn_entity((Tracked(drops.clone()),));
+    world.despawn_deferred(d).unwrap();
+    world.flush_removals().unwrap();
+    assert_eq!(drops.load(Ordering::SeqCst), 4);
+    drop(world);
+    assert_eq!(drops.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn test_remove_sees_value_before_drop() {
+    let mut world = logging_world();
+    let entity = world.spawn_entity((Health(7), Cell(0, 0)));
+    world.remove_component::<Health>(entity).unwrap();
+    assert!(world.get_component::<Health>(entity).is_none());
+
+    // Removing a missing component runs nothing
+    assert!(world.remove_component::<Health>(entity).is_err());
+    assert_eq!(logged(&world), ["add 7", "insert 7", "remove 7"]);
+}
+
+#[test]
+fn test_despawn_and_clear_run_remove_hooks() {
+    let mut world = logging_world();
+    let entity = world.spawn_entity((Health(1),));
+    world.spawn_entity((Health(2), Cell(1, 1)));
+    world.despawn(entity).unwrap();
+    world.clear();
+    assert_eq!(
+        logged(&world),
+        ["add 1", "insert 1", "add 2", "insert 2", "remove 1", "remove 2"]
+    );
+}
+
+#[test]
+fn test_sparse_components_run_hooks() {
+    let mut world = logging_world();
+    world
+        .register_storage::<Health>(StorageType::SparseSet)
+        .unwrap();
+    let entity = world.spawn_entity((Cell(0, 0),));
+    world.add_component(entity, Health(1)).unwrap();
+    world.add_component(entity, Health(2)).unwrap();
+    world.despawn(entity).unwrap();
+    assert_eq!(
+        logged(&world),
+        ["add 1", "insert 1", "replace 1", "insert 2", "remove 2"]
+    );
+}
+
+#[test]
+fn test_commands_run_hooks_when_applied() {
+    fn spawn(mut commands: Commands) {
+        let entity = commands.spawn((Health(3),)).id();
+        commands.entity(entity).remove::<Health>();
+    }
+
+    let mut world = logging_world();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(spawn.into_system()));
+    Executor::new(&mut schedule)
+        .execute_frame(&mut world)
+        .unwrap();
+    assert_eq!(logged(&world), ["add 3", "insert 3", "remove 3"]);
+}
+
+#[test]
+fn test_hooks_maintain_spatial_index() {
+    let mut world = grid_world();
+    let a = world.spawn_entity((Cell(0, 0),));
+    let b = world.spawn_entity((Cell(0, 0), Health(1)));
+    assert_eq!(at(&world, 0, 0), [a, b]);
+
+    // Moving an entity is a remove followed by an insert
+    world.remove_component::<Cell>(a).unwrap();
+    world.add_component(a, Cell(2, 3)).unwrap();
+    assert_eq!(at(&world, 0, 0), [b]);
+    assert_eq!(at(&world, 2, 3), [a]);
+
+    // Overwriting in place unindexes the old value first
+    world.add_component(b, Cell(5, 5)).unwrap();
+    assert!(at(&world, 0, 0).is_empty());
+    assert_eq!(at(&world, 5, 5), [b]);
+
+    world.despawn(b).unwrap();
+    assert!(at(&world, 5, 5).is_empty());
+}
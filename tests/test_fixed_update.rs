@@ -0,0 +1,123 @@
+use archetype_ecs::prelude::*;
+use std::time::Duration;
+
+#[derive(Default)]
+struct Steps(u32);
+
+#[derive(Default)]
+struct Frames(u32);
+
+#[derive(Default)]
+struct Alpha(f32);
+
+fn physics(mut steps: ResMut<Steps>) {
+    steps.0 += 1;
+}
+
+fn render(mut frames: ResMut<Frames>, mut alpha: ResMut<Alpha>, fixed: Res<FixedTime>) {
+    frames.0 += 1;
+    alpha.0 = fixed.overstep_fraction();
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.world.insert_resource(Steps::default());
+    app.world.insert_resource(Frames::default());
+    app.world.insert_resource(Alpha::default());
+    app.set_fixed_timestep(Duration::from_millis(10))
+        .add_fixed_system(Box::new(physics.into_system()))
+        .add_system(Box::new(render.into_system()));
+    app
+}
+
+#[test]
+fn test_fixed_steps_per_frame() {
+    let mut app = app();
+
+    app.update_with_delta(Duration::from_millis(35)).unwrap();
+    assert_eq!(app.world.resource::<Steps>().unwrap().0, 3);
+    assert!((app.world.resource::<Alpha>().unwrap().0 - 0.5).abs() < 1e-3);
+
+    // Overstep carries into the next frame
+    app.update_with_delta(Duration::from_millis(6)).unwrap();
+    assert_eq!(app.world.resource::<Steps>().unwrap().0, 4);
+
+    app.update_with_delta(Duration::from_millis(2)).unwrap();
+    assert_eq!(app.world.resource::<Steps>().unwrap().0, 4);
+    assert_eq!(app.world.resource::<Frames>().unwrap().0, 3);
+    assert_eq!(
+        app.world.resource::<Time>().unwrap().elapsed(),
+        Duration::from_millis(43)
+    );
+}
+
+#[test]
+fn test_paused_time_stops_fixed_steps() {
+    let mut app = app();
+    app.world.resource_mut::<Time>().unwrap().pause();
+
+    app.update_with_delta(Duration::from_millis(50)).unwrap();
+    assert_eq!(app.world.resource::<Steps>().unwrap().0, 0);
+    assert_eq!(app.world.resource::<Frames>().unwrap().0, 1);
+}
+
+#[test]
+fn test_update_uses_wall_clock() {
+    let mut app = app();
+    app.update().unwrap();
+    std::thread::sleep(Duration::from_millis(25));
+    app.update().unwrap();
+
+    assert!(app.world.resource::<Steps>().unwrap().0 >= 2);
+    assert_eq!(app.world.resource::<Time>().unwrap().frame_count(), 2);
+}
+
+#[derive(Default)]
+struct Deltas(Vec<Duration>);
+
+fn record_delta(time: Res<Time>, mut deltas: ResMut<Deltas>) {
+    deltas.0.push(time.delta());
+}
+
+#[test]
+fn test_fixed_systems_read_fixed_delta() {
+    let mut app = app();
+    app.world.insert_resource(Deltas::default());
+    app.add_fixed_system(Box::new(record_delta.into_system()));
+
+    app.update_with_delta(Duration::from_millis(25)).unwrap();
+    assert_eq!(
+        app.world.resource::<Deltas>().unwrap().0,
+        [Duration::from_millis(10); 2]
+    );
+    // The main schedule still sees the frame delta
+    assert_eq!(
+        app.world.resource::<Time>().unwrap().delta(),
+        Duration::from_millis(25)
+    );
+}
+
+#[test]
+fn test_stall_caps_fixed_steps() {
+    let mut app = app();
+    app.set_max_fixed_steps(5);
+
+    app.update_with_delta(Duration::from_secs(5)).unwrap();
+    assert_eq!(app.world.resource::<Steps>().unwrap().0, 5);
+
+    // The excess is dropped rather than replayed next frame
+    app.update_with_delta(Duration::from_millis(10)).unwrap();
+    assert_eq!(app.world.resource::<Steps>().unwrap().0, 6);
+}
+
+#[test]
+#[should_panic(expected = "fixed timestep must be non-zero")]
+fn test_zero_timestep_rejected() {
+    App::new().set_fixed_timestep(Duration::ZERO);
+}
+
+#[test]
+#[should_panic(expected = "max fixed steps must be non-zero")]
+fn test_zero_max_steps_rejected() {
+    App::new().set_max_fixed_steps(0);
+}
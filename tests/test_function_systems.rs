@@ -0,0 +1,171 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{Changed, ComponentId, EcsError};
+
+#[derive(Debug, PartialEq)]
+struct Position(f32);
+
+#[derive(Debug, PartialEq)]
+struct Velocity(f32);
+
+struct Gravity(f32);
+
+#[derive(Default)]
+struct Score(u32);
+
+fn movement(
+    mut query: Query<(&mut Position, &Velocity)>,
+    gravity: Res<Gravity>,
+    mut score: ResMut<Score>,
+    mut commands: Commands,
+) {
+    for (pos, vel) in query.iter_mut() {
+        pos.0 += vel.0 - gravity.0;
+        score.0 += 1;
+    }
+    commands.spawn((Position(100.0),));
+}
+
+#[test]
+fn test_function_system_access_from_signature() {
+    let system = movement.into_system();
+    let access = system.accesses();
+
+    assert!(access.writes.contains(&ComponentId::of::<Position>()));
+    assert!(access.writes.contains(&ComponentId::of::<Score>()));
+    assert!(access.reads.contains(&ComponentId::of::<Velocity>()));
+    assert!(access.reads.contains(&ComponentId::of::<Gravity>()));
+    assert!(!access.writes.contains(&ComponentId::of::<Velocity>()));
+    assert!(system.name().ends_with("movement"));
+}
+
+#[test]
+fn test_function_system_runs_in_schedule() {
+    let mut world = World::new();
+    world.insert_resource(Gravity(1.0));
+    world.insert_resource(Score::default());
+    let entity = world.spawn_entity((Position(0.0), Velocity(3.0)));
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(movement.into_system()));
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+
+    assert_eq!(
+        world.get_component::<Position>(entity),
+        Some(&Position(2.0))
+    );
+    assert_eq!(world.resource::<Score>().unwrap().0, 1);
+    // Spawned by the queued command
+    assert_eq!(world.query::<&Position>().iter().count(), 2);
+}
+
+#[test]
+fn test_function_system_missing_resource() {
+    let mut world = World::new();
+    world.insert_resource(Score::default());
+
+    let mut system = movement.into_system();
+    let mut commands = CommandBuffer::new();
+    let result = system.run(&mut world, &mut commands);
+    assert!(matches!(result, Err(EcsError::ResourceNotFound(_))));
+}
+
+#[test]
+#[should_panic(expected = "conflicts with an earlier parameter")]
+fn test_function_system_conflicting_params() {
+    fn aliasing(_a: Query<&mut Position>, _b: Query<&Position>) {}
+    let _ = aliasing.into_system();
+}
+
+#[test]
+fn test_function_systems_dependency_access() {
+    fn reader(_q: Query<&Position>) {}
+    fn other_reader(_q: Query<(&Position, &Velocity)>) {}
+    fn writer(_q: Query<&mut Position>) -> Result<()> {
+        Ok(())
+    }
+
+    let reader = reader.into_system().accesses();
+    let other_reader = other_reader.into_system().accesses();
+    let writer = writer.into_system().accesses();
+
+    assert!(reader.can_run_parallel(&other_reader));
+    assert!(reader.conflicts_with(&writer));
+}
+
+#[test]
+fn test_function_system_changed_since_last_run() {
+    #[derive(Default)]
+    struct Seen(usize);
+
+    fn count_changed(query: Query<(&Position, Changed<Position>)>, mut seen: ResMut<Seen>) {
+        seen.0 = query.iter().count();
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Seen::default());
+    let a = world.spawn_entity((Position(0.0),));
+    world.spawn_entity((Position(1.0),));
+
+    let mut system = count_changed.into_system();
+    let mut commands = CommandBuffer::new();
+
+    world.increment_tick();
+    system.run(&mut world, &mut commands).unwrap();
+    assert_eq!(world.resource::<Seen>().unwrap().0, 2);
+
+    world.increment_tick();
+    world.get_component_mut::<Position>(a).unwrap().0 = 5.0;
+    system.run(&mut world, &mut commands).unwrap();
+    assert_eq!(world.resource::<Seen>().unwrap().0, 1);
+}
+
+#[test]
+fn test_function_system_wide_query() {
+    struct A(u32);
+    struct B(u32);
+    struct C(u32);
+    struct D(u32);
+    struct E(u32);
+    struct F(u32);
+    struct G(u32);
+
+    #[allow(clippy::type_complexity)]
+    fn sum(
+        mut query: Query<(
+            &mut Position,
+            &A,
+            &B,
+            &C,
+            &D,
+            &E,
+            Option<&F>,
+            Option<&mut G>,
+        )>,
+    ) {
+        for (pos, a, b, c, d, e, f, g) in query.iter_mut() {
+            pos.0 = (a.0 + b.0 + c.0 + d.0 + e.0 + f.map_or(0, |f| f.0)) as f32;
+            if let Some(g) = g {
+                g.0 += 1;
+            }
+        }
+    }
+
+    let mut world = World::new();
+    let entity = world.spawn_entity((Position(0.0), A(1), B(2), C(3), D(4), E(5), F(6), G(0)));
+
+    let mut system = sum.into_system();
+    let access = system.accesses();
+    assert!(access.writes.contains(&ComponentId::of::<Position>()));
+    assert!(access.writes.contains(&ComponentId::of::<G>()));
+    assert!(access.reads.contains(&ComponentId::of::<E>()));
+    assert!(access.reads.contains(&ComponentId::of::<F>()));
+
+    let mut commands = CommandBuffer::new();
+    system.run(&mut world, &mut commands).unwrap();
+    assert_eq!(
+        world.get_component::<Position>(entity),
+        Some(&Position(21.0))
+    );
+    assert_eq!(world.get_component::<G>(entity).map(|g| g.0), Some(1));
+}
@@ -0,0 +1,98 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::Without;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Velocity {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Frozen;
+
+#[test]
+fn test_optional_read() {
+    let mut world = World::new();
+    let moving = world.spawn_entity((Position { x: 0.0, y: 0.0 }, Velocity { x: 1.0, y: 2.0 }));
+    let still = world.spawn_entity((Position { x: 5.0, y: 5.0 },));
+    world.spawn_entity((Velocity { x: 9.0, y: 9.0 },));
+
+    let mut rows: Vec<_> = world
+        .query::<(Entity, &Position, Option<&Velocity>)>()
+        .iter()
+        .map(|(e, _, vel)| (e, vel.copied()))
+        .collect();
+    rows.sort_by_key(|(e, _)| *e);
+
+    let mut expected = vec![(moving, Some(Velocity { x: 1.0, y: 2.0 })), (still, None)];
+    expected.sort_by_key(|(e, _)| *e);
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_optional_write() {
+    let mut world = World::new();
+    let moving = world.spawn_entity((Position { x: 0.0, y: 0.0 }, Velocity { x: 1.0, y: 2.0 }));
+    let still = world.spawn_entity((Position { x: 5.0, y: 5.0 },));
+
+    for (pos, vel) in world
+        .query_mut::<(&mut Position, Option<&mut Velocity>)>()
+        .iter()
+    {
+        match vel {
+            Some(vel) => {
+                pos.x += vel.x;
+                pos.y += vel.y;
+                vel.x = 0.0;
+            }
+            None => pos.x = -1.0,
+        }
+    }
+
+    assert_eq!(
+        world.get_component::<Position>(moving),
+        Some(&Position { x: 1.0, y: 2.0 })
+    );
+    assert_eq!(
+        world.get_component::<Velocity>(moving),
+        Some(&Velocity { x: 0.0, y: 2.0 })
+    );
+    assert_eq!(
+        world.get_component::<Position>(still),
+        Some(&Position { x: -1.0, y: 5.0 })
+    );
+}
+
+#[test]
+fn test_optional_with_filters() {
+    let mut world = World::new();
+    world.spawn_entity((Position { x: 0.0, y: 0.0 }, Velocity { x: 1.0, y: 0.0 }));
+    world.spawn_entity((Position { x: 0.0, y: 0.0 }, Frozen));
+    world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+
+    let count = world
+        .query::<(&Position, Option<&Velocity>, Without<Frozen>)>()
+        .iter()
+        .count();
+    assert_eq!(count, 2);
+
+    // Optional fetches do not change the signature, so archetypes created
+    // after the first run are still picked up by the cached result
+    world.spawn_entity((
+        Position { x: 0.0, y: 0.0 },
+        Velocity { x: 0.0, y: 0.0 },
+        1u32,
+    ));
+    let with_velocity = world
+        .query::<(&Position, Option<&Velocity>, Without<Frozen>)>()
+        .iter()
+        .filter(|(_, vel, _)| vel.is_some())
+        .count();
+    assert_eq!(with_velocity, 2);
+}
@@ -0,0 +1,139 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{AnyOf, Changed, Or, With, Without};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Health(u32);
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Enemy;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Boss;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Shield(u32);
+
+#[test]
+fn test_or_filter() {
+    let mut world = World::new();
+    let enemy = world.spawn_entity((Health(10), Enemy));
+    let boss = world.spawn_entity((Health(500), Boss));
+    let both = world.spawn_entity((Health(900), Enemy, Boss));
+    world.spawn_entity((Health(100),));
+
+    let mut hits: Vec<_> = world
+        .query::<(Entity, &Health, Or<(With<Enemy>, With<Boss>)>)>()
+        .iter()
+        .map(|(e, _, _)| e)
+        .collect();
+    hits.sort();
+
+    let mut expected = vec![enemy, boss, both];
+    expected.sort();
+    assert_eq!(hits, expected);
+
+    let not_both = world
+        .query::<(&Health, Or<(Without<Enemy>, Without<Boss>)>)>()
+        .iter()
+        .count();
+    assert_eq!(not_both, 3);
+}
+
+#[test]
+fn test_or_picks_up_new_archetypes() {
+    let mut world = World::new();
+    world.spawn_entity((Health(10), Enemy));
+
+    let count = world
+        .query::<(&Health, Or<(With<Enemy>, With<Boss>)>)>()
+        .iter()
+        .count();
+    assert_eq!(count, 1);
+
+    // Cached result is updated incrementally for archetypes created later
+    world.spawn_entity((Health(500), Boss));
+    world.spawn_entity((Health(1), Shield(2)));
+    let count = world
+        .query::<(&Health, Or<(With<Enemy>, With<Boss>)>)>()
+        .iter()
+        .count();
+    assert_eq!(count, 2);
+}
+
+#[test]
+fn test_or_changed() {
+    let mut world = World::new();
+    let a = world.spawn_entity((Health(1), Shield(1)));
+    let b = world.spawn_entity((Health(2), Shield(2)));
+    world.spawn_entity((Health(3), Shield(3)));
+
+    let tick = world.tick();
+    world.increment_tick();
+    if let Some(health) = world.get_component_mut::<Health>(a) {
+        health.0 += 1;
+    }
+    if let Some(shield) = world.get_component_mut::<Shield>(b) {
+        shield.0 += 1;
+    }
+
+    let mut changed: Vec<_> = world
+        .query_mut::<(Entity, Or<(Changed<Health>, Changed<Shield>)>)>()
+        .iter_since(tick)
+        .map(|(e, _)| e)
+        .collect();
+    changed.sort();
+
+    let mut expected = vec![a, b];
+    expected.sort();
+    assert_eq!(changed, expected);
+}
+
+#[test]
+fn test_any_of_fetch() {
+    let mut world = World::new();
+    let health_only = world.spawn_entity((Health(10),));
+    let shield_only = world.spawn_entity((Shield(5),));
+    let both = world.spawn_entity((Health(20), Shield(7)));
+    world.spawn_entity((Enemy,));
+
+    let mut rows: Vec<_> = world
+        .query::<(Entity, AnyOf<(&Health, &Shield)>)>()
+        .iter()
+        .map(|(e, (h, s))| (e, h.copied(), s.copied()))
+        .collect();
+    rows.sort_by_key(|(e, _, _)| *e);
+
+    let mut expected = vec![
+        (health_only, Some(Health(10)), None),
+        (shield_only, None, Some(Shield(5))),
+        (both, Some(Health(20)), Some(Shield(7))),
+    ];
+    expected.sort_by_key(|(e, _, _)| *e);
+    assert_eq!(rows, expected);
+}
+
+#[test]
+fn test_any_of_mut() {
+    let mut world = World::new();
+    let health_only = world.spawn_entity((Health(10),));
+    let both = world.spawn_entity((Health(20), Shield(7)));
+
+    for (health, shield) in world
+        .query_mut::<AnyOf<(&mut Health, &mut Shield)>>()
+        .iter()
+    {
+        if let Some(health) = health {
+            health.0 *= 2;
+        }
+        if let Some(shield) = shield {
+            shield.0 = 0;
+        }
+    }
+
+    assert_eq!(
+        world.get_component::<Health>(health_only),
+        Some(&Health(20))
+    );
+    assert_eq!(world.get_component::<Health>(both), Some(&Health(40)));
+    assert_eq!(world.get_component::<Shield>(both), Some(&Shield(0)));
+}
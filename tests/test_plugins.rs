@@ -0,0 +1,164 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{EcsError, PluginGroup, PluginGroupBuilder, PluginsState};
+
+#[derive(Default)]
+struct Log(Vec<String>);
+
+fn push(app: &mut App, entry: String) {
+    app.world.get_or_insert_with(Log::default).0.push(entry);
+}
+
+struct Named {
+    name: &'static str,
+    deps: Vec<&'static str>,
+    unique: bool,
+}
+
+impl Named {
+    fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            deps: Vec::new(),
+            unique: true,
+        }
+    }
+
+    fn after(mut self, dep: &'static str) -> Self {
+        self.deps.push(dep);
+        self
+    }
+}
+
+impl Plugin for Named {
+    fn plugin_name(&self) -> &'static str {
+        self.name
+    }
+
+    fn build(&self, app: &mut App) {
+        push(app, format!("build {}", self.name));
+    }
+
+    fn dependencies(&self) -> Vec<&'static str> {
+        self.deps.clone()
+    }
+
+    fn is_unique(&self) -> bool {
+        self.unique
+    }
+
+    fn finish(&self, app: &mut App) {
+        assert!(app.is_plugin_added(self.name));
+        push(app, format!("finish {}", self.name));
+    }
+
+    fn cleanup(&self, app: &mut App) {
+        push(app, format!("cleanup {}", self.name));
+    }
+}
+
+fn log(app: &App) -> Vec<String> {
+    app.world.resource::<Log>().unwrap().0.clone()
+}
+
+#[test]
+fn test_duplicate_plugin_rejected() {
+    let mut app = App::new();
+    app.add_plugin(Named::new("Render"));
+
+    let result = app.try_add_plugin(Named::new("Render"));
+    assert!(matches!(result, Err(EcsError::PluginError(_))));
+
+    let mut shared = Named::new("Logger");
+    shared.unique = false;
+    app.add_plugin(shared);
+    let mut again = Named::new("Logger");
+    again.unique = false;
+    app.add_plugin(again);
+    assert_eq!(log(&app).len(), 3);
+}
+
+#[test]
+#[should_panic(expected = "already added")]
+fn test_duplicate_plugin_panics() {
+    let mut app = App::new();
+    app.add_plugin(Named::new("Render"));
+    app.add_plugin(Named::new("Render"));
+}
+
+#[test]
+fn test_missing_dependency_rejected() {
+    let mut app = App::new();
+    let result = app.try_add_plugin(Named::new("Physics").after("Time").after("Transform"));
+    match result {
+        Err(EcsError::PluginError(msg)) => {
+            assert!(msg.contains("Time, Transform"), "{msg}");
+        }
+        _ => panic!("expected a plugin error"),
+    }
+    assert!(!app.is_plugin_added("Physics"));
+
+    app.add_plugin(Named::new("Time"))
+        .add_plugin(Named::new("Transform"))
+        .add_plugin(Named::new("Physics").after("Time").after("Transform"));
+    assert!(app.is_plugin_added("Physics"));
+}
+
+#[test]
+fn test_lifecycle_hooks_run_once_in_order() {
+    let mut app = App::new();
+    app.add_plugin(Named::new("A"))
+        .add_plugin(Named::new("B").after("A"));
+    assert_eq!(app.plugins_state(), PluginsState::Adding);
+
+    app.finish_plugins();
+    app.cleanup_plugins();
+    app.cleanup_plugins();
+    assert_eq!(app.plugins_state(), PluginsState::Cleaned);
+    assert_eq!(
+        log(&app),
+        [
+            "build A",
+            "build B",
+            "finish A",
+            "finish B",
+            "cleanup A",
+            "cleanup B"
+        ]
+    );
+
+    let late = app.try_add_plugin(Named::new("C"));
+    assert!(matches!(late, Err(EcsError::PluginError(_))));
+}
+
+#[test]
+fn test_run_finishes_plugins() {
+    let mut app = App::new();
+    app.add_plugin(Named::new("A"))
+        .set_runner(Box::new(archetype_ecs::runner::run_once));
+    app.run().unwrap();
+    assert_eq!(log(&app), ["build A", "finish A", "cleanup A"]);
+}
+
+struct CorePlugins;
+
+impl PluginGroup for CorePlugins {
+    fn build(self) -> PluginGroupBuilder {
+        PluginGroupBuilder::new()
+            .with_plugin(Named::new("Time"))
+            .with_plugin(Named::new("Input"))
+            .with_plugin(Named::new("Physics").after("Time"))
+    }
+}
+
+#[test]
+fn test_plugin_group() {
+    let group = CorePlugins.build().disable("Input");
+    assert_eq!(group.names(), ["Time", "Physics"]);
+
+    let mut app = App::new();
+    app.add_plugins(group);
+    assert!(app.is_plugin_added("Time"));
+    assert!(app.is_plugin_added("Physics"));
+    assert!(!app.is_plugin_added("Input"));
+    assert_eq!(log(&app), ["build Time", "build Physics"]);
+}
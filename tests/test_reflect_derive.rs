@@ -0,0 +1,173 @@
+use archetype_ecs::{
+    reflection::{Reflect, TypeRegistry},
+    LocalTransform, Vec3, World,
+};
+use std::any::TypeId;
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+struct Stats {
+    health: f32,
+    level: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+struct Player {
+    name: String,
+    stats: Stats,
+    #[reflect(ignore)]
+    inventory: Vec<u32>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Reflect)]
+struct Pair(i32, i32);
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+enum Shape {
+    #[default]
+    Empty,
+    Circle {
+        radius: f32,
+    },
+    Rect(f32, f32),
+}
+
+#[test]
+fn test_struct_field_access() {
+    let mut player = Player {
+        name: "hero".to_string(),
+        stats: Stats {
+            health: 100.0,
+            level: 3,
+        },
+        inventory: vec![1, 2, 3],
+    };
+
+    // Ignored fields are not reflected
+    assert_eq!(player.field_count(), 2);
+    assert_eq!(player.field_name(0), Some("name"));
+    assert_eq!(player.field_name(1), Some("stats"));
+    assert!(player.field_by_name("inventory").is_none());
+
+    let name = player.field_at(0).unwrap();
+    assert_eq!(name.as_any().downcast_ref::<String>().unwrap(), "hero");
+
+    // Nested structs are reflected through their own implementation
+    let stats = player.field_by_name("stats").unwrap();
+    let level = stats.field_by_name("level").unwrap();
+    assert_eq!(level.as_any().downcast_ref::<u32>(), Some(&3));
+
+    let health = player
+        .field_by_name_mut("stats")
+        .and_then(|stats| stats.field_by_name_mut("health"))
+        .unwrap();
+    health.apply(&50.0f32);
+    assert_eq!(player.stats.health, 50.0);
+}
+
+#[test]
+fn test_tuple_struct_field_access() {
+    let mut pair = Pair(1, 2);
+    assert_eq!(pair.field_count(), 2);
+    assert_eq!(pair.field_name(1), Some("1"));
+
+    pair.field_by_name_mut("1").unwrap().apply(&7i32);
+    assert_eq!(pair, Pair(1, 7));
+}
+
+#[test]
+fn test_enum_variant_access() {
+    let mut shape = Shape::Circle { radius: 2.0 };
+    assert_eq!(shape.variant_name(), Some("Circle"));
+    assert_eq!(shape.variant_index(), Some(1));
+    assert_eq!(shape.field_count(), 1);
+    assert_eq!(shape.field_name(0), Some("radius"));
+
+    shape.field_by_name_mut("radius").unwrap().apply(&4.0f32);
+    assert_eq!(shape, Shape::Circle { radius: 4.0 });
+
+    let rect = Shape::Rect(1.0, 2.0);
+    assert_eq!(rect.variant_name(), Some("Rect"));
+    assert_eq!(rect.field_count(), 2);
+    let height = rect.field_at(1).unwrap();
+    assert_eq!(height.as_any().downcast_ref::<f32>(), Some(&2.0));
+    assert!(rect.field_by_name("radius").is_none());
+
+    assert_eq!(Shape::Empty.field_count(), 0);
+}
+
+#[test]
+fn test_registry_records_field_names() {
+    let mut registry = TypeRegistry::new();
+    registry.register::<Player>();
+    registry.register::<LocalTransform>();
+
+    let player = registry.get(TypeId::of::<Player>()).unwrap();
+    assert_eq!(player.field_names, vec!["name", "stats"]);
+
+    let transform = registry.get(TypeId::of::<LocalTransform>()).unwrap();
+    assert_eq!(transform.field_names, vec!["position", "rotation", "scale"]);
+}
+
+/// Field names owned by the value, which only `field_name` can return
+#[derive(Clone)]
+struct Columns {
+    names: Vec<String>,
+}
+
+impl Reflect for Columns {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+
+    fn as_any_mut(&mut self) -> &mut dyn std::any::Any {
+        self
+    }
+
+    fn apply(&mut self, value: &dyn Reflect) {
+        if let Some(value) = value.as_any().downcast_ref::<Self>() {
+            *self = value.clone();
+        }
+    }
+
+    fn reflect_clone(&self) -> Box<dyn Reflect> {
+        Box::new(self.clone())
+    }
+
+    fn field_count(&self) -> usize {
+        self.names.len()
+    }
+
+    fn field_name(&self, index: usize) -> Option<&str> {
+        self.names.get(index).map(String::as_str)
+    }
+}
+
+#[test]
+fn test_field_names_borrowed_from_value() {
+    let columns = Columns {
+        names: vec!["a".to_string(), "b".to_string()],
+    };
+    assert_eq!(columns.field_name(1), Some("b"));
+    assert_eq!(columns.static_field_name(1), None);
+
+    let pair = Pair(1, 2);
+    assert_eq!(pair.static_field_name(0), Some("0"));
+}
+
+#[test]
+fn test_patch_component_field() {
+    let mut world = World::new();
+    let entity = world.spawn_entity((LocalTransform::identity(),));
+
+    {
+        let transform = world.get_component_mut::<LocalTransform>(entity).unwrap();
+        let x = transform
+            .field_by_name_mut("position")
+            .and_then(|position| position.field_by_name_mut("x"))
+            .unwrap();
+        x.apply(&5.0f32);
+    }
+
+    let transform = world.get_component::<LocalTransform>(entity).unwrap();
+    assert_eq!(transform.position, Vec3::new(5.0, 0.0, 0.0));
+}
@@ -0,0 +1,118 @@
+use archetype_ecs::{
+    reflection::{parse_path, PathSegment, Reflect, ReflectPath, ReflectValue},
+    EcsError, LocalTransform, Vec3, World,
+};
+use std::any::TypeId;
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+struct Item {
+    name: String,
+    count: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+struct Inventory {
+    items: Vec<Item>,
+    gold: usize,
+}
+
+fn inventory() -> Inventory {
+    Inventory {
+        items: (0..3)
+            .map(|i| Item {
+                name: format!("item{i}"),
+                count: i,
+            })
+            .collect(),
+        gold: 10,
+    }
+}
+
+#[test]
+fn test_parse_path() {
+    assert_eq!(
+        parse_path("items[2].count").unwrap(),
+        vec![
+            PathSegment::Field("items"),
+            PathSegment::Index(2),
+            PathSegment::Field("count")
+        ]
+    );
+    assert_eq!(
+        parse_path("grid[1][0]").unwrap(),
+        vec![
+            PathSegment::Field("grid"),
+            PathSegment::Index(1),
+            PathSegment::Index(0)
+        ]
+    );
+
+    for invalid in ["", "a..b", "a[", "a[x]", "a]", "a[1]b"] {
+        assert!(parse_path(invalid).is_err(), "{invalid:?} should not parse");
+    }
+}
+
+#[test]
+fn test_get_by_path() {
+    let inventory = inventory();
+
+    let count = inventory.reflect_path("items[2].count").unwrap();
+    assert_eq!(count.as_any().downcast_ref::<u32>(), Some(&2));
+
+    let name = inventory.reflect_path("items[1].name").unwrap();
+    assert!(matches!(
+        ReflectValue::from_reflect(name),
+        Some(ReflectValue::String(ref s)) if s == "item1"
+    ));
+
+    assert!(matches!(
+        inventory.reflect_path("items[5].count"),
+        Err(EcsError::ReflectPathError(_))
+    ));
+    assert!(matches!(
+        inventory.reflect_path("weight"),
+        Err(EcsError::ReflectPathError(_))
+    ));
+}
+
+#[test]
+fn test_set_from_reflect_value() {
+    let mut inventory = inventory();
+
+    inventory
+        .set_from_reflect_value("items[0].count", ReflectValue::U32(42))
+        .unwrap();
+    inventory
+        .set_from_reflect_value("gold", ReflectValue::Usize(99))
+        .unwrap();
+    assert_eq!(inventory.items[0].count, 42);
+    assert_eq!(inventory.gold, 99);
+
+    // Mismatched types are rejected and leave the value untouched
+    let result = inventory.set_from_reflect_value("gold", ReflectValue::F32(1.0));
+    assert!(matches!(result, Err(EcsError::ReflectPathError(_))));
+    assert_eq!(inventory.gold, 99);
+}
+
+#[test]
+fn test_set_component_field_by_path() {
+    let mut world = World::new();
+    world.register_type::<LocalTransform>();
+    let entity = world.spawn_entity((LocalTransform::identity(),));
+
+    world
+        .get_reflect_mut(entity, TypeId::of::<LocalTransform>())
+        .unwrap()
+        .set_from_reflect_value("position.y", ReflectValue::F32(3.0))
+        .unwrap();
+
+    let transform = world.get_component::<LocalTransform>(entity).unwrap();
+    assert_eq!(transform.position, Vec3::new(0.0, 3.0, 0.0));
+
+    let scale_x = world
+        .get_reflect(entity, TypeId::of::<LocalTransform>())
+        .unwrap()
+        .reflect_path("scale.x")
+        .unwrap();
+    assert_eq!(scale_x.as_any().downcast_ref::<f32>(), Some(&1.0));
+}
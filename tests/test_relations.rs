@@ -0,0 +1,255 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{RelatedTo, Relation, RelationSources};
+
+struct Targets;
+struct OwnedBy;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Health(u32);
+
+#[test]
+fn test_add_and_remove_relation() {
+    let mut world = World::new();
+    let archer = world.spawn_entity((Health(10),));
+    let mage = world.spawn_entity((Health(8),));
+    let goblin = world.spawn_entity((Health(5),));
+
+    world.add_relation::<Targets>(archer, goblin).unwrap();
+    world.add_relation::<Targets>(mage, goblin).unwrap();
+    world.add_relation::<Targets>(mage, goblin).unwrap();
+
+    assert!(world.has_relation::<Targets>(archer, goblin));
+    assert!(!world.has_relation::<OwnedBy>(archer, goblin));
+    assert_eq!(world.relation_targets::<Targets>(mage), &[goblin]);
+    assert_eq!(world.relation_sources::<Targets>(goblin), &[archer, mage]);
+
+    world.remove_relation::<Targets>(archer, goblin).unwrap();
+    assert!(!world.has_component::<Relation<Targets>>(archer));
+    assert_eq!(world.relation_sources::<Targets>(goblin), &[mage]);
+
+    assert!(world.remove_relation::<Targets>(archer, goblin).is_err());
+}
+
+#[test]
+fn test_relation_kinds_are_independent() {
+    let mut world = World::new();
+    let sword = world.spawn_entity((Health(1),));
+    let knight = world.spawn_entity((Health(20),));
+    let dragon = world.spawn_entity((Health(500),));
+
+    world.add_relation::<OwnedBy>(sword, knight).unwrap();
+    world.add_relation::<Targets>(knight, dragon).unwrap();
+    world.add_relation::<Targets>(knight, sword).unwrap();
+
+    assert_eq!(world.relation_targets::<OwnedBy>(sword), &[knight]);
+    assert_eq!(world.relation_targets::<Targets>(knight), &[dragon, sword]);
+    assert!(world.relation_sources::<OwnedBy>(dragon).is_empty());
+}
+
+#[test]
+fn test_despawn_cleans_up_relations() {
+    let mut world = World::new();
+    let archer = world.spawn_entity((Health(10),));
+    let mage = world.spawn_entity((Health(8),));
+    let goblin = world.spawn_entity((Health(5),));
+    let orc = world.spawn_entity((Health(15),));
+
+    world.add_relation::<Targets>(archer, goblin).unwrap();
+    world.add_relation::<Targets>(archer, orc).unwrap();
+    world.add_relation::<Targets>(mage, goblin).unwrap();
+
+    // Target despawned: sources forget it, emptied relations are removed
+    world.despawn(goblin).unwrap();
+    assert_eq!(world.relation_targets::<Targets>(archer), &[orc]);
+    assert!(!world.has_component::<Relation<Targets>>(mage));
+
+    // Source despawned: target forgets it
+    world.despawn(archer).unwrap();
+    assert!(world
+        .get_component::<RelationSources<Targets>>(orc)
+        .unwrap()
+        .is_empty());
+    assert_eq!(world.get_component::<Health>(mage), Some(&Health(8)));
+}
+
+#[test]
+fn test_query_related() {
+    let mut world = World::new();
+    let goblin = world.spawn_entity((Health(5),));
+    let archer = world.spawn_entity((Health(10),));
+    let mage = world.spawn_entity((Health(8), 1.5f32));
+    let bystander = world.spawn_entity((Health(3),));
+
+    world.add_relation::<Targets>(archer, goblin).unwrap();
+    world.add_relation::<Targets>(mage, goblin).unwrap();
+    world.add_relation::<Targets>(bystander, archer).unwrap();
+
+    let attackers: Vec<_> = world
+        .query_related::<Targets, (Entity, &Health)>(goblin)
+        .map(|(e, h)| (e, *h))
+        .collect();
+    assert_eq!(attackers, vec![(archer, Health(10)), (mage, Health(8))]);
+
+    // Sources that don't match the query are skipped
+    let casters: Vec<_> = world
+        .query_related::<Targets, (Entity, &f32)>(goblin)
+        .map(|(e, _)| e)
+        .collect();
+    assert_eq!(casters, vec![mage]);
+}
+
+#[test]
+fn test_despawn_related_recursive() {
+    let mut world = World::new();
+    let ship = world.spawn_entity((Health(100),));
+    let crate_a = world.spawn_entity((Health(1),));
+    let crate_b = world.spawn_entity((Health(1),));
+    let item = world.spawn_entity((Health(1),));
+    let other = world.spawn_entity((Health(1),));
+
+    world.add_relation::<OwnedBy>(crate_a, ship).unwrap();
+    world.add_relation::<OwnedBy>(crate_b, ship).unwrap();
+    world.add_relation::<OwnedBy>(item, crate_a).unwrap();
+    world.add_relation::<Targets>(other, ship).unwrap();
+
+    world.despawn_related::<OwnedBy>(ship).unwrap();
+
+    for entity in [ship, crate_a, crate_b, item] {
+        assert!(!world.is_alive(entity));
+    }
+    assert!(world.is_alive(other));
+    assert!(world.relation_targets::<Targets>(other).is_empty());
+}
+
+#[test]
+fn test_despawn_related_cycle() {
+    let mut world = World::new();
+    let a = world.spawn_entity((Health(1),));
+    let b = world.spawn_entity((Health(1),));
+    let c = world.spawn_entity((Health(1),));
+    world.add_relation::<OwnedBy>(a, b).unwrap();
+    world.add_relation::<OwnedBy>(b, a).unwrap();
+    world.add_relation::<OwnedBy>(c, c).unwrap();
+    world.add_relation::<OwnedBy>(c, b).unwrap();
+
+    world.despawn_related::<OwnedBy>(a).unwrap();
+
+    for entity in [a, b, c] {
+        assert!(!world.is_alive(entity));
+    }
+}
+
+#[test]
+fn test_related_to_query_term() {
+    #[derive(Default)]
+    struct Attackers(Vec<(EntityId, u32)>);
+    struct Goblin(EntityId);
+
+    fn find_attackers(
+        query: Query<(Entity, &Health, RelatedTo<Targets>)>,
+        goblin: Res<Goblin>,
+        mut attackers: ResMut<Attackers>,
+    ) {
+        attackers.0 = query
+            .iter()
+            .filter(|(_, _, targets)| targets.contains(goblin.0))
+            .map(|(entity, health, _)| (entity, health.0))
+            .collect();
+    }
+
+    let mut world = World::new();
+    let goblin = world.spawn_entity((Health(5),));
+    let archer = world.spawn_entity((Health(10),));
+    let mage = world.spawn_entity((Health(8), 1.5f32));
+    let bystander = world.spawn_entity((Health(3),));
+    world.add_relation::<Targets>(archer, goblin).unwrap();
+    world.add_relation::<Targets>(mage, goblin).unwrap();
+    world.add_relation::<Targets>(bystander, archer).unwrap();
+    world.add_relation::<OwnedBy>(goblin, goblin).unwrap();
+    world.insert_resource(Goblin(goblin));
+    world.insert_resource(Attackers::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(find_attackers.into_system()));
+    Executor::new(&mut schedule)
+        .execute_frame(&mut world)
+        .unwrap();
+
+    let mut attackers = world.resource::<Attackers>().unwrap().0.clone();
+    attackers.sort_by_key(|&(_, health)| health);
+    assert_eq!(attackers, [(mage, 8), (archer, 10)]);
+    assert_eq!(world.query::<RelatedTo<OwnedBy>>().iter().count(), 1);
+}
+
+#[test]
+fn test_iter_related_to_filters_by_target() {
+    #[derive(Default)]
+    struct Attackers(Vec<EntityId>);
+    struct Goblin(EntityId);
+
+    fn find_attackers(
+        query: Query<(Entity, RelatedTo<Targets>)>,
+        goblin: Res<Goblin>,
+        mut attackers: ResMut<Attackers>,
+    ) {
+        attackers.0 = query
+            .iter_related_to::<Targets>(goblin.0)
+            .map(|(entity, _)| entity)
+            .collect();
+    }
+
+    let mut world = World::new();
+    let goblin = world.spawn_entity((Health(5),));
+    let orc = world.spawn_entity((Health(15),));
+    let archer = world.spawn_entity((Health(10),));
+    let mage = world.spawn_entity((Health(8), 1.5f32));
+    let knight = world.spawn_entity((Health(20),));
+    world.add_relation::<Targets>(archer, goblin).unwrap();
+    world.add_relation::<Targets>(mage, goblin).unwrap();
+    world.add_relation::<Targets>(mage, orc).unwrap();
+    world.add_relation::<Targets>(knight, orc).unwrap();
+    world.add_relation::<OwnedBy>(knight, goblin).unwrap();
+    world.insert_resource(Goblin(goblin));
+    world.insert_resource(Attackers::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(find_attackers.into_system()));
+    Executor::new(&mut schedule)
+        .execute_frame(&mut world)
+        .unwrap();
+
+    let mut attackers = world.resource::<Attackers>().unwrap().0.clone();
+    attackers.sort();
+    let mut expected = vec![archer, mage];
+    expected.sort();
+    assert_eq!(attackers, expected);
+
+    // A second target only matches its own sources
+    let mut orc_attackers: Vec<_> = world
+        .query::<(Entity, RelatedTo<Targets>)>()
+        .iter_related_to::<Targets>(orc)
+        .map(|(entity, _)| entity)
+        .collect();
+    orc_attackers.sort();
+    let mut expected = vec![mage, knight];
+    expected.sort();
+    assert_eq!(orc_attackers, expected);
+    assert_eq!(
+        world
+            .query::<RelatedTo<Targets>>()
+            .iter_related_to::<Targets>(knight)
+            .count(),
+        0
+    );
+}
+
+#[test]
+#[should_panic(expected = "requires RelatedTo<R>")]
+fn test_iter_related_to_requires_relation_term() {
+    let mut world = World::new();
+    let goblin = world.spawn_entity((Health(5),));
+    let _ = world
+        .query::<&Health>()
+        .iter_related_to::<Targets>(goblin)
+        .count();
+}
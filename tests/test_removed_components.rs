@@ -0,0 +1,150 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::StorageType;
+
+#[derive(Debug)]
+struct Replicated;
+
+#[derive(Debug)]
+struct Health;
+
+#[derive(Default)]
+struct Outbox(Vec<EntityId>);
+
+#[derive(Default)]
+struct Other(Vec<EntityId>);
+
+fn send_removals(removed: RemovedComponents<Replicated>, mut outbox: ResMut<Outbox>) {
+    outbox.0.extend(removed.read());
+}
+
+fn other_reader(removed: RemovedComponents<Replicated>, mut seen: ResMut<Other>) {
+    seen.0.extend(removed.read());
+}
+
+fn outbox(world: &World) -> Vec<EntityId> {
+    world.resource::<Outbox>().unwrap().0.clone()
+}
+
+/// World and schedule whose reader has already run once
+fn tracking() -> (World, Schedule) {
+    let mut world = World::new();
+    world.insert_resource(Outbox::default());
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(send_removals.into_system()));
+    Executor::new(&mut schedule)
+        .execute_frame(&mut world)
+        .unwrap();
+    (world, schedule)
+}
+
+fn run(world: &mut World, schedule: &mut Schedule) {
+    Executor::new(schedule).execute_frame(world).unwrap();
+}
+
+#[test]
+fn test_remove_component_seen_once() {
+    let (mut world, mut schedule) = tracking();
+    let entity = world.spawn_entity((Replicated, Health));
+    world.remove_component::<Replicated>(entity).unwrap();
+    world.remove_component::<Health>(entity).unwrap();
+
+    run(&mut world, &mut schedule);
+    assert_eq!(outbox(&world), [entity]);
+    run(&mut world, &mut schedule);
+    assert_eq!(outbox(&world), [entity]);
+}
+
+#[test]
+fn test_despawn_and_flush_removals_recorded() {
+    let (mut world, mut schedule) = tracking();
+    let despawned = world.spawn_entity((Replicated,));
+    let deferred = world.spawn_entity((Health, Replicated));
+    let untracked = world.spawn_entity((Health,));
+    world.despawn(despawned).unwrap();
+    world.despawn_deferred(deferred).unwrap();
+    world.despawn(untracked).unwrap();
+    world.flush_removals().unwrap();
+
+    run(&mut world, &mut schedule);
+    assert_eq!(outbox(&world), [despawned, deferred]);
+}
+
+#[test]
+fn test_sparse_removals_recorded() {
+    let (mut world, mut schedule) = tracking();
+    world
+        .register_storage::<Replicated>(StorageType::SparseSet)
+        .unwrap();
+    let removed = world.spawn_entity((Health,));
+    let despawned = world.spawn_entity((Health,));
+    world.add_component(removed, Replicated).unwrap();
+    world.add_component(despawned, Replicated).unwrap();
+    world.remove_component::<Replicated>(removed).unwrap();
+    world.despawn(despawned).unwrap();
+
+    run(&mut world, &mut schedule);
+    assert_eq!(outbox(&world), [removed, despawned]);
+}
+
+#[test]
+fn test_removals_before_first_run_not_seen() {
+    let mut world = World::new();
+    world.insert_resource(Outbox::default());
+    let entity = world.spawn_entity((Replicated,));
+    world.despawn(entity).unwrap();
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(send_removals.into_system()));
+    run(&mut world, &mut schedule);
+    run(&mut world, &mut schedule);
+    assert!(outbox(&world).is_empty());
+}
+
+#[test]
+fn test_every_reader_sees_each_removal() {
+    let mut world = World::new();
+    world.insert_resource(Outbox::default());
+    world.insert_resource(Other::default());
+    let mut fast = Schedule::new();
+    fast.add_system(Box::new(send_removals.into_system()));
+    let mut slow = Schedule::new();
+    slow.add_system(Box::new(other_reader.into_system()));
+    run(&mut world, &mut fast);
+    run(&mut world, &mut slow);
+
+    let first = world.spawn_entity((Replicated,));
+    world.despawn(first).unwrap();
+    run(&mut world, &mut fast);
+    let second = world.spawn_entity((Replicated,));
+    world.despawn(second).unwrap();
+    run(&mut world, &mut fast);
+
+    // The slow reader still gets removals the fast one already read
+    run(&mut world, &mut slow);
+    assert_eq!(outbox(&world), [first, second]);
+    assert_eq!(world.resource::<Other>().unwrap().0, [first, second]);
+}
+
+#[test]
+fn test_command_removals_seen_next_frame() {
+    #[derive(Default)]
+    struct Targets(Vec<EntityId>);
+
+    fn strip(mut commands: Commands, targets: Res<Targets>) {
+        for &entity in &targets.0 {
+            commands.entity(entity).remove::<Replicated>();
+        }
+    }
+
+    let (mut world, mut schedule) = tracking();
+    let entity = world.spawn_entity((Replicated,));
+    world.insert_resource(Targets(vec![entity]));
+    schedule.add_system(Box::new(strip.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_parallel(&mut world).unwrap();
+    assert!(outbox(&world).is_empty());
+    world.resource_mut::<Targets>().unwrap().0.clear();
+    executor.execute_frame_parallel(&mut world).unwrap();
+    assert_eq!(outbox(&world), [entity]);
+}
@@ -0,0 +1,114 @@
+use archetype_ecs::prelude::*;
+#[cfg(debug_assertions)]
+use archetype_ecs::{EcsError, UnsafeWorldCell};
+
+#[derive(Default)]
+struct Score(u32);
+
+#[derive(Default)]
+struct Frames(u32);
+
+struct Gravity(f32);
+
+fn add_score(mut score: ResMut<Score>, gravity: Res<Gravity>) {
+    score.0 += gravity.0 as u32;
+}
+
+fn count_frames(mut frames: ResMut<Frames>, _gravity: Res<Gravity>) {
+    frames.0 += 1;
+}
+
+#[test]
+fn test_distinct_resources_in_parallel() {
+    let mut world = World::new();
+    world.insert_resource(Score::default());
+    world.insert_resource(Frames::default());
+    world.insert_resource(Gravity(2.0));
+
+    let score = add_score.into_system();
+    let frames = count_frames.into_system();
+    assert!(score.accesses().can_run_parallel(&frames.accesses()));
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(score));
+    schedule.add_system(Box::new(frames));
+    let mut executor = Executor::new(&mut schedule);
+    for _ in 0..3 {
+        executor.execute_frame_parallel(&mut world).unwrap();
+    }
+
+    assert_eq!(world.resource::<Score>().unwrap().0, 6);
+    assert_eq!(world.resource::<Frames>().unwrap().0, 3);
+}
+
+#[test]
+fn test_shared_resource_writes_conflict() {
+    fn other_score(mut score: ResMut<Score>) {
+        score.0 += 1;
+    }
+
+    let a = add_score.into_system().accesses();
+    let b = other_score.into_system().accesses();
+    assert!(a.conflicts_with(&b));
+}
+
+/// Declares `Score` but touches `Frames` too
+#[cfg(debug_assertions)]
+struct Undeclared;
+
+#[cfg(debug_assertions)]
+impl System for Undeclared {
+    fn accesses(&self) -> SystemAccess {
+        SystemAccess::new().resource_mut::<Score>()
+    }
+
+    fn name(&self) -> &'static str {
+        "undeclared"
+    }
+
+    fn run(&mut self, world: &mut World, commands: &mut CommandBuffer) -> Result<()> {
+        let access = self.accesses();
+        unsafe { self.run_parallel(world.as_unsafe_world_cell().with_access(&access), commands) }
+    }
+
+    unsafe fn run_parallel(
+        &mut self,
+        world: UnsafeWorldCell,
+        _commands: &mut CommandBuffer,
+    ) -> Result<()> {
+        world.get_resource_mut::<Score>()?.0 += 1;
+        world.get_resource_mut::<Frames>()?.0 += 1;
+        Ok(())
+    }
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_undeclared_resource_errors_in_debug() {
+    let mut world = World::new();
+    world.insert_resource(Score::default());
+    world.insert_resource(Frames::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(Undeclared));
+    let mut executor = Executor::new(&mut schedule);
+    let result = executor.execute_frame_parallel(&mut world);
+
+    assert!(matches!(result, Err(EcsError::ResourceAccessError(_))));
+    assert_eq!(world.resource::<Frames>().unwrap().0, 0);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+fn test_conflicting_cell_borrows_error_in_debug() {
+    let mut world = World::new();
+    world.insert_resource(Score::default());
+
+    let cell = unsafe { world.as_unsafe_world_cell() };
+    let score = unsafe { cell.get_resource_mut::<Score>() }.unwrap();
+    let again = unsafe { cell.get_resource::<Score>() };
+    assert!(matches!(again, Err(EcsError::ResourceAccessError(_))));
+    drop(score);
+
+    assert!(unsafe { cell.get_resource::<Score>() }.is_ok());
+}
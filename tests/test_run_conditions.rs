@@ -0,0 +1,217 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{on_timer, resource_changed, resource_exists, EcsError};
+use std::time::Duration;
+
+#[derive(Default)]
+struct Runs(u32);
+
+#[derive(Default)]
+struct Settings(u32);
+
+struct Playing;
+
+fn count_runs(mut runs: ResMut<Runs>) {
+    runs.0 += 1;
+}
+
+fn runs(world: &World) -> u32 {
+    world.resource::<Runs>().unwrap().0
+}
+
+#[test]
+fn test_closure_condition_every_nth_frame() {
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+
+    let mut schedule = Schedule::new();
+    let mut frame = 0;
+    schedule.add_system_with_condition(Box::new(count_runs.into_system()), move |_: &World| {
+        frame += 1;
+        frame % 10 == 0
+    });
+
+    let mut executor = Executor::new(&mut schedule);
+    for _ in 0..25 {
+        executor.execute_frame(&mut world).unwrap();
+    }
+    assert_eq!(runs(&world), 2);
+}
+
+#[test]
+fn test_resource_exists_condition() {
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(count_runs.into_system()));
+    schedule
+        .add_run_condition(
+            std::any::type_name_of_val(&count_runs),
+            resource_exists::<Playing>(),
+        )
+        .unwrap();
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 0);
+
+    world.insert_resource(Playing);
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 2);
+}
+
+#[test]
+fn test_resource_changed_condition() {
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+    world.insert_resource(Settings::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system_with_condition(
+        Box::new(count_runs.into_system()),
+        resource_changed::<Settings>(),
+    );
+
+    let mut executor = Executor::new(&mut schedule);
+    // Insertion counts as a change
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 1);
+
+    world.resource_mut::<Settings>().unwrap().0 = 3;
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 2);
+}
+
+#[test]
+fn test_on_timer_condition() {
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system_with_condition(
+        Box::new(count_runs.into_system()),
+        on_timer(Duration::from_millis(20)),
+    );
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 0);
+
+    std::thread::sleep(Duration::from_millis(25));
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 1);
+}
+
+#[test]
+fn test_stage_condition_skips_all_systems() {
+    fn other(mut settings: ResMut<Settings>) {
+        settings.0 += 1;
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+    world.insert_resource(Settings::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_stage("gameplay").unwrap();
+    schedule
+        .add_system_to_stage("gameplay", Box::new(count_runs.into_system()))
+        .unwrap();
+    schedule
+        .add_system_to_stage("gameplay", Box::new(other.into_system()))
+        .unwrap();
+    schedule
+        .add_stage_run_condition("gameplay", resource_exists::<Playing>())
+        .unwrap();
+    assert!(matches!(
+        schedule.add_stage_run_condition("missing", resource_exists::<Playing>()),
+        Err(EcsError::ScheduleError(_))
+    ));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 0);
+    assert_eq!(world.resource::<Settings>().unwrap().0, 0);
+
+    let profile = executor.profile().unwrap();
+    assert!(profile.system_timings.is_empty());
+    assert_eq!(profile.skipped_systems.len(), 2);
+
+    world.insert_resource(Playing);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 1);
+    assert_eq!(world.resource::<Settings>().unwrap().0, 1);
+    assert!(executor.profile().unwrap().skipped_systems.is_empty());
+}
+
+#[test]
+fn test_skipped_systems_in_parallel_profile() {
+    fn always(mut settings: ResMut<Settings>) {
+        settings.0 += 1;
+    }
+
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+    world.insert_resource(Settings::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system_with_condition(Box::new(count_runs.into_system()), |_: &World| false);
+    schedule.add_system(Box::new(always.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_parallel(&mut world).unwrap();
+
+    assert_eq!(runs(&world), 0);
+    assert_eq!(world.resource::<Settings>().unwrap().0, 1);
+
+    let profile = executor.profile().unwrap();
+    assert_eq!(profile.system_timings.len(), 1);
+    assert_eq!(profile.skipped_systems.len(), 1);
+    assert!(profile.skipped_systems[0].ends_with("count_runs"));
+}
+
+#[test]
+fn test_run_condition_unknown_system() {
+    let mut schedule = Schedule::new();
+    let result = schedule.add_run_condition("missing", |_: &World| true);
+    assert!(matches!(result, Err(EcsError::ScheduleError(_))));
+}
+
+#[test]
+fn test_run_condition_guards_first_named_system() {
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(count_runs.into_system()));
+    schedule.add_system(Box::new(count_runs.into_system()));
+    schedule
+        .add_run_condition(std::any::type_name_of_val(&count_runs), |_: &World| false)
+        .unwrap();
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(runs(&world), 1);
+}
+
+#[test]
+fn test_skipped_systems_in_event_executor_profile() {
+    let mut world = World::new();
+    world.insert_resource(Runs::default());
+
+    let mut schedule = Schedule::new();
+    schedule.add_system_with_condition(Box::new(count_runs.into_system()), |_: &World| false);
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_with_events(&mut world).unwrap();
+
+    assert_eq!(runs(&world), 0);
+    let profile = executor.profile().unwrap();
+    assert!(profile.system_timings.is_empty());
+    assert_eq!(profile.skipped_systems.len(), 1);
+    assert!(profile.skipped_systems[0].ends_with("count_runs"));
+}
@@ -0,0 +1,312 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{Added, Changed, StorageType, View, With, Without};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Position {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Stunned {
+    turns: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Selected;
+
+fn world_with_sparse() -> World {
+    let mut world = World::new();
+    world
+        .register_storage::<Stunned>(StorageType::SparseSet)
+        .unwrap();
+    world
+        .register_storage::<Selected>(StorageType::SparseSet)
+        .unwrap();
+    world
+}
+
+#[test]
+fn test_toggle_does_not_create_archetypes() {
+    let mut world = world_with_sparse();
+    let entity = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    let archetypes = world.archetype_count();
+    let location = world.get_entity_location(entity).unwrap();
+
+    for turns in 0..10 {
+        world.add_component(entity, Stunned { turns }).unwrap();
+        world.add_component(entity, Selected).unwrap();
+        assert!(world.has_component::<Stunned>(entity));
+        world.remove_component::<Stunned>(entity).unwrap();
+        world.remove_component::<Selected>(entity).unwrap();
+        assert!(!world.has_component::<Stunned>(entity));
+    }
+
+    assert_eq!(world.archetype_count(), archetypes);
+    assert_eq!(
+        world.get_entity_location(entity).unwrap().archetype_id,
+        location.archetype_id
+    );
+    assert!(world.remove_component::<Stunned>(entity).is_err());
+}
+
+#[test]
+fn test_sparse_get_and_mutate() {
+    let mut world = world_with_sparse();
+    let entity = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    world.add_component(entity, Stunned { turns: 3 }).unwrap();
+
+    world.get_component_mut::<Stunned>(entity).unwrap().turns -= 1;
+    assert_eq!(
+        world.get_component::<Stunned>(entity),
+        Some(&Stunned { turns: 2 })
+    );
+
+    // Re-adding replaces the value
+    world.add_component(entity, Stunned { turns: 9 }).unwrap();
+    assert_eq!(
+        world.get_component::<Stunned>(entity),
+        Some(&Stunned { turns: 9 })
+    );
+    assert_eq!(world.sparse_storage().get::<Stunned>().unwrap().len(), 1);
+}
+
+#[test]
+fn test_sparse_queries() {
+    let mut world = world_with_sparse();
+    let a = world.spawn_entity((Position { x: 1.0, y: 0.0 },));
+    let b = world.spawn_entity((Position { x: 2.0, y: 0.0 },));
+    let c = world.spawn_entity((Position { x: 3.0, y: 0.0 }, 7u32));
+    world.add_component(a, Stunned { turns: 1 }).unwrap();
+    world.add_component(c, Stunned { turns: 2 }).unwrap();
+    world.add_component(b, Selected).unwrap();
+
+    let mut stunned: Vec<_> = world
+        .query::<(Entity, &Position, &Stunned)>()
+        .iter()
+        .map(|(e, _, s)| (e, s.turns))
+        .collect();
+    stunned.sort();
+    let mut expected = vec![(a, 1), (c, 2)];
+    expected.sort();
+    assert_eq!(stunned, expected);
+
+    let free: Vec<_> = world
+        .query::<(Entity, &Position, Without<Stunned>)>()
+        .iter()
+        .map(|(e, _, _)| e)
+        .collect();
+    assert_eq!(free, vec![b]);
+
+    let selected: Vec<_> = world
+        .query::<(Entity, With<Selected>)>()
+        .iter()
+        .map(|(e, _)| e)
+        .collect();
+    assert_eq!(selected, vec![b]);
+
+    let optional = world
+        .query::<(&Position, Option<&Stunned>)>()
+        .iter()
+        .filter(|(_, s)| s.is_some())
+        .count();
+    assert_eq!(optional, 2);
+}
+
+#[test]
+fn test_sparse_query_mut_and_change_detection() {
+    let mut world = world_with_sparse();
+    let a = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    let b = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    world.add_component(a, Stunned { turns: 2 }).unwrap();
+    world.add_component(b, Stunned { turns: 5 }).unwrap();
+
+    let tick = world.tick();
+    world.increment_tick();
+
+    for (pos, stunned) in world.query_mut::<(&mut Position, &mut Stunned)>().iter() {
+        stunned.turns -= 1;
+        pos.x = stunned.turns as f32;
+    }
+
+    assert_eq!(
+        world.get_component::<Stunned>(a),
+        Some(&Stunned { turns: 1 })
+    );
+    assert_eq!(
+        world.get_component::<Position>(b),
+        Some(&Position { x: 4.0, y: 0.0 })
+    );
+    let set = world.sparse_storage().get::<Stunned>().unwrap();
+    assert!(set.changed_tick(a).unwrap() > tick);
+
+    // Table-stored filters still work alongside sparse fetches
+    let changed = world
+        .query_mut::<(&Stunned, Changed<Position>)>()
+        .iter_since(tick)
+        .count();
+    assert_eq!(changed, 2);
+}
+
+#[test]
+fn test_despawn_and_clear_remove_sparse_components() {
+    let mut world = world_with_sparse();
+    let a = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    let b = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    world.add_component(a, Stunned { turns: 1 }).unwrap();
+    world.add_component(b, Stunned { turns: 1 }).unwrap();
+
+    world.despawn(a).unwrap();
+    assert_eq!(world.sparse_storage().get::<Stunned>().unwrap().len(), 1);
+    assert_eq!(world.query::<&Stunned>().iter().count(), 1);
+
+    world.clear();
+    assert!(world.sparse_storage().get::<Stunned>().unwrap().is_empty());
+    assert_eq!(world.storage_type::<Stunned>(), StorageType::SparseSet);
+}
+
+#[test]
+fn test_storage_registration_rules() {
+    let mut world = World::new();
+    world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    assert!(world
+        .register_storage::<Position>(StorageType::SparseSet)
+        .is_err());
+    assert_eq!(world.storage_type::<Position>(), StorageType::Table);
+
+    world
+        .register_storage::<Stunned>(StorageType::SparseSet)
+        .unwrap();
+
+    let entity = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    world.add_component(entity, Stunned { turns: 1 }).unwrap();
+    assert!(world
+        .register_storage::<Stunned>(StorageType::Table)
+        .is_err());
+
+    world.remove_component::<Stunned>(entity).unwrap();
+    world
+        .register_storage::<Stunned>(StorageType::Table)
+        .unwrap();
+    world.add_component(entity, Stunned { turns: 1 }).unwrap();
+    assert_eq!(world.query::<&Stunned>().iter().count(), 1);
+}
+
+#[test]
+fn test_spawn_bundles_with_sparse_members() {
+    #[derive(Default)]
+    struct Spawned(Vec<EntityId>);
+
+    fn spawn_stunned(mut commands: Commands, mut spawned: ResMut<Spawned>) {
+        let entity = commands
+            .spawn((Stunned { turns: 3 }, Position { x: 3.0, y: 0.0 }))
+            .id();
+        spawned.0.push(entity);
+    }
+
+    let mut world = world_with_sparse();
+    world.insert_resource(Spawned::default());
+    let plain = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    let archetypes = world.archetype_count();
+
+    let single = world.spawn_entity((Position { x: 1.0, y: 0.0 }, Stunned { turns: 1 }, Selected));
+    let batch = world
+        .spawn_batch((0..2).map(|i| (Stunned { turns: i }, Position { x: 2.0, y: 0.0 })))
+        .unwrap();
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(spawn_stunned.into_system()));
+    Executor::new(&mut schedule)
+        .execute_frame(&mut world)
+        .unwrap();
+    let commanded = world.resource::<Spawned>().unwrap().0[0];
+
+    // Every entity shares the plain entity's archetype
+    assert_eq!(world.archetype_count(), archetypes);
+    assert_eq!(world.query::<(&Position, &Stunned)>().iter().count(), 4);
+    assert_eq!(
+        world.get_component::<Stunned>(single),
+        Some(&Stunned { turns: 1 })
+    );
+    assert!(world.has_component::<Selected>(single));
+    assert_eq!(
+        world.get_component::<Stunned>(batch[1]),
+        Some(&Stunned { turns: 1 })
+    );
+    assert_eq!(
+        world.get_component::<Position>(commanded),
+        Some(&Position { x: 3.0, y: 0.0 })
+    );
+    assert!(!world.has_component::<Stunned>(plain));
+}
+
+#[test]
+fn test_sparse_change_filters() {
+    let mut world = world_with_sparse();
+    let a = world.spawn_entity((Position { x: 0.0, y: 0.0 },));
+    let b = world.spawn_entity((Position { x: 0.0, y: 0.0 }, Stunned { turns: 1 }));
+    world.add_component(a, Stunned { turns: 1 }).unwrap();
+
+    let tick = world.tick();
+    world.increment_tick();
+    assert_eq!(
+        View::<(&Stunned, Changed<Stunned>)>::new(&world, tick)
+            .iter()
+            .count(),
+        0
+    );
+
+    // Re-inserting counts as a change, not an addition
+    world.add_component(a, Stunned { turns: 2 }).unwrap();
+    let c = world.spawn_entity((Position { x: 0.0, y: 0.0 }, Stunned { turns: 3 }));
+    world.get_component_mut::<Stunned>(b).unwrap().turns = 5;
+
+    let changed: Vec<EntityId> = world
+        .query_mut::<(Entity, Changed<Stunned>)>()
+        .iter_since(tick)
+        .map(|(entity, _)| entity)
+        .collect();
+    assert_eq!(changed.len(), 3);
+    let added: Vec<EntityId> = View::<(Entity, Added<Stunned>)>::new(&world, tick)
+        .iter()
+        .map(|(entity, _)| entity)
+        .collect();
+    assert_eq!(added, [c]);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn test_par_query_mutates_sparse_across_archetypes() {
+    #[derive(Debug, Clone, Copy)]
+    struct Velocity;
+
+    let mut world = world_with_sparse();
+    let mut stunned = Vec::new();
+    for i in 0..64 {
+        let entity = if i % 2 == 0 {
+            world.spawn_entity((Position { x: 0.0, y: 0.0 },))
+        } else {
+            world.spawn_entity((Position { x: 0.0, y: 0.0 }, Velocity))
+        };
+        world.add_component(entity, Stunned { turns: i }).unwrap();
+        stunned.push(entity);
+    }
+    let tick = world.tick();
+    world.increment_tick();
+
+    // One task per archetype, all writing through the same sparse set
+    world
+        .query_mut::<&mut Stunned>()
+        .par()
+        .for_each(|stunned| stunned.turns += 100);
+
+    let set = world.sparse_storage().get::<Stunned>().unwrap();
+    for (i, &entity) in stunned.iter().enumerate() {
+        assert_eq!(
+            set.get(entity),
+            Some(&Stunned {
+                turns: i as u32 + 100
+            })
+        );
+        assert!(set.changed_tick(entity).unwrap() > tick);
+    }
+}
@@ -0,0 +1,147 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{CallbackSubscriber, GameState, GameStateChanged};
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum Flow {
+    Loading,
+    Menu,
+    Playing,
+}
+
+#[derive(Default)]
+struct Log(Vec<&'static str>);
+
+struct Record(&'static str);
+
+impl System for Record {
+    fn accesses(&self) -> SystemAccess {
+        SystemAccess::new().resource_mut::<Log>()
+    }
+
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn run(&mut self, world: &mut World, _commands: &mut CommandBuffer) -> Result<()> {
+        world.resource_mut::<Log>().unwrap().0.push(self.0);
+        Ok(())
+    }
+}
+
+fn log(app: &App) -> Vec<&'static str> {
+    app.world.resource::<Log>().unwrap().0.clone()
+}
+
+fn set_next(app: &mut App, state: Flow) {
+    app.world
+        .resource_mut::<NextState<Flow>>()
+        .unwrap()
+        .set(state);
+}
+
+#[test]
+fn test_transition_schedule_order() {
+    let mut app = App::new();
+    app.world.insert_resource(Log::default());
+    app.insert_state(Flow::Loading)
+        .add_state_system(OnEnter(Flow::Loading), Box::new(Record("enter_loading")))
+        .add_state_system(OnExit(Flow::Loading), Box::new(Record("exit_loading")))
+        .add_state_system(
+            OnTransition {
+                from: Flow::Loading,
+                to: Flow::Menu,
+            },
+            Box::new(Record("loading_to_menu")),
+        )
+        .add_state_system(OnEnter(Flow::Menu), Box::new(Record("enter_menu")))
+        .add_state_system(OnEnter(Flow::Playing), Box::new(Record("enter_playing")));
+
+    app.update().unwrap();
+    assert_eq!(log(&app), ["enter_loading"]);
+
+    set_next(&mut app, Flow::Menu);
+    app.update().unwrap();
+    assert_eq!(
+        log(&app),
+        [
+            "enter_loading",
+            "exit_loading",
+            "loading_to_menu",
+            "enter_menu"
+        ]
+    );
+    assert_eq!(
+        app.world.resource::<State<Flow>>().unwrap().get(),
+        &Flow::Menu
+    );
+    assert!(app
+        .world
+        .resource::<NextState<Flow>>()
+        .unwrap()
+        .pending()
+        .is_none());
+
+    // Setting the current state again is a no-op
+    set_next(&mut app, Flow::Menu);
+    app.update().unwrap();
+    assert_eq!(log(&app).len(), 4);
+}
+
+#[test]
+fn test_in_state_condition() {
+    fn tick(mut log: ResMut<Log>) {
+        log.0.push("tick");
+    }
+
+    fn start(mut next: ResMut<NextState<Flow>>) {
+        next.set(Flow::Playing);
+    }
+
+    let mut app = App::new();
+    app.world.insert_resource(Log::default());
+    app.insert_state(Flow::Menu);
+    app.schedule
+        .add_system_with_condition(Box::new(tick.into_system()), in_state(Flow::Playing));
+    app.schedule
+        .add_system_with_condition(Box::new(start.into_system()), in_state(Flow::Menu));
+
+    // `start` queues the transition, applied at the next update
+    app.update().unwrap();
+    assert!(log(&app).is_empty());
+
+    app.update().unwrap();
+    app.update().unwrap();
+    assert_eq!(log(&app), ["tick", "tick"]);
+}
+
+#[test]
+fn test_game_state_changed_published() {
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let handler_seen = seen.clone();
+
+    let mut app = App::new();
+    app.insert_state(GameState::Menu);
+    app.world
+        .event_bus_mut()
+        .subscribe::<GameStateChanged>(Box::new(CallbackSubscriber::new(move |event| {
+            let event = event.as_any().downcast_ref::<GameStateChanged>().unwrap();
+            handler_seen
+                .lock()
+                .unwrap()
+                .push((event.old_state.clone(), event.new_state.clone()));
+            Ok(())
+        })));
+
+    app.world
+        .resource_mut::<NextState<GameState>>()
+        .unwrap()
+        .set(GameState::Playing);
+    app.update().unwrap();
+    app.world.process_global_events().unwrap();
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        [(GameState::Menu, GameState::Playing)]
+    );
+}
@@ -7,7 +7,7 @@ fn test_disjoint_mutable_borrows_via_cell() {
 
     // Spawn some entities
     for i in 0..100 {
-        world.spawn_entity((i as f32, i as i32));
+        world.spawn_entity((i as f32, i));
     }
 
     let tick = world.tick();
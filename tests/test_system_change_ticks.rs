@@ -0,0 +1,126 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{Changed, TICK_REBASE_THRESHOLD};
+
+#[derive(Debug)]
+struct Position(f32);
+
+#[derive(Default)]
+struct Seen(Vec<usize>);
+
+/// Whether `nudge` moves anything this frame
+struct Moving(bool);
+
+fn count_changed(query: Query<(&Position, Changed<Position>)>, mut seen: ResMut<Seen>) {
+    seen.0.push(query.iter().count());
+}
+
+fn nudge(mut query: Query<&mut Position>, moving: Res<Moving>) {
+    if moving.0 {
+        for position in query.iter_mut() {
+            position.0 += 1.0;
+        }
+    }
+}
+
+fn seen(world: &World) -> Vec<usize> {
+    world.resource::<Seen>().unwrap().0.clone()
+}
+
+fn world_with(moving: bool) -> World {
+    let mut world = World::new();
+    world.insert_resource(Seen::default());
+    world.insert_resource(Moving(moving));
+    world.spawn_entity((Position(0.0),));
+    world
+}
+
+#[test]
+fn test_changes_after_reader_seen_next_frame() {
+    let mut world = world_with(true);
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(count_changed.into_system()));
+    schedule.add_system(Box::new(nudge.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(seen(&world), [1, 1, 1, 0]);
+}
+
+#[test]
+fn test_changes_before_reader_seen_same_frame() {
+    let mut world = world_with(true);
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(nudge.into_system()));
+    schedule.add_system(Box::new(count_changed.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_parallel(&mut world).unwrap();
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    executor.execute_frame_parallel(&mut world).unwrap();
+    assert_eq!(seen(&world), [1, 0]);
+}
+
+#[test]
+fn test_skipped_system_sees_changes_since_it_last_ran() {
+    let mut world = world_with(false);
+    let mut schedule = Schedule::new();
+    let mut frame = 0;
+    schedule.add_system_with_condition(Box::new(count_changed.into_system()), move |_: &World| {
+        frame += 1;
+        frame % 3 == 1
+    });
+    schedule.add_system(Box::new(nudge.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    // Only the first of the skipped frames moves anything
+    world.resource_mut::<Moving>().unwrap().0 = true;
+    executor.execute_frame(&mut world).unwrap();
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+    assert_eq!(seen(&world), [1, 1, 0]);
+}
+
+#[test]
+fn test_change_detection_survives_tick_rebase() {
+    let mut world = world_with(false);
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(count_changed.into_system()));
+    schedule.add_system(Box::new(nudge.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame(&mut world).unwrap();
+    world.tick = TICK_REBASE_THRESHOLD - 2;
+    world.resource_mut::<Moving>().unwrap().0 = true;
+    executor.execute_frame(&mut world).unwrap();
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    executor.execute_frame(&mut world).unwrap();
+    executor.execute_frame(&mut world).unwrap();
+
+    assert!(world.tick() < TICK_REBASE_THRESHOLD);
+    assert_eq!(seen(&world), [1, 0, 1, 0]);
+}
+
+#[test]
+fn test_changes_seen_under_event_executor() {
+    let mut world = world_with(true);
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(count_changed.into_system()));
+    schedule.add_system(Box::new(nudge.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_with_events(&mut world).unwrap();
+    executor.execute_frame_with_events(&mut world).unwrap();
+    world.resource_mut::<Moving>().unwrap().0 = false;
+    executor.execute_frame_with_events(&mut world).unwrap();
+    executor.execute_frame_with_events(&mut world).unwrap();
+    assert_eq!(seen(&world), [1, 1, 1, 0]);
+}
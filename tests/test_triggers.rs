@@ -0,0 +1,189 @@
+use archetype_ecs::prelude::*;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OnDamage {
+    amount: u32,
+}
+
+struct OnHeal;
+
+#[derive(Default)]
+struct Log(Vec<(EntityId, u32)>);
+
+fn log_damage(trigger: &mut Trigger<OnDamage>, world: &mut World) {
+    let entry = (trigger.target().unwrap(), trigger.event().amount);
+    world.resource_mut::<Log>().unwrap().0.push(entry);
+}
+
+fn logged(world: &World) -> Vec<(EntityId, u32)> {
+    world.resource::<Log>().unwrap().0.clone()
+}
+
+/// World with a ship > turret > barrel hierarchy
+fn ship_world() -> (World, [EntityId; 3]) {
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    let ship = world.spawn_entity(());
+    let turret = world.spawn_entity((Parent(ship),));
+    let barrel = world.spawn_entity((Parent(turret),));
+    (world, [ship, turret, barrel])
+}
+
+#[test]
+fn test_global_observer_receives_typed_event() {
+    #[derive(Default)]
+    struct Amounts(Vec<u32>);
+
+    let mut world = World::new();
+    world.insert_resource(Amounts::default());
+    world.observe(|trigger: &mut Trigger<OnDamage>, world: &mut World| {
+        assert!(trigger.target().is_none());
+        let amount = trigger.event().amount;
+        world.resource_mut::<Amounts>().unwrap().0.push(amount);
+    });
+    world.observe(|_: &mut Trigger<OnHeal>, _: &mut World| panic!("wrong trigger type"));
+
+    world.trigger(OnDamage { amount: 4 });
+    assert_eq!(world.resource::<Amounts>().unwrap().0, [4]);
+}
+
+#[test]
+fn test_scoped_observer_only_sees_its_entity() {
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    let a = world.spawn_entity(());
+    let b = world.spawn_entity(());
+    world.observe_entity(a, log_damage);
+
+    world.trigger_entity(OnDamage { amount: 1 }, b);
+    world.trigger_entity(OnDamage { amount: 2 }, a);
+    world.trigger(OnDamage { amount: 3 });
+    assert_eq!(logged(&world), [(a, 2)]);
+}
+
+#[test]
+fn test_trigger_propagates_up_parent_chain() {
+    let (mut world, [ship, turret, barrel]) = ship_world();
+    for entity in [ship, turret, barrel] {
+        world.observe_entity(entity, log_damage);
+    }
+    world.observe_entity(
+        ship,
+        move |trigger: &mut Trigger<OnDamage>, _: &mut World| {
+            assert_eq!(trigger.original_target(), Some(barrel));
+        },
+    );
+
+    world.trigger_entity(OnDamage { amount: 5 }, barrel);
+    assert_eq!(logged(&world), [(barrel, 5), (turret, 5), (ship, 5)]);
+}
+
+#[test]
+fn test_stop_propagation_finishes_current_entity() {
+    let (mut world, [ship, turret, barrel]) = ship_world();
+    world.observe_entity(turret, |trigger: &mut Trigger<OnDamage>, _: &mut World| {
+        trigger.stop_propagation();
+    });
+    for entity in [ship, turret, barrel] {
+        world.observe_entity(entity, log_damage);
+    }
+
+    world.trigger_entity(OnDamage { amount: 1 }, barrel);
+    assert_eq!(logged(&world), [(barrel, 1), (turret, 1)]);
+}
+
+#[test]
+fn test_event_mut_visible_to_later_observers() {
+    let (mut world, [ship, turret, _]) = ship_world();
+    // Armor on the turret halves damage before it reaches the ship
+    world.observe_entity(turret, |trigger: &mut Trigger<OnDamage>, _: &mut World| {
+        trigger.event_mut().amount /= 2;
+    });
+    world.observe_entity(ship, log_damage);
+
+    world.trigger_entity(OnDamage { amount: 10 }, turret);
+    assert_eq!(logged(&world), [(ship, 5)]);
+}
+
+#[test]
+fn test_unobserve_and_despawn_remove_observers() {
+    let (mut world, [ship, turret, _]) = ship_world();
+    let id = world.observe_entity(ship, log_damage);
+    world.observe_entity(turret, log_damage);
+    world.observe(|_: &mut Trigger<OnDamage>, _: &mut World| {});
+    assert_eq!(world.trigger_observer_count(), 3);
+
+    assert!(world.unobserve(id));
+    assert!(!world.unobserve(id));
+    world.despawn(turret).unwrap();
+    assert_eq!(world.trigger_observer_count(), 1);
+
+    world.trigger_entity(OnDamage { amount: 1 }, ship);
+    assert!(logged(&world).is_empty());
+}
+
+#[test]
+fn test_commands_trigger_when_applied() {
+    fn attack(mut commands: Commands, targets: Res<Targets>) {
+        commands.entity(targets.0).trigger(OnDamage { amount: 7 });
+    }
+
+    struct Targets(EntityId);
+
+    let (mut world, [ship, _, barrel]) = ship_world();
+    world.insert_resource(Targets(barrel));
+    world.observe_entity(ship, log_damage);
+
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(attack.into_system()));
+    Executor::new(&mut schedule)
+        .execute_frame(&mut world)
+        .unwrap();
+    assert_eq!(logged(&world), [(ship, 7)]);
+}
+
+#[test]
+fn test_dispatch_only_visits_matching_observers() {
+    #[derive(Default)]
+    struct Calls(usize);
+
+    let mut world = World::new();
+    world.insert_resource(Calls::default());
+    let entities: Vec<EntityId> = (0..2000).map(|_| world.spawn_entity(())).collect();
+    for &entity in &entities {
+        world.observe_entity(entity, |_: &mut Trigger<OnDamage>, world: &mut World| {
+            world.resource_mut::<Calls>().unwrap().0 += 1;
+        });
+    }
+
+    world.trigger_entity(OnDamage { amount: 1 }, entities[1234]);
+    assert_eq!(world.resource::<Calls>().unwrap().0, 1);
+}
+
+#[test]
+fn test_parent_cycle_ends_propagation() {
+    let mut world = World::new();
+    world.insert_resource(Log::default());
+    let a = world.spawn_entity(());
+    let b = world.spawn_entity((Parent(a),));
+    world.add_component(a, Parent(b)).unwrap();
+    world.observe_entity(a, log_damage);
+    world.observe_entity(b, log_damage);
+
+    world.trigger_entity(OnDamage { amount: 1 }, b);
+    assert_eq!(logged(&world), [(b, 1), (a, 1)]);
+}
+
+#[test]
+fn test_global_stop_propagation_keeps_target_observers() {
+    let (mut world, [ship, turret, barrel]) = ship_world();
+    world.observe(|trigger: &mut Trigger<OnDamage>, _: &mut World| {
+        trigger.stop_propagation();
+    });
+    for entity in [ship, turret, barrel] {
+        world.observe_entity(entity, log_damage);
+    }
+
+    world.trigger_entity(OnDamage { amount: 2 }, turret);
+    assert_eq!(logged(&world), [(turret, 2)]);
+}
@@ -0,0 +1,183 @@
+use archetype_ecs::prelude::*;
+use archetype_ecs::{ComponentId, EcsError};
+use std::any::type_name_of_val;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Hit(u32);
+
+#[derive(Default)]
+struct Received(Vec<u32>);
+
+#[derive(Default)]
+struct Frame(u32);
+
+fn send_hit(mut hits: EventWriter<Hit>, mut frame: ResMut<Frame>) {
+    frame.0 += 1;
+    hits.send(Hit(frame.0));
+}
+
+fn receive(mut hits: EventReader<Hit>, mut received: ResMut<Received>) {
+    received.0.extend(hits.read().map(|hit| hit.0));
+}
+
+fn app() -> App {
+    let mut app = App::new();
+    app.add_event::<Hit>();
+    app.world.insert_resource(Received::default());
+    app.world.insert_resource(Frame::default());
+    app
+}
+
+fn received(world: &World) -> Vec<u32> {
+    world.resource::<Received>().unwrap().0.clone()
+}
+
+#[test]
+fn test_reader_and_writer_access() {
+    let reader = receive.into_system().accesses();
+    let writer = send_hit.into_system().accesses();
+    let channel = ComponentId::of::<Events<Hit>>();
+
+    assert!(reader.reads.contains(&channel));
+    assert!(reader.event_writes.is_empty());
+    assert!(writer.writes.contains(&channel));
+    assert_eq!(writer.event_writes, vec![channel]);
+}
+
+#[test]
+fn test_writer_runs_before_reader_added_first() {
+    let mut app = app();
+    app.add_system(Box::new(receive.into_system()));
+    app.add_system(Box::new(send_hit.into_system()));
+
+    app.update().unwrap();
+    assert_eq!(received(&app.world), vec![1]);
+    app.update().unwrap();
+    assert_eq!(received(&app.world), vec![1, 2]);
+}
+
+#[test]
+fn test_explicit_ordering_overrides_event_ordering() {
+    let mut app = app();
+    app.add_system(Box::new(send_hit.into_system()));
+    app.schedule
+        .add_system_before(Box::new(receive.into_system()), type_name_of_val(&send_hit));
+
+    // The reader runs first, so it sees each event one frame late
+    app.update().unwrap();
+    assert!(received(&app.world).is_empty());
+    app.update().unwrap();
+    app.update().unwrap();
+    assert_eq!(received(&app.world), vec![1, 2]);
+}
+
+#[test]
+fn test_mutual_event_channels_do_not_cycle() {
+    struct Reply(u32);
+
+    fn reply(mut hits: EventReader<Hit>, mut replies: EventWriter<Reply>) {
+        replies.send_batch(hits.read().map(|hit| Reply(hit.0)));
+    }
+    fn hit_back(
+        mut replies: EventReader<Reply>,
+        mut hits: EventWriter<Hit>,
+        mut received: ResMut<Received>,
+    ) {
+        received.0.extend(replies.read().map(|reply| reply.0));
+        hits.send(Hit(1));
+    }
+
+    let mut app = app();
+    app.add_event::<Reply>();
+    app.add_system(Box::new(reply.into_system()));
+    app.add_system(Box::new(hit_back.into_system()));
+
+    // Only one direction can be ordered, so hits reach `reply` a frame late
+    app.update().unwrap();
+    app.update().unwrap();
+    app.update().unwrap();
+    assert_eq!(received(&app.world), vec![1, 1]);
+}
+
+#[test]
+fn test_every_reader_sees_every_event_once() {
+    #[derive(Default)]
+    struct Other(Vec<u32>);
+
+    fn other(mut hits: EventReader<Hit>, mut seen: ResMut<Other>) {
+        seen.0.extend(hits.read().map(|hit| hit.0));
+    }
+
+    let mut app = app();
+    app.world.insert_resource(Other::default());
+    app.add_system(Box::new(send_hit.into_system()));
+    app.add_system(Box::new(receive.into_system()));
+    app.add_system(Box::new(other.into_system()));
+
+    for _ in 0..3 {
+        app.update().unwrap();
+    }
+    assert_eq!(received(&app.world), vec![1, 2, 3]);
+    assert_eq!(app.world.resource::<Other>().unwrap().0, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_events_survive_one_skipped_frame() {
+    let mut app = app();
+    app.add_system(Box::new(send_hit.into_system()));
+    let mut frame = 0;
+    app.schedule
+        .add_system_with_condition(Box::new(receive.into_system()), move |_: &World| {
+            frame += 1;
+            frame % 2 == 0
+        });
+
+    for _ in 0..4 {
+        app.update().unwrap();
+    }
+    assert_eq!(received(&app.world), vec![1, 2, 3, 4]);
+}
+
+#[test]
+fn test_events_dropped_after_two_updates() {
+    let mut app = app();
+    app.world
+        .resource_mut::<Events<Hit>>()
+        .unwrap()
+        .send(Hit(7));
+    app.update().unwrap();
+    assert_eq!(app.world.resource::<Events<Hit>>().unwrap().len(), 1);
+    app.update().unwrap();
+    assert!(app.world.resource::<Events<Hit>>().unwrap().is_empty());
+
+    // A reader added now finds nothing
+    app.add_system(Box::new(receive.into_system()));
+    app.update().unwrap();
+    assert!(received(&app.world).is_empty());
+}
+
+#[test]
+fn test_reader_without_channel_errors() {
+    let mut world = World::new();
+    world.insert_resource(Received::default());
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(receive.into_system()));
+
+    let result = Executor::new(&mut schedule).execute_frame(&mut world);
+    assert!(matches!(result, Err(EcsError::ResourceNotFound(_))));
+}
+
+#[test]
+fn test_writer_runs_before_reader_in_event_executor() {
+    let mut world = World::new();
+    world.insert_resource(Events::<Hit>::new());
+    world.insert_resource(Received::default());
+    world.insert_resource(Frame::default());
+    let mut schedule = Schedule::new();
+    schedule.add_system(Box::new(receive.into_system()));
+    schedule.add_system(Box::new(send_hit.into_system()));
+
+    let mut executor = Executor::new(&mut schedule);
+    executor.execute_frame_with_events(&mut world).unwrap();
+    assert_eq!(received(&world), vec![1]);
+}